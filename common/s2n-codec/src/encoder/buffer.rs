@@ -41,6 +41,33 @@ impl<'a> EncoderBuffer<'a> {
         self.set_position(position)
     }
 
+    /// Returns a checkpoint of the current write cursor
+    ///
+    /// Any bytes written after the checkpoint can be discarded by passing it to
+    /// [`Self::rollback`], which is useful when speculatively encoding values that may not be
+    /// kept.
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            position: self.position,
+        }
+    }
+
+    /// Rewinds the write cursor to a previously taken checkpoint
+    ///
+    /// # Panics
+    /// Panics when the checkpoint is ahead of the current write cursor
+    #[inline]
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        debug_assert!(
+            checkpoint.position <= self.position,
+            "checkpoint {} is ahead of the current position {}",
+            checkpoint.position,
+            self.position
+        );
+        self.position = checkpoint.position;
+    }
+
     /// Splits off the used buffer from the remaining bytes
     #[inline]
     pub fn split_off(self) -> (&'a mut [u8], &'a mut [u8]) {
@@ -70,6 +97,15 @@ impl<'a> EncoderBuffer<'a> {
     }
 }
 
+/// A saved write cursor position for an [`EncoderBuffer`]
+///
+/// See [`EncoderBuffer::checkpoint`] and [`EncoderBuffer::rollback`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct Checkpoint {
+    position: usize,
+}
+
 impl<'a> Encoder for EncoderBuffer<'a> {
     #[inline]
     fn write_sized<F: FnOnce(&mut [u8])>(&mut self, len: usize, write: F) {
@@ -125,3 +161,45 @@ impl<'a> Encoder for EncoderBuffer<'a> {
         self.position
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollback_test() {
+        let mut bytes = [0u8; 16];
+        let mut buffer = EncoderBuffer::new(&mut bytes);
+
+        // write a "frame" made up of a tag and a value
+        buffer.encode(&1u8);
+        buffer.encode(&0x0203u16);
+        let first_len = buffer.len();
+
+        let checkpoint = buffer.checkpoint();
+        buffer.encode(&4u8);
+        buffer.encode(&0x0506_0708u32);
+        assert_eq!(buffer.len(), first_len + 5);
+
+        // discard the second frame
+        buffer.rollback(checkpoint);
+        assert_eq!(buffer.len(), first_len);
+        assert_eq!(buffer.as_mut_slice(), &[1, 2, 3]);
+
+        // writing after a rollback overwrites the discarded bytes
+        buffer.encode(&9u8);
+        assert_eq!(buffer.as_mut_slice(), &[1, 2, 3, 9]);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn rollback_ahead_test() {
+        let mut bytes = [0u8; 4];
+        let mut buffer = EncoderBuffer::new(&mut bytes);
+        buffer.encode(&1u8);
+        let checkpoint = buffer.checkpoint();
+        buffer.set_position(0);
+        buffer.rollback(checkpoint);
+    }
+}
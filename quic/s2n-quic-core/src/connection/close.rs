@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{application, crypto::tls, transport};
+use crate::{application, connection, crypto::tls, endpoint, transport};
 pub use crate::{frame::ConnectionClose, inet::SocketAddress};
 
 /// Provides a hook for applications to rewrite CONNECTION_CLOSE frames
@@ -158,3 +158,107 @@ impl Formatter for Production {
         transport::Error::APPLICATION_ERROR.into()
     }
 }
+
+/// The reason a connection was closed
+///
+/// This condenses the [`connection::Error`] a connection was closed with into the cases
+/// applications usually need to tell apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectionCloseReason {
+    /// The idle timeout elapsed without any packets being received from the peer
+    IdleTimeout,
+
+    /// An application closed the connection
+    ///
+    /// The error code is `None` if the connection was closed gracefully, without an error.
+    Application {
+        error: Option<application::Error>,
+        initiator: endpoint::Location,
+    },
+
+    /// The connection was closed with a transport error
+    Transport {
+        code: transport::error::Code,
+        initiator: endpoint::Location,
+    },
+
+    /// The peer reset the connection by sending a stateless reset
+    StatelessReset,
+
+    /// The connection was closed for another reason, such as the handshake taking too long
+    Other(connection::Error),
+}
+
+impl From<connection::Error> for ConnectionCloseReason {
+    #[inline]
+    fn from(error: connection::Error) -> Self {
+        match error {
+            connection::Error::IdleTimerExpired { .. } => Self::IdleTimeout,
+            connection::Error::Closed { initiator, .. } => Self::Application {
+                error: None,
+                initiator,
+            },
+            connection::Error::Application {
+                error, initiator, ..
+            } => Self::Application {
+                error: Some(error),
+                initiator,
+            },
+            connection::Error::Transport {
+                code, initiator, ..
+            } => Self::Transport { code, initiator },
+            connection::Error::StatelessReset { .. } => Self::StatelessReset,
+            error => Self::Other(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::varint::VarInt;
+
+    #[test]
+    fn close_reason_test() {
+        assert_eq!(
+            ConnectionCloseReason::from(connection::Error::idle_timer_expired()),
+            ConnectionCloseReason::IdleTimeout
+        );
+        assert_eq!(
+            ConnectionCloseReason::from(connection::Error::stateless_reset()),
+            ConnectionCloseReason::StatelessReset
+        );
+        assert_eq!(
+            ConnectionCloseReason::from(connection::Error::closed(endpoint::Location::Remote)),
+            ConnectionCloseReason::Application {
+                error: None,
+                initiator: endpoint::Location::Remote,
+            }
+        );
+
+        let error = application::Error::from(VarInt::from_u8(123));
+        assert_eq!(
+            ConnectionCloseReason::from(connection::Error::application(error)),
+            ConnectionCloseReason::Application {
+                error: Some(error),
+                initiator: endpoint::Location::Local,
+            }
+        );
+
+        let error = transport::Error::PROTOCOL_VIOLATION;
+        assert_eq!(
+            ConnectionCloseReason::from(connection::Error::from(error)),
+            ConnectionCloseReason::Transport {
+                code: error.code,
+                initiator: endpoint::Location::Local,
+            }
+        );
+
+        let error = connection::Error::unspecified();
+        assert_eq!(
+            ConnectionCloseReason::from(error),
+            ConnectionCloseReason::Other(error)
+        );
+    }
+}
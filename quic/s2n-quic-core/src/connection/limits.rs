@@ -9,8 +9,9 @@ use crate::{
         AckDelayExponent, ActiveConnectionIdLimit, InitialFlowControlLimits, InitialMaxData,
         InitialMaxStreamDataBidiLocal, InitialMaxStreamDataBidiRemote, InitialMaxStreamDataUni,
         InitialMaxStreamsBidi, InitialMaxStreamsUni, InitialStreamLimits, MaxAckDelay,
-        MaxDatagramFrameSize, MaxIdleTimeout, TransportParameters,
+        MaxDatagramFrameSize, MaxIdleTimeout, MigrationSupport, TransportParameters,
     },
+    varint::VarInt,
 };
 use core::{convert::TryInto, time::Duration};
 use s2n_codec::decoder_invariant;
@@ -29,6 +30,12 @@ const MAX_HANDSHAKE_DURATION_DEFAULT: Duration = Duration::from_secs(10);
 //# middleboxes from losing state for UDP flows [GATEWAY].
 const MAX_KEEP_ALIVE_PERIOD_DEFAULT: Duration = Duration::from_secs(30);
 
+const DATA_WINDOW_GROWTH_FACTOR_DEFAULT: u8 = 2;
+
+/// By default, a packet from each of the Initial, Handshake and ApplicationData spaces can be
+/// coalesced into a single datagram
+const MAX_COALESCED_PACKETS_DEFAULT: u8 = 3;
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ConnectionInfo<'a> {
@@ -49,6 +56,8 @@ impl<'a> ConnectionInfo<'a> {
 pub struct Limits {
     pub(crate) max_idle_timeout: MaxIdleTimeout,
     pub(crate) data_window: InitialMaxData,
+    pub(crate) max_data_window: Option<InitialMaxData>,
+    pub(crate) data_window_growth_factor: u8,
     pub(crate) bidirectional_local_data_window: InitialMaxStreamDataBidiLocal,
     pub(crate) bidirectional_remote_data_window: InitialMaxStreamDataBidiRemote,
     pub(crate) unidirectional_data_window: InitialMaxStreamDataUni,
@@ -62,10 +71,13 @@ pub struct Limits {
     pub(crate) ack_elicitation_interval: u8,
     pub(crate) ack_ranges_limit: u8,
     pub(crate) max_send_buffer_size: stream::limits::MaxSendBufferSize,
+    pub(crate) send_buffer_low_watermark: stream::limits::SendBufferLowWatermark,
     pub(crate) max_handshake_duration: Duration,
     pub(crate) max_keep_alive_period: Duration,
     pub(crate) max_datagram_frame_size: MaxDatagramFrameSize,
     pub(crate) initial_round_trip_time: Duration,
+    pub(crate) migration_support: MigrationSupport,
+    pub(crate) max_coalesced_packets: u8,
 }
 
 impl Default for Limits {
@@ -93,6 +105,8 @@ impl Limits {
         Self {
             max_idle_timeout: MaxIdleTimeout::RECOMMENDED,
             data_window: InitialMaxData::RECOMMENDED,
+            max_data_window: None,
+            data_window_growth_factor: DATA_WINDOW_GROWTH_FACTOR_DEFAULT,
             bidirectional_local_data_window: InitialMaxStreamDataBidiLocal::RECOMMENDED,
             bidirectional_remote_data_window: InitialMaxStreamDataBidiRemote::RECOMMENDED,
             unidirectional_data_window: InitialMaxStreamDataUni::RECOMMENDED,
@@ -106,10 +120,13 @@ impl Limits {
             ack_elicitation_interval: ack::Settings::RECOMMENDED.ack_elicitation_interval,
             ack_ranges_limit: ack::Settings::RECOMMENDED.ack_ranges_limit,
             max_send_buffer_size: stream::Limits::RECOMMENDED.max_send_buffer_size,
+            send_buffer_low_watermark: stream::Limits::RECOMMENDED.send_buffer_low_watermark,
             max_handshake_duration: MAX_HANDSHAKE_DURATION_DEFAULT,
             max_keep_alive_period: MAX_KEEP_ALIVE_PERIOD_DEFAULT,
             max_datagram_frame_size: MaxDatagramFrameSize::DEFAULT,
             initial_round_trip_time: recovery::DEFAULT_INITIAL_RTT,
+            migration_support: MigrationSupport::Enabled,
+            max_coalesced_packets: MAX_COALESCED_PACKETS_DEFAULT,
         }
     }
 
@@ -125,6 +142,33 @@ impl Limits {
             "data_window must be <= u32::MAX"
         );
     });
+    /// Sets the maximum size the connection receive window can be auto-tuned to
+    ///
+    /// The connection starts out with the `data_window`. Each time the application
+    /// consumes a full window of data in less than two round trips, the window is the
+    /// bottleneck for the connection and is grown by the `data_window_growth_factor`,
+    /// up to this value. Auto-tuning is disabled if this value is not larger than the
+    /// `data_window`, which is the default.
+    pub fn with_max_data_window(mut self, value: u64) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            value <= u32::MAX.into(),
+            "max_data_window must be <= u32::MAX"
+        );
+        self.max_data_window = Some(value.try_into()?);
+        Ok(self)
+    }
+    setter!(
+        /// Sets the factor the connection receive window is multiplied by when auto-tuned
+        with_data_window_growth_factor,
+        data_window_growth_factor,
+        u8,
+        |validate_value| {
+            decoder_invariant!(
+                validate_value > 1,
+                "data_window_growth_factor must be > 1"
+            );
+        }
+    );
     setter!(
         with_bidirectional_local_data_window,
         bidirectional_local_data_window,
@@ -230,12 +274,37 @@ impl Limits {
         max_send_buffer_size,
         u32
     );
+    setter!(
+        /// Sets the send buffer low watermark for a Stream
+        ///
+        /// Once the send buffer of a Stream reaches the maximum send buffer size, the application
+        /// is not notified that it can write more data until the amount of buffered data drops
+        /// to this watermark. Lower values cause the application to write in fewer, larger
+        /// batches. Values greater than the maximum send buffer size are capped to it, which
+        /// resumes the application as soon as any space is available. This is the default.
+        with_send_buffer_low_watermark,
+        send_buffer_low_watermark,
+        u32
+    );
     setter!(
         with_max_handshake_duration,
         max_handshake_duration,
         Duration
     );
     setter!(with_max_keep_alive_period, max_keep_alive_period, Duration);
+    setter!(
+        /// Sets the maximum number of QUIC packets that are coalesced into a single datagram
+        ///
+        /// At most one packet is written from each packet number space, so values above 3 have
+        /// no effect. Lowering the limit bounds the work needed to process each datagram at the
+        /// cost of sending more datagrams during the handshake.
+        with_max_coalesced_packets,
+        max_coalesced_packets,
+        u8,
+        |validate_value| {
+            decoder_invariant!(validate_value > 0, "max_coalesced_packets must be > 0");
+        }
+    );
 
     /// Sets the initial round trip time (RTT) for use in recovery mechanisms prior to
     /// measuring an actual RTT sample.
@@ -257,6 +326,21 @@ impl Limits {
         Ok(self)
     }
 
+    /// Sets whether the peer is allowed to actively migrate the connection to a new address
+    ///
+    /// When disabled, the `disable_active_migration` transport parameter is sent to the peer.
+    /// Packets the peer sends from a new address with a new connection ID are then dropped.
+    /// Address changes that keep the same connection ID, which are usually caused by NAT
+    /// rebinding, are still validated.
+    pub fn with_active_migration(mut self, enabled: bool) -> Result<Self, ValidationError> {
+        self.migration_support = if enabled {
+            MigrationSupport::Enabled
+        } else {
+            MigrationSupport::Disabled
+        };
+        Ok(self)
+    }
+
     // internal APIs
 
     #[doc(hidden)]
@@ -292,6 +376,27 @@ impl Limits {
         }
     }
 
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_data_window(&self) -> VarInt {
+        let data_window = self.data_window.as_varint();
+        self.max_data_window.map_or(data_window, |max_data_window| {
+            max_data_window.as_varint().max(data_window)
+        })
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn data_window_growth_factor(&self) -> u8 {
+        self.data_window_growth_factor
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_coalesced_packets(&self) -> u8 {
+        self.max_coalesced_packets
+    }
+
     #[doc(hidden)]
     #[inline]
     pub const fn initial_stream_limits(&self) -> InitialStreamLimits {
@@ -307,6 +412,7 @@ impl Limits {
     pub fn stream_limits(&self) -> stream::Limits {
         stream::Limits {
             max_send_buffer_size: self.max_send_buffer_size,
+            send_buffer_low_watermark: self.send_buffer_low_watermark,
             max_open_local_unidirectional_streams: self.max_open_local_unidirectional_streams,
             max_open_local_bidirectional_streams: self.max_open_local_bidirectional_streams,
         }
@@ -335,6 +441,12 @@ impl Limits {
     pub fn initial_round_trip_time(&self) -> Duration {
         self.initial_round_trip_time
     }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn migration_support(&self) -> MigrationSupport {
+        self.migration_support
+    }
 }
 
 /// Creates limits for a given connection
@@ -359,14 +471,59 @@ mod tests {
         let mut data = u32::MAX as u64 + 1;
         let limits = Limits::default();
         assert!(limits.with_data_window(data).is_err());
+        assert!(limits.with_max_data_window(data).is_err());
         assert!(limits.with_bidirectional_local_data_window(data).is_err());
         assert!(limits.with_bidirectional_remote_data_window(data).is_err());
         assert!(limits.with_unidirectional_data_window(data).is_err());
 
         data = u32::MAX as u64;
         assert!(limits.with_data_window(data).is_ok());
+        assert!(limits.with_max_data_window(data).is_ok());
         assert!(limits.with_bidirectional_local_data_window(data).is_ok());
         assert!(limits.with_bidirectional_remote_data_window(data).is_ok());
         assert!(limits.with_unidirectional_data_window(data).is_ok());
     }
+
+    #[test]
+    fn max_coalesced_packets_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.max_coalesced_packets(), 3);
+        assert!(limits.with_max_coalesced_packets(0).is_err());
+        let limits = limits.with_max_coalesced_packets(1).unwrap();
+        assert_eq!(limits.max_coalesced_packets(), 1);
+    }
+
+    #[test]
+    fn data_window_tuning_validation() {
+        let limits = Limits::default();
+        assert!(limits.with_data_window_growth_factor(0).is_err());
+        assert!(limits.with_data_window_growth_factor(1).is_err());
+        assert!(limits.with_data_window_growth_factor(2).is_ok());
+
+        // the max data window can never be smaller than the initial window
+        let limits = limits
+            .with_data_window(2000)
+            .unwrap()
+            .with_max_data_window(1000)
+            .unwrap();
+        assert_eq!(limits.max_data_window(), VarInt::from_u32(2000));
+
+        let limits = limits.with_max_data_window(8000).unwrap();
+        assert_eq!(limits.max_data_window(), VarInt::from_u32(8000));
+    }
+
+    #[test]
+    fn max_data_window_default() {
+        // auto-tuning is disabled by default
+        let limits = Limits::default();
+        assert_eq!(limits.max_data_window(), limits.data_window.as_varint());
+
+        // lowering the data window alone keeps the window fixed
+        let limits = limits.with_data_window(1000).unwrap();
+        assert_eq!(limits.max_data_window(), VarInt::from_u32(1000));
+
+        // raising it also raises the max data window
+        let limits = limits.with_data_window(u32::MAX.into()).unwrap();
+        assert_eq!(limits.max_data_window(), VarInt::from_u32(u32::MAX));
+    }
 }
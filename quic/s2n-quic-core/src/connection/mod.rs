@@ -5,7 +5,12 @@ pub mod close;
 pub mod error;
 pub mod id;
 pub mod limits;
+pub mod peer_transport_parameters;
+pub mod stats;
 
+pub use close::ConnectionCloseReason;
 pub use error::{Error, ProcessingError};
 pub use id::{InitialId, LocalId, PeerId, UnboundedId};
 pub use limits::Limits;
+pub use peer_transport_parameters::PeerTransportParameters;
+pub use stats::ConnectionStats;
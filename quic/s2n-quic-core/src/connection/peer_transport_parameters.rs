@@ -0,0 +1,137 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transport::parameters::{MigrationSupport, TransportParameters};
+use core::time::Duration;
+
+/// The transport parameters advertised by the peer during the handshake
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PeerTransportParameters {
+    /// The idle timeout of the peer, or `None` if the peer disabled it
+    pub max_idle_timeout: Option<Duration>,
+    /// The largest UDP payload the peer is willing to receive
+    pub max_udp_payload_size: u64,
+    /// The initial connection-level flow control limit
+    pub initial_max_data: u64,
+    /// The initial flow control limit for bidirectional streams opened by the peer
+    pub initial_max_stream_data_bidi_local: u64,
+    /// The initial flow control limit for bidirectional streams opened by the local endpoint
+    pub initial_max_stream_data_bidi_remote: u64,
+    /// The initial flow control limit for unidirectional streams opened by the local endpoint
+    pub initial_max_stream_data_uni: u64,
+    /// The number of bidirectional streams the local endpoint is initially allowed to open
+    pub initial_max_streams_bidi: u64,
+    /// The number of unidirectional streams the local endpoint is initially allowed to open
+    pub initial_max_streams_uni: u64,
+    /// The largest DATAGRAM frame the peer is willing to receive, or `0` if it doesn't
+    /// support datagrams
+    pub max_datagram_frame_size: u64,
+    /// The exponent the peer uses to encode the ACK Delay field
+    pub ack_delay_exponent: u8,
+    /// The maximum amount of time the peer will delay sending acknowledgments
+    pub max_ack_delay: Duration,
+    /// The number of connection IDs from the local endpoint the peer is willing to store
+    pub active_connection_id_limit: u64,
+    /// Whether the peer disabled active connection migration
+    pub migration_disabled: bool,
+}
+
+impl<A, B, C, D> From<&TransportParameters<A, B, C, D>> for PeerTransportParameters {
+    fn from(parameters: &TransportParameters<A, B, C, D>) -> Self {
+        Self {
+            max_idle_timeout: parameters.max_idle_timeout.as_duration(),
+            max_udp_payload_size: parameters.max_udp_payload_size.as_u64(),
+            initial_max_data: parameters.initial_max_data.as_u64(),
+            initial_max_stream_data_bidi_local: parameters
+                .initial_max_stream_data_bidi_local
+                .as_u64(),
+            initial_max_stream_data_bidi_remote: parameters
+                .initial_max_stream_data_bidi_remote
+                .as_u64(),
+            initial_max_stream_data_uni: parameters.initial_max_stream_data_uni.as_u64(),
+            initial_max_streams_bidi: parameters.initial_max_streams_bidi.as_u64(),
+            initial_max_streams_uni: parameters.initial_max_streams_uni.as_u64(),
+            max_datagram_frame_size: parameters.max_datagram_frame_size.as_u64(),
+            ack_delay_exponent: *parameters.ack_delay_exponent,
+            max_ack_delay: parameters.max_ack_delay.as_duration(),
+            active_connection_id_limit: parameters.active_connection_id_limit.as_u64(),
+            migration_disabled: parameters.migration_support == MigrationSupport::Disabled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::parameters::ClientTransportParameters;
+    use s2n_codec::{DecoderBuffer, DecoderValue};
+
+    #[test]
+    fn decoded_parameters_test() {
+        #[rustfmt::skip]
+        let extension = [
+            // max_idle_timeout: 30000ms
+            0x01, 0x04, 0x80, 0x00, 0x75, 0x30,
+            // max_udp_payload_size: 1472
+            0x03, 0x02, 0x45, 0xc0,
+            // initial_max_data: 1048576
+            0x04, 0x04, 0x80, 0x10, 0x00, 0x00,
+            // initial_max_stream_data_bidi_local: 65536
+            0x05, 0x04, 0x80, 0x01, 0x00, 0x00,
+            // initial_max_stream_data_bidi_remote: 32768
+            0x06, 0x04, 0x80, 0x00, 0x80, 0x00,
+            // initial_max_stream_data_uni: 16384
+            0x07, 0x04, 0x80, 0x00, 0x40, 0x00,
+            // initial_max_streams_bidi: 100
+            0x08, 0x02, 0x40, 0x64,
+            // initial_max_streams_uni: 3
+            0x09, 0x01, 0x03,
+            // ack_delay_exponent: 2
+            0x0a, 0x01, 0x02,
+            // max_ack_delay: 20ms
+            0x0b, 0x01, 0x14,
+            // disable_active_migration
+            0x0c, 0x00,
+            // active_connection_id_limit: 4
+            0x0e, 0x01, 0x04,
+            // max_datagram_frame_size: 65535
+            0x20, 0x04, 0x80, 0x00, 0xff, 0xff,
+        ];
+
+        let (parameters, remaining) =
+            ClientTransportParameters::decode(DecoderBuffer::new(&extension)).unwrap();
+        assert!(remaining.is_empty());
+
+        let parameters = PeerTransportParameters::from(&parameters);
+        assert_eq!(parameters.max_idle_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(parameters.max_udp_payload_size, 1472);
+        assert_eq!(parameters.initial_max_data, 1_048_576);
+        assert_eq!(parameters.initial_max_stream_data_bidi_local, 65_536);
+        assert_eq!(parameters.initial_max_stream_data_bidi_remote, 32_768);
+        assert_eq!(parameters.initial_max_stream_data_uni, 16_384);
+        assert_eq!(parameters.initial_max_streams_bidi, 100);
+        assert_eq!(parameters.initial_max_streams_uni, 3);
+        assert_eq!(parameters.ack_delay_exponent, 2);
+        assert_eq!(parameters.max_ack_delay, Duration::from_millis(20));
+        assert!(parameters.migration_disabled);
+        assert_eq!(parameters.active_connection_id_limit, 4);
+        assert_eq!(parameters.max_datagram_frame_size, 65_535);
+    }
+
+    #[test]
+    fn default_parameters_test() {
+        // an empty extension results in the default value of each parameter
+        let (parameters, _) = ClientTransportParameters::decode(DecoderBuffer::new(&[])).unwrap();
+        let parameters = PeerTransportParameters::from(&parameters);
+
+        assert_eq!(parameters.max_idle_timeout, None);
+        assert_eq!(parameters.max_udp_payload_size, 65_527);
+        assert_eq!(parameters.initial_max_data, 0);
+        assert_eq!(parameters.ack_delay_exponent, 3);
+        assert_eq!(parameters.max_ack_delay, Duration::from_millis(25));
+        assert!(!parameters.migration_disabled);
+        assert_eq!(parameters.active_connection_id_limit, 2);
+        assert_eq!(parameters.max_datagram_frame_size, 0);
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+/// A snapshot of the counters and congestion state of a connection
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ConnectionStats {
+    /// The number of packets sent across all packet number spaces
+    pub packets_sent: u64,
+    /// The number of packets received from the peer and successfully processed
+    pub packets_received: u64,
+    /// The number of bytes sent in packets, including headers and authentication tags
+    pub bytes_sent: u64,
+    /// The number of bytes received in datagrams from the peer
+    pub bytes_received: u64,
+    /// The number of sent packets that were declared lost
+    ///
+    /// Any frames in a lost packet that require retransmission are sent again in new packets.
+    pub packets_lost: u64,
+    /// The number of packets declared lost that were later acknowledged by the peer
+    ///
+    /// The frames in these packets were retransmitted unnecessarily. A high count relative to
+    /// `packets_lost` indicates the loss detection thresholds are too aggressive for the path.
+    pub spurious_retransmissions: u64,
+    /// The smoothed round trip time of the active path
+    pub smoothed_rtt: Duration,
+    /// The congestion window of the active path, in bytes
+    pub congestion_window: u32,
+}
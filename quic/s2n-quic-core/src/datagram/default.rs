@@ -26,6 +26,7 @@ use core::{
 #[derive(Debug, Default)]
 pub struct Endpoint {
     send_queue_capacity: usize,
+    send_drop_policy: DropPolicy,
     recv_queue_capacity: usize,
 }
 
@@ -40,6 +41,7 @@ impl Endpoint {
 #[derive(Debug, Default)]
 pub struct EndpointBuilder {
     send_queue_capacity: usize,
+    send_drop_policy: DropPolicy,
     recv_queue_capacity: usize,
 }
 
@@ -72,6 +74,13 @@ impl EndpointBuilder {
         Ok(self)
     }
 
+    /// Sets which datagram is dropped by [`Sender::send_datagram_with_policy`] when the send
+    /// queue is full
+    pub fn with_send_drop_policy(mut self, policy: DropPolicy) -> Result<Self, BuilderError> {
+        self.send_drop_policy = policy;
+        Ok(self)
+    }
+
     pub fn with_recv_capacity(mut self, capacity: usize) -> Result<Self, BuilderError> {
         if capacity == 0 {
             return Err(BuilderError::ZeroCapacity);
@@ -83,6 +92,7 @@ impl EndpointBuilder {
     pub fn build(self) -> Result<Endpoint, core::convert::Infallible> {
         Ok(Endpoint {
             send_queue_capacity: self.send_queue_capacity,
+            send_drop_policy: self.send_drop_policy,
             recv_queue_capacity: self.recv_queue_capacity,
         })
     }
@@ -96,6 +106,7 @@ impl super::Endpoint for Endpoint {
         (
             Sender::builder()
                 .with_capacity(self.send_queue_capacity)
+                .with_drop_policy(self.send_drop_policy)
                 .with_connection_info(info)
                 .build()
                 .unwrap(),
@@ -251,6 +262,7 @@ impl ReceiverBuilder {
 pub struct Sender {
     queue: VecDeque<Datagram>,
     capacity: usize,
+    drop_policy: DropPolicy,
     min_packet_space: usize,
     max_packet_space: usize,
     smoothed_packet_size: f64,
@@ -265,6 +277,16 @@ pub struct Datagram {
     pub data: Bytes,
 }
 
+/// Determines which datagram is dropped when the send queue is full
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// The oldest datagram on the queue is dropped to make room for the new one
+    DropOldest,
+    /// The new datagram is dropped and the queue is left unchanged
+    #[default]
+    DropNewest,
+}
+
 #[non_exhaustive]
 #[derive(Debug, PartialEq)]
 pub enum DatagramError {
@@ -285,7 +307,7 @@ impl fmt::Display for DatagramError {
             Self::ExceedsPeerTransportLimits { .. } => {
                 write!(
                     f,
-                    "Datagram size is larger than peer's transport parameters."
+                    "Datagram size is larger than peer's transport parameters or the path MTU allow."
                 )
             }
             Self::ConnectionError { .. } => {
@@ -404,6 +426,21 @@ impl Sender {
         Ok(())
     }
 
+    /// Adds a datagram on the queue to be sent, applying the configured [`DropPolicy`] if the
+    /// queue is full
+    ///
+    /// # Return value
+    /// - `Ok()` if the datagram was enqueued for sending
+    /// - `Err(DatagramError::QueueAtCapacity)` if the queue is full and the policy is
+    ///   [`DropPolicy::DropNewest`]
+    /// - `Err(DatagramError)` if some other error occurred
+    pub fn send_datagram_with_policy(&mut self, data: bytes::Bytes) -> Result<(), DatagramError> {
+        match self.drop_policy {
+            DropPolicy::DropOldest => self.send_datagram_forced(data).map(|_dropped| ()),
+            DropPolicy::DropNewest => self.send_datagram(data),
+        }
+    }
+
     /// Filter through the datagrams in the send queue and only keep those that
     /// match a predicate
     pub fn retain_datagrams<F>(&mut self, f: F)
@@ -509,6 +546,7 @@ impl super::Sender for Sender {
 #[derive(Debug)]
 struct SenderBuilder {
     queue_capacity: usize,
+    drop_policy: DropPolicy,
     max_datagram_payload: u64,
 }
 
@@ -516,6 +554,7 @@ impl Default for SenderBuilder {
     fn default() -> Self {
         Self {
             queue_capacity: 200,
+            drop_policy: DropPolicy::default(),
             max_datagram_payload: 0,
         }
     }
@@ -528,6 +567,12 @@ impl SenderBuilder {
         self
     }
 
+    /// Sets which datagram is dropped when the sender queue is full
+    pub fn with_drop_policy(mut self, policy: DropPolicy) -> Self {
+        self.drop_policy = policy;
+        self
+    }
+
     /// Gives the default sender relevant connection info
     pub fn with_connection_info(mut self, connection_info: &ConnectionInfo) -> Self {
         self.max_datagram_payload = connection_info.max_datagram_payload;
@@ -539,6 +584,7 @@ impl SenderBuilder {
         Ok(Sender {
             queue: VecDeque::with_capacity(self.queue_capacity),
             capacity: self.queue_capacity,
+            drop_policy: self.drop_policy,
             max_datagram_payload: self.max_datagram_payload,
             max_packet_space: 0,
             min_packet_space: 0,
@@ -636,6 +682,90 @@ mod tests {
         assert!(default_sender.queue.is_empty());
     }
 
+    #[test]
+    fn send_datagram_exceeds_peer_limit() {
+        let conn_info = ConnectionInfo {
+            max_datagram_payload: 3,
+            waker: noop_waker(),
+        };
+        let mut default_sender = Sender::builder()
+            .with_capacity(2)
+            .with_connection_info(&conn_info)
+            .build()
+            .unwrap();
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        // A datagram that is exactly the size of the limit is accepted
+        let datagram = bytes::Bytes::from_static(&[1, 2, 3]);
+        assert_eq!(default_sender.send_datagram(datagram), Ok(()));
+
+        // Datagrams larger than the limit are rejected by each of the send methods
+        let oversized = bytes::Bytes::from_static(&[1, 2, 3, 4]);
+        assert_eq!(
+            default_sender.send_datagram(oversized.clone()),
+            Err(DatagramError::ExceedsPeerTransportLimits)
+        );
+        assert_eq!(
+            default_sender.send_datagram_forced(oversized.clone()),
+            Err(DatagramError::ExceedsPeerTransportLimits)
+        );
+        let mut data = oversized.clone();
+        assert_eq!(
+            default_sender.poll_send_datagram(&mut data, &mut cx),
+            Poll::Ready(Err(DatagramError::ExceedsPeerTransportLimits))
+        );
+        // The rejected datagram is left with the caller
+        assert_eq!(data, oversized);
+
+        // Only the datagram that fit is on the queue
+        assert_eq!(default_sender.queue.len(), 1);
+    }
+
+    #[test]
+    fn send_datagram_with_policy() {
+        let conn_info = ConnectionInfo::new(100, noop_waker());
+        let datagrams = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+        for (policy, expected) in [
+            (DropPolicy::DropOldest, [[4, 5, 6], [7, 8, 9]]),
+            (DropPolicy::DropNewest, [[1, 2, 3], [4, 5, 6]]),
+        ] {
+            // Create a default sender queue that only holds two elements
+            let mut default_sender = Sender::builder()
+                .with_capacity(2)
+                .with_drop_policy(policy)
+                .with_connection_info(&conn_info)
+                .build()
+                .unwrap();
+
+            for (idx, datagram) in datagrams.iter().enumerate() {
+                let result = default_sender
+                    .send_datagram_with_policy(bytes::Bytes::copy_from_slice(datagram));
+
+                // Only the newest datagram is rejected once the queue is full
+                if idx == 2 && policy == DropPolicy::DropNewest {
+                    assert_eq!(result, Err(DatagramError::QueueAtCapacity));
+                } else {
+                    assert_eq!(result, Ok(()));
+                }
+            }
+
+            // The remaining datagrams are transmitted in the order they were queued
+            for datagram in expected {
+                assert_eq!(default_sender.queue.front().unwrap().data[..], datagram);
+                let mut packet = MockPacket {
+                    remaining_capacity: datagram.len(),
+                    has_pending_streams: false,
+                    datagrams_prioritized: false,
+                };
+                crate::datagram::Sender::on_transmit(&mut default_sender, &mut packet);
+                assert_eq!(packet.remaining_capacity, 0);
+            }
+            assert!(default_sender.queue.is_empty());
+        }
+    }
+
     #[test]
     fn poll_send_datagram() {
         let conn_info = ConnectionInfo::new(100, noop_waker());
@@ -23,8 +23,9 @@ pub trait Endpoint: 'static + Send {
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ConnectionInfo {
-    /// The peer's limit on the size of datagrams they will accept. Datagrams larger than this will
-    /// result in an error.
+    /// The largest datagram payload that can be sent. This is the peer's limit on the size of
+    /// datagrams they will accept, capped by what fits in a single packet at the path's maximum
+    /// MTU. Datagrams larger than this will result in an error.
     pub max_datagram_payload: u64,
 
     /// The `waker` associated with this connection. When woken, the connection will check the
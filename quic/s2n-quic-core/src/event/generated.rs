@@ -651,6 +651,19 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A packet was received from a peer address that differs from the active path"]
+    #[doc = ""]
+    #[doc = " This is emitted once for each new peer address and can be caused by either NAT"]
+    #[doc = " rebinding or the peer actively migrating."]
+    pub struct PeerAddressChanged<'a> {
+        pub old: SocketAddress<'a>,
+        pub new: SocketAddress<'a>,
+    }
+    impl<'a> Event for PeerAddressChanged<'a> {
+        const NAME: &'static str = "connectivity:peer_address_changed";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Frame was sent"]
     pub struct FrameSent {
         pub packet_header: PacketHeader,
@@ -703,6 +716,25 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The congestion and pacing budget of the path when an application packet was written"]
+    #[doc = ""]
+    #[doc = " This is emitted for every 1-RTT packet, other than the final CONNECTION_CLOSE, and is"]
+    #[doc = " useful for diagnosing why a transfer isn't using all of the available congestion window."]
+    pub struct TransmissionBudget {
+        pub path_id: u64,
+        pub congestion_window: u32,
+        #[doc = " The bytes in flight before the packet was sent"]
+        pub bytes_in_flight: u32,
+        #[doc = " The maximum burst size allowed by the pacer, if the congestion controller sets one"]
+        pub send_quantum: Option<usize>,
+        #[doc = " The number of bytes written to the packet"]
+        pub bytes_written: usize,
+    }
+    impl Event for TransmissionBudget {
+        const NAME: &'static str = "recovery:transmission_budget";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Congestion (ECN or packet loss) has occurred"]
     pub struct Congestion<'a> {
         pub path: Path<'a>,
@@ -927,6 +959,25 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " Path validation completed for a path"]
+    #[doc = ""]
+    #[doc = " This is emitted when a PATH_RESPONSE validates the path or when the challenge is abandoned"]
+    #[doc = " after its timer expires. The time validation took is the difference between"]
+    #[doc = " `challenge_sent_at` and `completed_at`."]
+    pub struct PathValidationUpdated<'a> {
+        pub status: PathChallengeStatus,
+        pub local_addr: SocketAddress<'a>,
+        pub remote_addr: SocketAddress<'a>,
+        #[doc = " When the first PATH_CHALLENGE for the path was sent"]
+        pub challenge_sent_at: crate::event::Timestamp,
+        #[doc = " When the path was validated or the challenge was abandoned"]
+        pub completed_at: crate::event::Timestamp,
+    }
+    impl<'a> Event for PathValidationUpdated<'a> {
+        const NAME: &'static str = "connectivity:path_validation_updated";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct TlsClientHello<'a> {
         pub payload: &'a [&'a [u8]],
     }
@@ -1757,6 +1808,17 @@ pub mod tracing {
             tracing :: event ! (target : "path_created" , parent : id , tracing :: Level :: DEBUG , active = tracing :: field :: debug (active) , new = tracing :: field :: debug (new));
         }
         #[inline]
+        fn on_peer_address_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::PeerAddressChanged,
+        ) {
+            let id = context.id();
+            let api::PeerAddressChanged { old, new } = event;
+            tracing :: event ! (target : "peer_address_changed" , parent : id , tracing :: Level :: DEBUG , old = tracing :: field :: debug (old) , new = tracing :: field :: debug (new));
+        }
+        #[inline]
         fn on_frame_sent(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -1825,6 +1887,23 @@ pub mod tracing {
             tracing :: event ! (target : "recovery_metrics" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , min_rtt = tracing :: field :: debug (min_rtt) , smoothed_rtt = tracing :: field :: debug (smoothed_rtt) , latest_rtt = tracing :: field :: debug (latest_rtt) , rtt_variance = tracing :: field :: debug (rtt_variance) , max_ack_delay = tracing :: field :: debug (max_ack_delay) , pto_count = tracing :: field :: debug (pto_count) , congestion_window = tracing :: field :: debug (congestion_window) , bytes_in_flight = tracing :: field :: debug (bytes_in_flight) , congestion_limited = tracing :: field :: debug (congestion_limited));
         }
         #[inline]
+        fn on_transmission_budget(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::TransmissionBudget,
+        ) {
+            let id = context.id();
+            let api::TransmissionBudget {
+                path_id,
+                congestion_window,
+                bytes_in_flight,
+                send_quantum,
+                bytes_written,
+            } = event;
+            tracing :: event ! (target : "transmission_budget" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , congestion_window = tracing :: field :: debug (congestion_window) , bytes_in_flight = tracing :: field :: debug (bytes_in_flight) , send_quantum = tracing :: field :: debug (send_quantum) , bytes_written = tracing :: field :: debug (bytes_written));
+        }
+        #[inline]
         fn on_congestion(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -2088,6 +2167,23 @@ pub mod tracing {
             tracing :: event ! (target : "path_challenge_updated" , parent : id , tracing :: Level :: DEBUG , path_challenge_status = tracing :: field :: debug (path_challenge_status) , path = tracing :: field :: debug (path) , challenge_data = tracing :: field :: debug (challenge_data));
         }
         #[inline]
+        fn on_path_validation_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::PathValidationUpdated,
+        ) {
+            let id = context.id();
+            let api::PathValidationUpdated {
+                status,
+                local_addr,
+                remote_addr,
+                challenge_sent_at,
+                completed_at,
+            } = event;
+            tracing :: event ! (target : "path_validation_updated" , parent : id , tracing :: Level :: DEBUG , status = tracing :: field :: debug (status) , local_addr = tracing :: field :: debug (local_addr) , remote_addr = tracing :: field :: debug (remote_addr) , challenge_sent_at = tracing :: field :: debug (challenge_sent_at) , completed_at = tracing :: field :: debug (completed_at));
+        }
+        #[inline]
         fn on_tls_client_hello(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -3564,6 +3660,25 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " A packet was received from a peer address that differs from the active path"]
+    #[doc = ""]
+    #[doc = " This is emitted once for each new peer address and can be caused by either NAT"]
+    #[doc = " rebinding or the peer actively migrating."]
+    pub struct PeerAddressChanged<'a> {
+        pub old: SocketAddress<'a>,
+        pub new: SocketAddress<'a>,
+    }
+    impl<'a> IntoEvent<api::PeerAddressChanged<'a>> for PeerAddressChanged<'a> {
+        #[inline]
+        fn into_event(self) -> api::PeerAddressChanged<'a> {
+            let PeerAddressChanged { old, new } = self;
+            api::PeerAddressChanged {
+                old: old.into_event(),
+                new: new.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Frame was sent"]
     pub struct FrameSent {
         pub packet_header: PacketHeader,
@@ -3676,6 +3791,40 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " The congestion and pacing budget of the path when an application packet was written"]
+    #[doc = ""]
+    #[doc = " This is emitted for every 1-RTT packet, other than the final CONNECTION_CLOSE, and is"]
+    #[doc = " useful for diagnosing why a transfer isn't using all of the available congestion window."]
+    pub struct TransmissionBudget {
+        pub path_id: u64,
+        pub congestion_window: u32,
+        #[doc = " The bytes in flight before the packet was sent"]
+        pub bytes_in_flight: u32,
+        #[doc = " The maximum burst size allowed by the pacer, if the congestion controller sets one"]
+        pub send_quantum: Option<usize>,
+        #[doc = " The number of bytes written to the packet"]
+        pub bytes_written: usize,
+    }
+    impl IntoEvent<api::TransmissionBudget> for TransmissionBudget {
+        #[inline]
+        fn into_event(self) -> api::TransmissionBudget {
+            let TransmissionBudget {
+                path_id,
+                congestion_window,
+                bytes_in_flight,
+                send_quantum,
+                bytes_written,
+            } = self;
+            api::TransmissionBudget {
+                path_id: path_id.into_event(),
+                congestion_window: congestion_window.into_event(),
+                bytes_in_flight: bytes_in_flight.into_event(),
+                send_quantum: send_quantum.into_event(),
+                bytes_written: bytes_written.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Congestion (ECN or packet loss) has occurred"]
     pub struct Congestion<'a> {
         pub path: Path<'a>,
@@ -4054,6 +4203,40 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " Path validation completed for a path"]
+    #[doc = ""]
+    #[doc = " This is emitted when a PATH_RESPONSE validates the path or when the challenge is abandoned"]
+    #[doc = " after its timer expires. The time validation took is the difference between"]
+    #[doc = " `challenge_sent_at` and `completed_at`."]
+    pub struct PathValidationUpdated<'a> {
+        pub status: PathChallengeStatus,
+        pub local_addr: SocketAddress<'a>,
+        pub remote_addr: SocketAddress<'a>,
+        #[doc = " When the first PATH_CHALLENGE for the path was sent"]
+        pub challenge_sent_at: crate::time::Timestamp,
+        #[doc = " When the path was validated or the challenge was abandoned"]
+        pub completed_at: crate::time::Timestamp,
+    }
+    impl<'a> IntoEvent<api::PathValidationUpdated<'a>> for PathValidationUpdated<'a> {
+        #[inline]
+        fn into_event(self) -> api::PathValidationUpdated<'a> {
+            let PathValidationUpdated {
+                status,
+                local_addr,
+                remote_addr,
+                challenge_sent_at,
+                completed_at,
+            } = self;
+            api::PathValidationUpdated {
+                status: status.into_event(),
+                local_addr: local_addr.into_event(),
+                remote_addr: remote_addr.into_event(),
+                challenge_sent_at: challenge_sent_at.into_event(),
+                completed_at: completed_at.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     pub struct TlsClientHello<'a> {
         pub payload: &'a [&'a [u8]],
     }
@@ -4754,6 +4937,18 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `PeerAddressChanged` event is triggered"]
+        #[inline]
+        fn on_peer_address_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PeerAddressChanged,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `FrameSent` event is triggered"]
         #[inline]
         fn on_frame_sent(
@@ -4802,6 +4997,18 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `TransmissionBudget` event is triggered"]
+        #[inline]
+        fn on_transmission_budget(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransmissionBudget,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `Congestion` event is triggered"]
         #[inline]
         fn on_congestion(
@@ -5056,6 +5263,18 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `PathValidationUpdated` event is triggered"]
+        #[inline]
+        fn on_path_validation_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `TlsClientHello` event is triggered"]
         #[inline]
         fn on_tls_client_hello(
@@ -5457,6 +5676,16 @@ mod traits {
             (self.1).on_path_created(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_peer_address_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PeerAddressChanged,
+        ) {
+            (self.0).on_peer_address_changed(&mut context.0, meta, event);
+            (self.1).on_peer_address_changed(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_frame_sent(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -5497,6 +5726,16 @@ mod traits {
             (self.1).on_recovery_metrics(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_transmission_budget(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransmissionBudget,
+        ) {
+            (self.0).on_transmission_budget(&mut context.0, meta, event);
+            (self.1).on_transmission_budget(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_congestion(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -5708,6 +5947,16 @@ mod traits {
             (self.1).on_path_challenge_updated(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_path_validation_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationUpdated,
+        ) {
+            (self.0).on_path_validation_updated(&mut context.0, meta, event);
+            (self.1).on_path_validation_updated(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_tls_client_hello(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -6122,6 +6371,8 @@ mod traits {
         fn on_active_path_updated(&mut self, event: builder::ActivePathUpdated);
         #[doc = "Publishes a `PathCreated` event to the publisher's subscriber"]
         fn on_path_created(&mut self, event: builder::PathCreated);
+        #[doc = "Publishes a `PeerAddressChanged` event to the publisher's subscriber"]
+        fn on_peer_address_changed(&mut self, event: builder::PeerAddressChanged);
         #[doc = "Publishes a `FrameSent` event to the publisher's subscriber"]
         fn on_frame_sent(&mut self, event: builder::FrameSent);
         #[doc = "Publishes a `FrameReceived` event to the publisher's subscriber"]
@@ -6130,6 +6381,8 @@ mod traits {
         fn on_packet_lost(&mut self, event: builder::PacketLost);
         #[doc = "Publishes a `RecoveryMetrics` event to the publisher's subscriber"]
         fn on_recovery_metrics(&mut self, event: builder::RecoveryMetrics);
+        #[doc = "Publishes a `TransmissionBudget` event to the publisher's subscriber"]
+        fn on_transmission_budget(&mut self, event: builder::TransmissionBudget);
         #[doc = "Publishes a `Congestion` event to the publisher's subscriber"]
         fn on_congestion(&mut self, event: builder::Congestion);
         #[doc = "Publishes a `AckProcessed` event to the publisher's subscriber"]
@@ -6172,6 +6425,8 @@ mod traits {
         fn on_tls_exporter_ready(&mut self, event: builder::TlsExporterReady);
         #[doc = "Publishes a `PathChallengeUpdated` event to the publisher's subscriber"]
         fn on_path_challenge_updated(&mut self, event: builder::PathChallengeUpdated);
+        #[doc = "Publishes a `PathValidationUpdated` event to the publisher's subscriber"]
+        fn on_path_validation_updated(&mut self, event: builder::PathValidationUpdated);
         #[doc = "Publishes a `TlsClientHello` event to the publisher's subscriber"]
         fn on_tls_client_hello(&mut self, event: builder::TlsClientHello);
         #[doc = "Publishes a `TlsServerHello` event to the publisher's subscriber"]
@@ -6295,6 +6550,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_peer_address_changed(&mut self, event: builder::PeerAddressChanged) {
+            let event = event.into_event();
+            self.subscriber
+                .on_peer_address_changed(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_frame_sent(&mut self, event: builder::FrameSent) {
             let event = event.into_event();
             self.subscriber
@@ -6331,6 +6595,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_transmission_budget(&mut self, event: builder::TransmissionBudget) {
+            let event = event.into_event();
+            self.subscriber
+                .on_transmission_budget(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_congestion(&mut self, event: builder::Congestion) {
             let event = event.into_event();
             self.subscriber
@@ -6524,6 +6797,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_path_validation_updated(&mut self, event: builder::PathValidationUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_path_validation_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_tls_client_hello(&mut self, event: builder::TlsClientHello) {
             let event = event.into_event();
             self.subscriber
@@ -6637,10 +6919,12 @@ pub mod testing {
         pub packet_received: u32,
         pub active_path_updated: u32,
         pub path_created: u32,
+        pub peer_address_changed: u32,
         pub frame_sent: u32,
         pub frame_received: u32,
         pub packet_lost: u32,
         pub recovery_metrics: u32,
+        pub transmission_budget: u32,
         pub congestion: u32,
         pub ack_processed: u32,
         pub rx_ack_range_dropped: u32,
@@ -6662,6 +6946,7 @@ pub mod testing {
         pub handshake_status_updated: u32,
         pub tls_exporter_ready: u32,
         pub path_challenge_updated: u32,
+        pub path_validation_updated: u32,
         pub tls_client_hello: u32,
         pub tls_server_hello: u32,
         pub rx_stream_progress: u32,
@@ -6717,10 +7002,12 @@ pub mod testing {
                 packet_received: 0,
                 active_path_updated: 0,
                 path_created: 0,
+                peer_address_changed: 0,
                 frame_sent: 0,
                 frame_received: 0,
                 packet_lost: 0,
                 recovery_metrics: 0,
+                transmission_budget: 0,
                 congestion: 0,
                 ack_processed: 0,
                 rx_ack_range_dropped: 0,
@@ -6742,6 +7029,7 @@ pub mod testing {
                 handshake_status_updated: 0,
                 tls_exporter_ready: 0,
                 path_challenge_updated: 0,
+                path_validation_updated: 0,
                 tls_client_hello: 0,
                 tls_server_hello: 0,
                 rx_stream_progress: 0,
@@ -6854,6 +7142,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_peer_address_changed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PeerAddressChanged,
+        ) {
+            self.peer_address_changed += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_frame_sent(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6898,6 +7197,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_transmission_budget(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TransmissionBudget,
+        ) {
+            self.transmission_budget += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_congestion(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -7130,6 +7440,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_path_validation_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathValidationUpdated,
+        ) {
+            self.path_validation_updated += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_tls_client_hello(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -7348,10 +7669,12 @@ pub mod testing {
         pub packet_received: u32,
         pub active_path_updated: u32,
         pub path_created: u32,
+        pub peer_address_changed: u32,
         pub frame_sent: u32,
         pub frame_received: u32,
         pub packet_lost: u32,
         pub recovery_metrics: u32,
+        pub transmission_budget: u32,
         pub congestion: u32,
         pub ack_processed: u32,
         pub rx_ack_range_dropped: u32,
@@ -7373,6 +7696,7 @@ pub mod testing {
         pub handshake_status_updated: u32,
         pub tls_exporter_ready: u32,
         pub path_challenge_updated: u32,
+        pub path_validation_updated: u32,
         pub tls_client_hello: u32,
         pub tls_server_hello: u32,
         pub rx_stream_progress: u32,
@@ -7418,10 +7742,12 @@ pub mod testing {
                 packet_received: 0,
                 active_path_updated: 0,
                 path_created: 0,
+                peer_address_changed: 0,
                 frame_sent: 0,
                 frame_received: 0,
                 packet_lost: 0,
                 recovery_metrics: 0,
+                transmission_budget: 0,
                 congestion: 0,
                 ack_processed: 0,
                 rx_ack_range_dropped: 0,
@@ -7443,6 +7769,7 @@ pub mod testing {
                 handshake_status_updated: 0,
                 tls_exporter_ready: 0,
                 path_challenge_updated: 0,
+                path_validation_updated: 0,
                 tls_client_hello: 0,
                 tls_server_hello: 0,
                 rx_stream_progress: 0,
@@ -7601,6 +7928,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_peer_address_changed(&mut self, event: builder::PeerAddressChanged) {
+            self.peer_address_changed += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_frame_sent(&mut self, event: builder::FrameSent) {
             self.frame_sent += 1;
             let event = event.into_event();
@@ -7629,6 +7963,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_transmission_budget(&mut self, event: builder::TransmissionBudget) {
+            self.transmission_budget += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_congestion(&mut self, event: builder::Congestion) {
             self.congestion += 1;
             let event = event.into_event();
@@ -7780,6 +8121,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_path_validation_updated(&mut self, event: builder::PathValidationUpdated) {
+            self.path_validation_updated += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_tls_client_hello(&mut self, event: builder::TlsClientHello) {
             self.tls_client_hello += 1;
             let event = event.into_event();
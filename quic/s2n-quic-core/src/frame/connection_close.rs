@@ -146,3 +146,38 @@ impl<'a> EncoderValue for ConnectionClose<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{application::error::TryInto as _, frame::FrameMut};
+    use s2n_codec::DecoderBufferMut;
+
+    #[test]
+    fn application_close_round_trip() {
+        let reason = "connexion fermée 👋";
+        let frame = ConnectionClose {
+            error_code: VarInt::from_u32(0x2a),
+            frame_type: None,
+            reason: Some(reason.as_bytes()),
+        };
+        assert_eq!(frame.tag(), APPLICATION_ERROR_TAG);
+
+        let mut bytes = frame.encode_to_vec();
+        let (decoded, remaining) = DecoderBufferMut::new(&mut bytes)
+            .decode::<FrameMut>()
+            .unwrap();
+        assert!(remaining.is_empty());
+
+        let decoded = match decoded {
+            FrameMut::ConnectionClose(decoded) => decoded,
+            other => panic!("unexpected frame {other:?}"),
+        };
+        assert_eq!(decoded, frame);
+        assert_eq!(
+            decoded.application_error(),
+            Some(application::Error::from(VarInt::from_u32(0x2a)))
+        );
+        assert_eq!(core::str::from_utf8(decoded.reason.unwrap()), Ok(reason));
+    }
+}
@@ -96,4 +96,8 @@ impl EncoderValue for MaxStreams {
         buffer.encode(&self.tag());
         buffer.encode(&self.maximum_streams);
     }
+    #[inline]
+    fn encoding_size(&self) -> usize {
+        self.tag().encoding_size() + self.maximum_streams.encoding_size()
+    }
 }
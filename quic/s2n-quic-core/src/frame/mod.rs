@@ -45,6 +45,19 @@ macro_rules! frames {
 
         pub type RemainingBuffer<'a> = Option<DecoderBufferMut<'a>>;
 
+        /// Returns the tag and name of every frame type supported by the codec, ordered by tag
+        ///
+        /// Frame types which use several tags, such as STREAM, are returned once for each tag.
+        pub fn all_frame_types() -> impl Iterator<Item = (Tag, &'static str)> {
+            // single byte frame tags must fit into a small variable-integer
+            (0..0b0100_0000).filter_map(|tag: Tag| match tag {
+                $(
+                    $tag_macro!() => Some((tag, stringify!($ty))),
+                )*
+                _ => None,
+            })
+        }
+
         #[derive(Debug, PartialEq, Eq)]
         pub enum Frame<'a, $ack, $data> {
             $(
@@ -229,6 +242,12 @@ macro_rules! simple_frame_codec {
                     buffer.encode(&self.$field);
                 )*
             }
+
+            #[inline]
+            fn encoding_size(&self) -> usize {
+                s2n_codec::EncoderValue::encoding_size(&$tag)
+                    $(+ s2n_codec::EncoderValue::encoding_size(&self.$field))*
+            }
         }
     };
 }
@@ -131,4 +131,14 @@ impl<'a> EncoderValue for NewConnectionId<'a> {
         buffer.encode_with_len_prefix::<u8, _>(&self.connection_id);
         buffer.encode(&self.stateless_reset_token.as_ref());
     }
+    #[inline]
+    fn encoding_size(&self) -> usize {
+        self.tag().encoding_size()
+            + self.sequence_number.encoding_size()
+            + self.retire_prior_to.encoding_size()
+            // the connection id is prefixed with a single byte length
+            + 1
+            + self.connection_id.len()
+            + STATELESS_RESET_TOKEN_LEN
+    }
 }
@@ -62,3 +62,26 @@ impl<'a> EncoderValue for PathChallenge<'a> {
         buffer.encode(&self.data.as_ref());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::FrameMut;
+    use bolero::check;
+    use s2n_codec::DecoderBufferMut;
+
+    #[test]
+    fn round_trip() {
+        check!().with_type::<[u8; DATA_LEN]>().for_each(|data| {
+            let frame = PathChallenge { data };
+            let mut bytes = frame.encode_to_vec();
+
+            let (decoded, remaining) = DecoderBufferMut::new(&mut bytes)
+                .decode::<FrameMut>()
+                .unwrap();
+
+            assert!(remaining.is_empty());
+            assert_eq!(decoded, FrameMut::PathChallenge(PathChallenge { data }));
+        });
+    }
+}
@@ -57,3 +57,34 @@ impl<'a> EncoderValue for PathResponse<'a> {
         buffer.encode(&self.data.as_ref());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::FrameMut;
+    use bolero::check;
+    use s2n_codec::DecoderBufferMut;
+
+    #[test]
+    fn round_trip() {
+        check!().with_type::<[u8; DATA_LEN]>().for_each(|data| {
+            let frame = PathResponse { data };
+            let mut bytes = frame.encode_to_vec();
+
+            let (decoded, remaining) = DecoderBufferMut::new(&mut bytes)
+                .decode::<FrameMut>()
+                .unwrap();
+
+            assert!(remaining.is_empty());
+            assert_eq!(decoded, FrameMut::PathResponse(PathResponse { data }));
+        });
+    }
+
+    #[test]
+    fn response_echoes_challenge_data() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+        let response: PathResponse = PathChallenge { data: &data }.into();
+
+        assert_eq!(response.data, &data);
+    }
+}
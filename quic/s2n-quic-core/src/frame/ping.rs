@@ -26,3 +26,26 @@ impl Ping {
 }
 
 simple_frame_codec!(Ping {}, ping_tag!());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::{ack_elicitation::AckElicitable, FrameMut};
+    use s2n_codec::{DecoderBufferMut, DecoderValueMut, EncoderValue};
+
+    #[test]
+    fn round_trip_test() {
+        let mut bytes = Ping.encode_to_vec();
+        assert_eq!(bytes, [0x01]);
+
+        let (frame, remaining) = FrameMut::decode_mut(DecoderBufferMut::new(&mut bytes)).unwrap();
+        assert_eq!(frame, FrameMut::Ping(Ping));
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn ack_eliciting_test() {
+        // the peer is required to acknowledge the packet containing the PING frame
+        assert!(Ping.ack_elicitation().is_ack_eliciting());
+    }
+}
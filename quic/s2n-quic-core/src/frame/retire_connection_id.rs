@@ -1,7 +1,8 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::varint::VarInt;
+use crate::{frame::Tag, varint::VarInt};
+use s2n_codec::{decoder_invariant, DecoderBuffer, DecoderBufferResult};
 
 //= https://www.rfc-editor.org/rfc/rfc9000#section-19.16
 //# An endpoint sends a RETIRE_CONNECTION_ID frame (type=0x19) to
@@ -35,9 +36,109 @@ impl RetireConnectionId {
     pub const fn tag(self) -> u8 {
         retire_connection_id_tag!()
     }
+
+    /// Decodes a RETIRE_CONNECTION_ID frame, including its frame type, from the buffer
+    ///
+    /// Frames with a sequence number greater than `max_sequence_number` are rejected while
+    /// decoding. This allows callers which know the highest sequence number they have issued
+    /// to discard frames referring to connection IDs that can't exist.
+    pub fn decode_bounded(
+        buffer: DecoderBuffer,
+        max_sequence_number: VarInt,
+    ) -> DecoderBufferResult<Self> {
+        let (tag, buffer) = buffer.decode::<Tag>()?;
+        decoder_invariant!(
+            tag == retire_connection_id_tag!(),
+            "invalid RETIRE_CONNECTION_ID frame type"
+        );
+
+        let (frame, buffer) = buffer.decode_parameterized::<Self>(tag)?;
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-19.16
+        //# Receipt of a RETIRE_CONNECTION_ID frame containing a sequence number
+        //# greater than any previously sent to the peer MUST be treated as a
+        //# connection error of type PROTOCOL_VIOLATION.
+        decoder_invariant!(
+            frame.sequence_number <= max_sequence_number,
+            "sequence number exceeds the maximum issued sequence number"
+        );
+
+        Ok((frame, buffer))
+    }
 }
 
 simple_frame_codec!(
     RetireConnectionId { sequence_number },
     retire_connection_id_tag!()
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bolero::check;
+    use s2n_codec::EncoderValue;
+
+    #[test]
+    fn decode_bounded_test() {
+        check!().with_type::<(VarInt, VarInt)>().cloned().for_each(
+            |(sequence_number, max_sequence_number)| {
+                let bytes = RetireConnectionId { sequence_number }.encode_to_vec();
+                let result = RetireConnectionId::decode_bounded(
+                    DecoderBuffer::new(&bytes),
+                    max_sequence_number,
+                );
+
+                if sequence_number <= max_sequence_number {
+                    let (frame, remaining) = result.unwrap();
+                    assert_eq!(frame.sequence_number, sequence_number);
+                    assert!(remaining.is_empty());
+                } else {
+                    assert!(result.is_err());
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn decode_bounded_max_len_test() {
+        // a sequence number using the maximum 8 byte varint encoding
+        let bytes = RetireConnectionId {
+            sequence_number: VarInt::MAX,
+        }
+        .encode_to_vec();
+        assert_eq!(bytes.len(), 1 + 8);
+
+        let max_sequence_number = VarInt::from_u8(7);
+        assert!(RetireConnectionId::decode_bounded(
+            DecoderBuffer::new(&bytes),
+            max_sequence_number
+        )
+        .is_err());
+
+        // the same value is accepted without a bound
+        let (frame, _) =
+            RetireConnectionId::decode_bounded(DecoderBuffer::new(&bytes), VarInt::MAX).unwrap();
+        assert_eq!(frame.sequence_number, VarInt::MAX);
+
+        // small values can also be sent with the maximum length encoding
+        let bytes = [retire_connection_id_tag!(), 0xc0, 0, 0, 0, 0, 0, 0, 8];
+        assert!(RetireConnectionId::decode_bounded(
+            DecoderBuffer::new(&bytes),
+            max_sequence_number
+        )
+        .is_err());
+        let bytes = [retire_connection_id_tag!(), 0xc0, 0, 0, 0, 0, 0, 0, 7];
+        let (frame, _) =
+            RetireConnectionId::decode_bounded(DecoderBuffer::new(&bytes), max_sequence_number)
+                .unwrap();
+        assert_eq!(frame.sequence_number, max_sequence_number);
+    }
+
+    #[test]
+    fn decode_bounded_invalid_tag_test() {
+        let bytes = [0x18, 0];
+        assert!(
+            RetireConnectionId::decode_bounded(DecoderBuffer::new(&bytes), VarInt::MAX).is_err()
+        );
+    }
+}
@@ -293,9 +293,10 @@ impl<'a> From<Stream<DecoderBufferMut<'a>>> for StreamMut<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::frame::Padding;
+    use crate::frame::{FrameMut, Padding};
     use bolero::check;
     use core::convert::TryInto;
+    use s2n_codec::{DecoderBufferMut, EncoderBuffer};
 
     fn model(stream_id: VarInt, offset: VarInt, length: VarInt, capacity: usize) {
         let length = if let Ok(length) = VarInt::try_into(length) {
@@ -369,4 +370,89 @@ mod tests {
                 model(stream_id, offset, length, capacity);
             });
     }
+
+    /// Encodes two STREAM frames back-to-back into `payload` and returns
+    /// the number of bytes written
+    fn encode_payload(data: &[u8], omit_last_len: bool, payload: &mut [u8]) -> usize {
+        let capacity = payload.len();
+        let mut buffer = EncoderBuffer::new(payload);
+
+        let first = Stream {
+            stream_id: VarInt::from_u8(0),
+            offset: VarInt::from_u8(0),
+            is_last_frame: false,
+            is_fin: false,
+            data,
+        };
+        buffer.encode(&first);
+
+        let mut last = Stream {
+            stream_id: VarInt::from_u8(4),
+            offset: VarInt::from_u8(0),
+            is_last_frame: false,
+            is_fin: true,
+            data,
+        };
+
+        if omit_last_len {
+            // the frame fills the rest of the payload so the length can be omitted
+            let len = last.try_fit(capacity - buffer.len()).unwrap();
+            assert_eq!(len, data.len());
+            assert!(last.is_last_frame);
+        }
+
+        buffer.encode(&last);
+        buffer.len()
+    }
+
+    #[test]
+    fn last_frame_omits_len_test() {
+        let data = &[1u8; 100][..];
+
+        let mut with_len = [0u8; 256];
+        let with_len_size = encode_payload(data, false, &mut with_len);
+
+        // the length of 100 bytes is encoded as a 2 byte varint
+        let len_prefix_size = VarInt::try_from(data.len()).unwrap().encoding_size();
+        assert_eq!(len_prefix_size, 2);
+
+        // only the last frame is allowed to omit the length, so size the payload so the last
+        // frame extends to the end of it
+        let mut without_len = [0u8; 256];
+        let without_len_size = encode_payload(
+            data,
+            true,
+            &mut without_len[..with_len_size - len_prefix_size],
+        );
+
+        assert_eq!(without_len_size, with_len_size - len_prefix_size);
+
+        for (payload, len) in [
+            (&mut with_len[..with_len_size], true),
+            (&mut without_len[..without_len_size], false),
+        ] {
+            let buffer = DecoderBufferMut::new(payload);
+
+            let (first, buffer) = buffer.decode::<FrameMut>().unwrap();
+            let first = match first {
+                FrameMut::Stream(frame) => StreamRef::from(frame),
+                other => panic!("unexpected frame {other:?}"),
+            };
+            // earlier frames always include the length
+            assert!(!first.is_last_frame);
+            assert_eq!(first.stream_id, VarInt::from_u8(0));
+            assert_eq!(first.data, data);
+
+            let (last, buffer) = buffer.decode::<FrameMut>().unwrap();
+            assert!(buffer.is_empty());
+            let last = match last {
+                FrameMut::Stream(frame) => StreamRef::from(frame),
+                other => panic!("unexpected frame {other:?}"),
+            };
+            assert_eq!(last.is_last_frame, !len);
+            assert_eq!(last.stream_id, VarInt::from_u8(4));
+            assert!(last.is_fin);
+            assert_eq!(last.data, data);
+        }
+    }
 }
@@ -85,4 +85,8 @@ impl EncoderValue for StreamsBlocked {
         buffer.encode(&self.tag());
         buffer.encode(&self.stream_limit);
     }
+    #[inline]
+    fn encoding_size(&self) -> usize {
+        self.tag().encoding_size() + self.stream_limit.encoding_size()
+    }
 }
@@ -1,9 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::frame::FrameRef;
+use crate::frame::{all_frame_types, FrameMut, FrameRef, Tag};
 use bolero::check;
-use s2n_codec::{assert_codec_round_trip_bytes_mut, Encoder, EncoderLenEstimator, EncoderValue};
+use s2n_codec::{
+    assert_codec_round_trip_bytes_mut, DecoderBufferMut, DecoderValueMut, Encoder, EncoderBuffer,
+    EncoderLenEstimator, EncoderValue,
+};
 
 #[test]
 fn round_trip() {
@@ -20,3 +23,52 @@ fn round_trip() {
         }
     });
 }
+
+#[test]
+fn all_frame_types_test() {
+    let types: Vec<_> = all_frame_types().collect();
+
+    // every tag is only dispatched to a single frame type
+    for window in types.windows(2) {
+        assert!(window[0].0 < window[1].0, "{types:?}");
+    }
+
+    assert!(types.contains(&(0x19, "RetireConnectionId")));
+    assert!(types.contains(&(0x0f, "Stream")));
+    assert_eq!(types.first(), Some(&(0x00, "Padding")));
+}
+
+#[test]
+fn all_frame_types_round_trip() {
+    let types: Vec<(Tag, &str)> = all_frame_types().collect();
+
+    check!()
+        .with_type::<(u8, Vec<u8>)>()
+        .for_each(|(index, body)| {
+            let (tag, name) = types[*index as usize % types.len()];
+
+            let mut input = vec![tag];
+            input.extend_from_slice(body);
+
+            // most generated bodies aren't valid for the frame type
+            let Ok((frame, _remaining)) = FrameMut::decode_mut(DecoderBufferMut::new(&mut input))
+            else {
+                return;
+            };
+
+            // the tag should be dispatched to the frame type it was registered with
+            assert!(format!("{frame:?}").starts_with(name), "{frame:?}");
+
+            // frames with several tags may be encoded with a different tag of the same type
+            let encoded_tag = frame.tag();
+            assert!(types.contains(&(encoded_tag, name)), "{encoded_tag:#x}");
+
+            let mut encoded = vec![0; frame.encoding_size()];
+            EncoderBuffer::new(&mut encoded).encode(&frame);
+
+            let (decoded, remaining) =
+                FrameMut::decode_mut(DecoderBufferMut::new(&mut encoded)).unwrap();
+            assert!(remaining.is_empty());
+            assert_eq!(frame, decoded);
+        });
+}
@@ -11,6 +11,11 @@ use core::fmt;
 #[cfg(any(test, feature = "generator"))]
 use bolero_generator::*;
 
+//= https://www.rfc-editor.org/rfc/rfc5156#section-2.2
+//# ::FFFF:0:0/96 are the IPv4-mapped addresses [RFC4291].
+const IPV4_MAPPED_PREFIX: u128 = 0xffff << 32;
+const IPV4_MAPPED_MASK: u128 = !(u32::MAX as u128);
+
 /// An IP address, either IPv4 or IPv6.
 ///
 /// Instead of using `std::net::IPAddr`, this implementation
@@ -53,6 +58,67 @@ impl IpAddress {
             Self::Ipv6(addr) => addr.with_port(port).into(),
         }
     }
+
+    /// Returns the network prefix of the address, with all of the bits after `prefix_len` zeroed
+    ///
+    /// This is useful for anonymizing addresses before they are logged. `None` is returned if
+    /// `prefix_len` is longer than the address: 32 bits for IPv4 and 128 bits for IPv6.
+    ///
+    /// ```
+    /// use s2n_quic_core::inet::{IpAddress, IpV4Address};
+    ///
+    /// let addr: IpAddress = IpV4Address::from([192, 0, 2, 123]).into();
+    /// let expected: IpAddress = IpV4Address::from([192, 0, 2, 0]).into();
+    /// assert_eq!(addr.masked(24), Some(expected));
+    /// assert_eq!(addr.masked(33), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn masked(self, prefix_len: u8) -> Option<Self> {
+        match self {
+            Self::Ipv4(addr) => {
+                let host_bits = 32u32.checked_sub(prefix_len as u32)?;
+                let mask = u32::MAX.checked_shl(host_bits).unwrap_or(0);
+                let value = u32::from_be_bytes(addr.into()) & mask;
+                Some(IpV4Address::from(value.to_be_bytes()).into())
+            }
+            Self::Ipv6(addr) => {
+                let host_bits = 128u32.checked_sub(prefix_len as u32)?;
+                let mask = u128::MAX.checked_shl(host_bits).unwrap_or(0);
+                let value = u128::from_be_bytes(addr.into()) & mask;
+                Some(IpV6Address::from(value.to_be_bytes()).into())
+            }
+        }
+    }
+
+    /// Converts the IP address into a `u128`, which allows it to be used as an integer key
+    ///
+    /// IPv6 addresses are converted as-is, with the octets in network byte order. IPv4 addresses
+    /// are placed in the low 32 bits and tagged with the IPv4-mapped prefix (`::ffff:0:0/96`),
+    /// which results in the same value as converting [`Self::to_ipv6_mapped`].
+    ///
+    /// Because the tag is itself an IPv6 prefix, IPv6 addresses within `::ffff:0:0/96` produce
+    /// the same value as the IPv4 address they map to, and [`Self::from_u128`] converts them back
+    /// as IPv4. The round trip is lossless for all other addresses.
+    #[inline]
+    #[must_use]
+    pub fn to_u128(self) -> u128 {
+        let octets: [u8; 16] = self.to_ipv6_mapped().into();
+        u128::from_be_bytes(octets)
+    }
+
+    /// Converts a value produced by [`Self::to_u128`] back into an IP address
+    ///
+    /// Values within the IPv4-mapped prefix (`::ffff:0:0/96`) are converted into IPv4 addresses.
+    #[inline]
+    #[must_use]
+    pub fn from_u128(value: u128) -> Self {
+        if value & IPV4_MAPPED_MASK == IPV4_MAPPED_PREFIX {
+            IpV4Address::from((value as u32).to_be_bytes()).into()
+        } else {
+            IpV6Address::from(value.to_be_bytes()).into()
+        }
+    }
 }
 
 impl From<IpV4Address> for IpAddress {
@@ -167,6 +233,18 @@ impl SocketAddress {
             Self::IpV6(addr) => addr.unmap(),
         }
     }
+
+    /// Returns a stable representation of the address, which can be used as a key when comparing
+    /// or deduplicating addresses
+    ///
+    /// IPv4-mapped and IPv4-compatible addresses are converted into IPv4. Flow information and
+    /// scope IDs are never stored in a `SocketAddress`, so those are already dropped when
+    /// converting from `std::net::SocketAddrV6`.
+    #[inline]
+    #[must_use]
+    pub fn canonical(&self) -> Self {
+        self.unmap()
+    }
 }
 
 impl Default for SocketAddress {
@@ -368,6 +446,7 @@ impl Protocol {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use s2n_codec::{DecoderBuffer, Encoder, EncoderBuffer};
     use std::net::{SocketAddr, ToSocketAddrs};
 
     const TESTS: &[&str] = &[
@@ -412,6 +491,162 @@ mod tests {
         }
     }
 
+    #[test]
+    fn masked_test() {
+        let addr: IpAddress = IpV4Address::from([203, 0, 113, 77]).into();
+        let expected: IpAddress = IpV4Address::from([203, 0, 113, 0]).into();
+        assert_eq!(addr.masked(24), Some(expected));
+        assert_eq!(addr.masked(32), Some(addr));
+        assert_eq!(addr.masked(0), Some(IpV4Address::UNSPECIFIED.into()));
+        assert_eq!(addr.masked(33), None);
+
+        let addr: std::net::Ipv6Addr = "2001:db8:85a3:1:2:8a2e:370:7334".parse().unwrap();
+        let addr: IpAddress = IpV6Address::from(addr.octets()).into();
+        let expected: std::net::Ipv6Addr = "2001:db8:85a3::".parse().unwrap();
+        let expected: IpAddress = IpV6Address::from(expected.octets()).into();
+        assert_eq!(addr.masked(48), Some(expected));
+        assert_eq!(addr.masked(128), Some(addr));
+        assert_eq!(addr.masked(0), Some(IpV6Address::UNSPECIFIED.into()));
+        assert_eq!(addr.masked(129), None);
+    }
+
+    #[test]
+    fn canonical_test() {
+        for test in TESTS.iter() {
+            let addr: SocketAddr = test.parse().unwrap();
+            let address: SocketAddress = addr.into();
+            let mapped: SocketAddress = address.to_ipv6_mapped().into();
+
+            // both representations of the same peer share a canonical value
+            assert_eq!(address.canonical(), mapped.canonical());
+            assert_eq!(
+                address.canonical().to_string(),
+                mapped.canonical().to_string()
+            );
+            // canonicalizing is idempotent
+            assert_eq!(address.canonical(), address.canonical().canonical());
+        }
+
+        // scope IDs and flow information don't affect the canonical value
+        let ip: std::net::Ipv6Addr = "fe80::1".parse().unwrap();
+        let scoped = SocketAddr::V6(std::net::SocketAddrV6::new(ip, 443, 7, 3));
+        let unscoped = SocketAddr::V6(std::net::SocketAddrV6::new(ip, 443, 0, 0));
+        assert_eq!(
+            SocketAddress::from(scoped).canonical(),
+            SocketAddress::from(unscoped).canonical()
+        );
+
+        // IPv4-compatible addresses are also converted into IPv4
+        let compatible: SocketAddr = "[::1.2.3.4]:443".parse().unwrap();
+        let address: SocketAddr = "1.2.3.4:443".parse().unwrap();
+        assert_eq!(
+            SocketAddress::from(compatible).canonical(),
+            SocketAddress::from(address)
+        );
+    }
+
+    #[test]
+    fn u128_round_trip_test() {
+        let tests: &[(&str, u128)] = &[
+            ("0.0.0.0", 0xffff_0000_0000),
+            ("255.255.255.255", 0xffff_ffff_ffff),
+            ("127.0.0.1", 0xffff_7f00_0001),
+            ("::", 0),
+            ("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", u128::MAX),
+            ("::1", 1),
+            (
+                "2001:0db8:85a3:0001:0002:8a2e:0370:7334",
+                0x2001_0db8_85a3_0001_0002_8a2e_0370_7334,
+            ),
+        ];
+
+        for (addr, expected) in tests.iter().copied() {
+            let ip: std::net::IpAddr = addr.parse().unwrap();
+            let address = match ip {
+                std::net::IpAddr::V4(ip) => IpAddress::Ipv4(ip.into()),
+                std::net::IpAddr::V6(ip) => IpAddress::Ipv6(ip.into()),
+            };
+            assert_eq!(address.to_u128(), expected, "{addr}");
+            assert_eq!(IpAddress::from_u128(expected), address, "{addr}");
+        }
+    }
+
+    #[test]
+    fn u128_ipv4_mapped_test() {
+        // IPv6 addresses in the IPv4-mapped prefix share a value with the IPv4 address
+        let ipv4 = IpAddress::Ipv4(IpV4Address::new([1, 2, 3, 4]));
+        let mapped = IpAddress::Ipv6(ipv4.to_ipv6_mapped());
+        assert_eq!(ipv4.to_u128(), mapped.to_u128());
+        assert_eq!(IpAddress::from_u128(mapped.to_u128()), ipv4);
+
+        // IPv4-compatible addresses are not in the mapped prefix, so they stay IPv6
+        let compatible = IpAddress::Ipv6(IpV6Address::new([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4,
+        ]));
+        assert_eq!(IpAddress::from_u128(compatible.to_u128()), compatible);
+    }
+
+    #[test]
+    fn u128_fuzz_test() {
+        bolero::check!()
+            .with_type::<IpAddress>()
+            .for_each(|&address| {
+                let value = address.to_u128();
+                let actual = IpAddress::from_u128(value);
+                assert_eq!(actual.to_u128(), value);
+
+                let is_mapped = value & IPV4_MAPPED_MASK == IPV4_MAPPED_PREFIX;
+                if !is_mapped || matches!(address, IpAddress::Ipv4(_)) {
+                    assert_eq!(actual, address);
+                }
+            });
+    }
+
+    /// Encodes a generated address at an arbitrary offset and checks that both the owned and
+    /// zerocopy decoders produce the same address
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn round_trip() {
+        bolero::check!()
+            .with_type::<(SocketAddress, u8)>()
+            .cloned()
+            .for_each(|(address, offset)| {
+                // shift the encoded value so the decoders are exercised on unaligned input
+                let offset = offset as usize % 8;
+                let mut buffer = [0u8; 8 + core::mem::size_of::<SocketAddressV6>()];
+                let mut encoder = EncoderBuffer::new(&mut buffer);
+                encoder.write_repeated(offset, 0);
+                match address {
+                    SocketAddress::IpV4(addr) => encoder.encode(&addr),
+                    SocketAddress::IpV6(addr) => encoder.encode(&addr),
+                }
+                let len = encoder.len();
+
+                let decoder = DecoderBuffer::new(&buffer[..len]);
+                let decoder = decoder.skip(offset).unwrap();
+
+                let (owned, reference) = match address {
+                    SocketAddress::IpV4(_) => {
+                        let (owned, _) = decoder.decode::<SocketAddressV4>().unwrap();
+                        let (reference, remaining) = decoder.decode::<&SocketAddressV4>().unwrap();
+                        assert!(remaining.is_empty());
+                        (owned.into(), SocketAddressRef::IpV4(reference))
+                    }
+                    SocketAddress::IpV6(_) => {
+                        let (owned, _) = decoder.decode::<SocketAddressV6>().unwrap();
+                        let (reference, remaining) = decoder.decode::<&SocketAddressV6>().unwrap();
+                        assert!(remaining.is_empty());
+                        (owned.into(), SocketAddressRef::IpV6(reference))
+                    }
+                };
+
+                assert_eq!(address, owned);
+                assert_eq!(address, reference.to_owned());
+                assert_eq!(address.ip(), owned.ip());
+                assert_eq!(address.port(), owned.port());
+            });
+    }
+
     #[test]
     fn display_test() {
         for test in TESTS.iter() {
@@ -422,6 +657,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn std_socket_addr_round_trip_test() {
+        for test in TESTS.iter() {
+            let addr: SocketAddr = test.parse().unwrap();
+            let address = SocketAddress::from(addr);
+
+            assert_eq!(SocketAddr::from(address), addr);
+
+            let address_ref = match &address {
+                SocketAddress::IpV4(addr) => SocketAddressRef::IpV4(addr),
+                SocketAddress::IpV6(addr) => SocketAddressRef::IpV6(addr),
+            };
+            assert_eq!(SocketAddr::from(address_ref), addr);
+            assert_eq!(address_ref.to_owned(), address);
+        }
+    }
+
     #[test]
     fn to_socket_addrs_test() {
         for test in TESTS.iter() {
@@ -121,6 +121,7 @@ pub trait PacketEncoder<K: CryptoKey, H: HeaderKey, Payload: PacketPayloadEncode
         mut buffer: EncoderBuffer<'a>,
     ) -> Result<(ProtectedPayload<'a>, EncoderBuffer<'a>), PacketEncodingError<'a>> {
         let packet_number = self.packet_number();
+        let checkpoint = buffer.checkpoint();
 
         // Truncate the packet number from the largest_acknowledged_packet_number.
         let truncated_packet_number =
@@ -250,7 +251,7 @@ pub trait PacketEncoder<K: CryptoKey, H: HeaderKey, Payload: PacketPayloadEncode
 
         // The payload didn't have anything to write so rewind the cursor
         if payload_len == 0 {
-            buffer.set_position(0);
+            buffer.rollback(checkpoint);
             return Err(PacketEncodingError::EmptyPayload(buffer));
         }
 
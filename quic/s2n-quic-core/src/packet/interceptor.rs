@@ -13,7 +13,7 @@ use s2n_codec::encoder::scatter;
 
 pub use s2n_codec::{DecoderBufferMut, EncoderBuffer};
 pub mod loss;
-pub use loss::Loss;
+pub use loss::{DropEveryN, Loss};
 
 /// TODO add `non_exhaustive` once/if this feature is stable
 #[derive(Debug)]
@@ -86,6 +86,17 @@ pub trait Interceptor: 'static + Send {
         let _ = payload;
     }
 
+    /// Returns `false` if the packet should be dropped before it is committed for transmission
+    ///
+    /// The packet number of a dropped packet is skipped and nothing is recorded as sent with
+    /// it, so its payload will be written to a later packet instead.
+    #[inline(always)]
+    fn intercept_tx_packet(&mut self, subject: &Subject, packet: &Packet) -> bool {
+        let _ = subject;
+        let _ = packet;
+        true
+    }
+
     #[inline(always)]
     fn intercept_tx_payload(
         &mut self,
@@ -154,6 +165,13 @@ where
         self.1.intercept_tx_datagram(subject, datagram, payload);
     }
 
+    #[inline(always)]
+    fn intercept_tx_packet(&mut self, subject: &Subject, packet: &Packet) -> bool {
+        let x = self.0.intercept_tx_packet(subject, packet);
+        let y = self.1.intercept_tx_packet(subject, packet);
+        x && y
+    }
+
     #[inline(always)]
     fn intercept_tx_payload(
         &mut self,
@@ -260,4 +278,13 @@ impl<T: Interceptor> Interceptor for Option<T> {
             inner.intercept_tx_payload(subject, packet, payload)
         }
     }
+
+    #[inline]
+    fn intercept_tx_packet(&mut self, subject: &Subject, packet: &Packet) -> bool {
+        if let Some(inner) = self.as_mut() {
+            inner.intercept_tx_packet(subject, packet)
+        } else {
+            true
+        }
+    }
 }
@@ -176,6 +176,37 @@ where
     }
 }
 
+/// Drops every `n`-th packet number in each packet number space before it is transmitted
+///
+/// Unlike [`Loss`], which drops datagrams after they have been committed for transmission, the
+/// packets are dropped deterministically based on their packet number. See
+/// [`Interceptor::intercept_tx_packet`] for how dropped packets are handled.
+#[derive(Clone, Copy, Debug)]
+pub struct DropEveryN {
+    n: u64,
+}
+
+impl DropEveryN {
+    /// Creates an interceptor which drops every `n`-th packet
+    ///
+    /// Panics if `n` is zero.
+    pub fn new(n: u64) -> Self {
+        assert_ne!(n, 0, "n must be greater than zero");
+        Self { n }
+    }
+}
+
+impl Interceptor for DropEveryN {
+    #[inline]
+    fn intercept_tx_packet(
+        &mut self,
+        _subject: &crate::event::api::Subject,
+        packet: &super::Packet,
+    ) -> bool {
+        (packet.number.as_u64() + 1) % self.n != 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{havoc::testing::RandomSlice, *};
@@ -215,4 +246,33 @@ mod tests {
         assert_eq!(passed, 143);
         assert_eq!(dropped, 113);
     }
+
+    #[test]
+    fn drop_every_n_test() {
+        use crate::{
+            event::api::Subject, packet::number::PacketNumberSpace, time::clock::testing,
+            varint::VarInt,
+        };
+
+        let subject = Subject::Connection { id: 0 };
+        let mut interceptor = DropEveryN::new(3);
+
+        for space in [
+            PacketNumberSpace::Initial,
+            PacketNumberSpace::Handshake,
+            PacketNumberSpace::ApplicationData,
+        ] {
+            let sent: Vec<_> = (0..9u8)
+                .filter(|pn| {
+                    let packet = super::super::Packet {
+                        number: space.new_packet_number(VarInt::from_u8(*pn)),
+                        timestamp: testing::now(),
+                    };
+                    interceptor.intercept_tx_packet(&subject, &packet)
+                })
+                .collect();
+
+            assert_eq!(sent, [0, 1, 3, 4, 6, 7]);
+        }
+    }
 }
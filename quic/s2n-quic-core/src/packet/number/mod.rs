@@ -92,6 +92,21 @@ fn derive_truncation_range(
         .and_then(|value| PacketNumberLen::from_varint(value, space))
 }
 
+/// Returns the number of bytes, from 1 to 4, used to encode `packet_number` in a packet header
+///
+/// The width is selected based on the distance from the largest acknowledged packet number,
+/// which is the same selection [`PacketNumber::truncate`] makes when encoding packets. `None`
+/// is returned if `packet_number` is smaller than `largest_acknowledged_packet_number` or too far
+/// ahead of it to be represented in 4 bytes.
+#[inline]
+pub fn encode_width(
+    packet_number: PacketNumber,
+    largest_acknowledged_packet_number: PacketNumber,
+) -> Option<u8> {
+    let len = derive_truncation_range(largest_acknowledged_packet_number, packet_number)?;
+    Some(len.bytesize() as u8)
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-17.1
 //# As a result, the size of the packet number encoding is at least one
 //# bit more than the base-2 logarithm of the number of contiguous
@@ -132,6 +147,41 @@ fn packet_number_len_example_test() {
     );
 }
 
+#[test]
+fn encode_width_test() {
+    let space = PacketNumberSpace::default();
+    let largest_acknowledged_packet_number = space.new_packet_number(VarInt::from_u32(1000));
+    let width = |gap: u32| {
+        let packet_number = space.new_packet_number(VarInt::from_u32(1000 + gap));
+        encode_width(packet_number, largest_acknowledged_packet_number)
+    };
+
+    // the encoding must represent more than twice the gap
+    for (gap, expected) in [
+        (0, 1),
+        (1, 1),
+        (127, 1),
+        (128, 2),
+        (32_767, 2),
+        (32_768, 3),
+        (8_388_607, 3),
+        (8_388_608, 4),
+        (2_147_483_647, 4),
+    ] {
+        assert_eq!(width(gap), Some(expected), "gap: {gap}");
+    }
+
+    // the gap is too large to be encoded
+    assert_eq!(width(2_147_483_648), None);
+
+    // packet numbers behind the largest acknowledged can't be encoded
+    let packet_number = space.new_packet_number(VarInt::from_u32(999));
+    assert_eq!(
+        encode_width(packet_number, largest_acknowledged_packet_number),
+        None
+    );
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-17.1
 //# At a receiver, protection of the packet number is removed prior to
 //# recovering the full packet number.  The full packet number is then
@@ -146,7 +146,7 @@ impl PacketNumber {
     /// exceeded the maximum value allowed `None` will be returned.
     #[inline]
     pub fn next(self) -> Option<Self> {
-        let value = Self::as_varint(self).checked_add(VarInt::from_u8(1))?;
+        let value = Self::as_varint(self).checked_increment()?;
         let space = self.space();
         Some(Self::from_varint(value, space))
     }
@@ -23,6 +23,12 @@ fn round_trip() {
             if let Some((mask, bytes)) =
                 encode_packet_number(packet_number, largest_acked_packet_number)
             {
+                // The encoded width should match the selected width
+                assert_eq!(
+                    encode_width(packet_number, largest_acked_packet_number),
+                    Some(bytes.len() as u8)
+                );
+
                 // If encoding was valid, assert that the information can be decoded
                 let actual_packet_number =
                     decode_packet_number(mask, bytes, largest_acked_packet_number).unwrap();
@@ -286,6 +286,50 @@ impl Handle for Tuple {
     }
 }
 
+/// The address validation state of a path
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationState {
+    /// The path has not been validated yet
+    Pending,
+    /// The path has passed address validation
+    Validated,
+    /// Address validation was attempted on the path and failed
+    Failed,
+}
+
+/// A snapshot of a path maintained by a connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PathInfo {
+    /// The local address of the path
+    pub local_address: SocketAddress,
+    /// The remote address of the path
+    pub remote_address: SocketAddress,
+    /// The address validation state of the path
+    pub validation_state: ValidationState,
+    /// `true` if the path is currently used to transmit non-probing packets
+    pub is_active: bool,
+}
+
+impl PathInfo {
+    #[inline]
+    #[doc(hidden)]
+    pub fn new(
+        local_address: LocalAddress,
+        remote_address: RemoteAddress,
+        validation_state: ValidationState,
+        is_active: bool,
+    ) -> Self {
+        Self {
+            local_address: *local_address,
+            remote_address: *remote_address,
+            validation_state,
+            is_active,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MaxMtu(NonZeroU16);
 
@@ -437,9 +481,12 @@ pub fn remote_port_throttled(port: u16) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::path::{
-        remote_port_blocked, remote_port_throttled, BLOCKED_PORTS, MAX_BLOCKED_PORT,
-        MAX_THROTTLED_PORT, THROTTLED_PORTS,
+    use crate::{
+        inet::{IpV4Address, SocketAddressV4},
+        path::{
+            remote_port_blocked, remote_port_throttled, Handle, LocalAddress, RemoteAddress, Tuple,
+            BLOCKED_PORTS, MAX_BLOCKED_PORT, MAX_THROTTLED_PORT, THROTTLED_PORTS,
+        },
     };
 
     #[test]
@@ -489,6 +536,35 @@ mod tests {
             assert_eq!(throttled_expected, remote_port_throttled(port));
         }
     }
+
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-9
+    //= type=test
+    //# An endpoint MUST
+    //# perform path validation (Section 8.2) if it detects any change to a
+    //# peer's address, unless it has previously validated that address.
+    #[test]
+    fn remote_port_change_is_new_path() {
+        let ip = IpV4Address::new([192, 0, 2, 1]);
+        let remote_address = RemoteAddress::from(SocketAddressV4::new(ip, 443));
+        // a NAT rebinding only changes the port of the peer's address
+        let rebound_address = RemoteAddress::from(SocketAddressV4::new(ip, 444));
+
+        assert!(Handle::eq(&remote_address, &remote_address));
+        assert!(!Handle::eq(&remote_address, &rebound_address));
+
+        let local_address = LocalAddress::from(SocketAddressV4::new([192, 0, 2, 2], 4433));
+        let tuple = Tuple {
+            remote_address,
+            local_address,
+        };
+        let rebound_tuple = Tuple {
+            remote_address: rebound_address,
+            local_address,
+        };
+
+        assert!(Handle::eq(&tuple, &tuple));
+        assert!(!Handle::eq(&tuple, &rebound_tuple));
+    }
 }
 
 #[cfg(any(test, feature = "testing"))]
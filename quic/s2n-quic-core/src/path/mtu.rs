@@ -41,6 +41,25 @@ pub mod testing {
     }
 }
 
+/// Returns the largest UDP payload that fits in `max_mtu` when exchanging datagrams with
+/// `peer_socket_address`
+///
+/// The minimum length IP header (without IPv4 options or IPv6 extensions) for the address family
+/// and the UDP header are subtracted from `max_mtu`. The result is never less than the minimum
+/// QUIC datagram size.
+#[inline]
+pub fn max_udp_payload(max_mtu: MaxMtu, peer_socket_address: &SocketAddress) -> u16 {
+    (u16::from(max_mtu) - UDP_HEADER_LEN - min_ip_header_len(peer_socket_address)).max(BASE_PLPMTU)
+}
+
+#[inline]
+fn min_ip_header_len(peer_socket_address: &SocketAddress) -> u16 {
+    match peer_socket_address {
+        SocketAddress::IpV4(_) => IPV4_MIN_HEADER_LEN,
+        SocketAddress::IpV6(_) => IPV6_MIN_HEADER_LEN,
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum State {
     //= https://www.rfc-editor.org/rfc/rfc8899#section-5.2
@@ -151,12 +170,8 @@ impl Controller {
     /// be over 9000.
     #[inline]
     pub fn new(max_mtu: MaxMtu, peer_socket_address: &SocketAddress) -> Self {
-        let min_ip_header_len = match peer_socket_address {
-            SocketAddress::IpV4(_) => IPV4_MIN_HEADER_LEN,
-            SocketAddress::IpV6(_) => IPV6_MIN_HEADER_LEN,
-        };
-        let max_udp_payload =
-            (u16::from(max_mtu) - UDP_HEADER_LEN - min_ip_header_len).max(BASE_PLPMTU);
+        let min_ip_header_len = min_ip_header_len(peer_socket_address);
+        let max_udp_payload = max_udp_payload(max_mtu, peer_socket_address);
 
         // The UDP payload size for the most likely MTU is based on standard Ethernet MTU minus
         // the minimum length IP headers (without IPv4 options or IPv6 extensions) and UPD header
@@ -189,6 +204,30 @@ impl Controller {
         self.request_new_search(None);
     }
 
+    /// Limits the size of datagrams to the `max_udp_payload_size` transport parameter
+    /// advertised by the peer
+    #[inline]
+    pub fn on_peer_max_udp_payload_size(&mut self, peer_max_udp_payload_size: u16) {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-18.2
+        //#    This limit does act as an additional constraint on datagram size
+        //#    in the same way as the path MTU, but it is a property of the
+        //#    endpoint and not the path; see Section 14.
+        let max_udp_payload = self
+            .max_udp_payload
+            .min(peer_max_udp_payload_size)
+            .max(BASE_PLPMTU);
+
+        self.max_udp_payload = max_udp_payload;
+        self.max_probe_size = self.max_probe_size.min(max_udp_payload);
+        self.probed_size = self.probed_size.min(max_udp_payload);
+    }
+
+    /// Returns the maximum UDP payload that can be sent on the path
+    #[inline]
+    pub fn max_udp_payload(&self) -> u16 {
+        self.max_udp_payload
+    }
+
     /// Called when the connection timer expires
     #[inline]
     pub fn on_timeout(&mut self, now: Timestamp) {
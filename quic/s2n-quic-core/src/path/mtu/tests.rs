@@ -52,6 +52,45 @@ fn new_max_mtu_smaller_than_common_mtu() {
     assert_eq!(State::SearchComplete, controller.state);
 }
 
+#[test]
+fn peer_max_udp_payload_size() {
+    let mut controller = new_controller(9000);
+    assert_eq!(
+        9000 - UDP_HEADER_LEN - IPV4_MIN_HEADER_LEN,
+        controller.max_udp_payload()
+    );
+    assert_eq!(1472, controller.probed_size);
+
+    // the peer limit caps probing below the local maximum
+    controller.on_peer_max_udp_payload_size(1400);
+    assert_eq!(1400, controller.max_udp_payload());
+    assert_eq!(1400, controller.max_probe_size);
+    assert_eq!(1400, controller.probed_size);
+
+    // a larger peer limit doesn't raise the local maximum
+    controller.on_peer_max_udp_payload_size(65527);
+    assert_eq!(1400, controller.max_udp_payload());
+
+    // the limit never drops below the minimum QUIC datagram size
+    controller.on_peer_max_udp_payload_size(1000);
+    assert_eq!(BASE_PLPMTU, controller.max_udp_payload());
+    assert_eq!(BASE_PLPMTU, controller.probed_size);
+
+    controller.enable();
+    assert_eq!(State::SearchComplete, controller.state);
+}
+
+#[test]
+fn max_udp_payload_test() {
+    let ipv4: SocketAddr = "127.0.0.1:443".parse().unwrap();
+    let ipv6: SocketAddr = "[::1]:443".parse().unwrap();
+    let max_mtu: MaxMtu = 1500.try_into().unwrap();
+
+    assert_eq!(1472, max_udp_payload(max_mtu, &ipv4.into()));
+    assert_eq!(1452, max_udp_payload(max_mtu, &ipv6.into()));
+    assert_eq!(BASE_PLPMTU, max_udp_payload(MaxMtu::MIN, &ipv6.into()));
+}
+
 #[test]
 fn new_ipv4() {
     let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
@@ -223,6 +262,42 @@ fn on_packet_ack_search_requested() {
     assert!(!controller.pmtu_raise_timer.is_armed());
 }
 
+#[test]
+fn on_packet_ack_raises_mtu() {
+    let mut controller = new_controller(1500 + (PROBE_THRESHOLD * 2));
+    let mut cc = CongestionController::default();
+    let now = now();
+    let mut publisher = Publisher::snapshot();
+    assert_eq!(BASE_PLPMTU as usize, controller.mtu());
+
+    let probed_size = controller.probed_sized();
+    let probe_pn = pn(2);
+    controller.state = State::Searching(probe_pn, now);
+
+    // acknowledging a packet other than the probe does not change the MTU
+    controller.on_packet_ack(
+        pn(1),
+        BASE_PLPMTU,
+        &mut cc,
+        path::Id::test_id(),
+        &mut publisher,
+    );
+    assert_eq!(BASE_PLPMTU as usize, controller.mtu());
+    assert_eq!(0, cc.on_mtu_update);
+
+    // acknowledging the probe raises the MTU to the probed size
+    controller.on_packet_ack(
+        probe_pn,
+        probed_size as u16,
+        &mut cc,
+        path::Id::test_id(),
+        &mut publisher,
+    );
+    assert_eq!(probed_size, controller.mtu());
+    assert!(controller.mtu() > BASE_PLPMTU as usize);
+    assert_eq!(1, cc.on_mtu_update);
+}
+
 #[test]
 fn on_packet_ack_resets_black_hole_counter() {
     let mut controller = new_controller(1500 + (PROBE_THRESHOLD * 2));
@@ -260,6 +260,14 @@ impl Estimator {
         self.rate_sample
     }
 
+    /// Gets the delivery rate of the latest [RateSample]
+    ///
+    /// Returns `None` if no packets have been acknowledged yet.
+    pub fn delivery_rate(&self) -> Option<Bandwidth> {
+        let delivery_rate = self.rate_sample.delivery_rate();
+        (delivery_rate != Bandwidth::ZERO).then_some(delivery_rate)
+    }
+
     /// Returns true if the path is currently in an application-limited period
     pub fn is_app_limited(&self) -> bool {
         self.app_limited_delivered_bytes.is_some()
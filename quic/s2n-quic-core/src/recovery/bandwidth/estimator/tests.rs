@@ -488,3 +488,45 @@ fn as_bytes_per_second() {
     assert_eq!(0, Bandwidth::ZERO.as_bytes_per_second());
     assert_eq!(u64::MAX, Bandwidth::INFINITY.as_bytes_per_second());
 }
+
+#[test]
+fn delivery_rate() {
+    let mut publisher = event::testing::Publisher::no_snapshot();
+    let mut publisher = PathPublisher::new(&mut publisher, path::Id::test_id());
+    let t0 = NoopClock.get_time();
+    let mut bw_estimator = Estimator::default();
+
+    const PACKET_LEN: usize = 1000;
+    const SEND_INTERVAL: Duration = Duration::from_millis(10);
+    const RTT: Duration = Duration::from_millis(100);
+
+    // no packets have been acknowledged yet
+    assert_eq!(None, bw_estimator.delivery_rate());
+
+    // Send a packet every 10ms, each of which is acknowledged one RTT later
+    let mut in_flight = std::collections::VecDeque::new();
+    for i in 0..100 {
+        let now = t0 + SEND_INTERVAL * i;
+
+        while let Some((time_sent, packet_info)) = in_flight.front().copied() {
+            if time_sent + RTT > now {
+                break;
+            }
+            in_flight.pop_front();
+            bw_estimator.on_ack(PACKET_LEN, time_sent, packet_info, now, &mut publisher);
+        }
+
+        let bytes_in_flight = (in_flight.len() * PACKET_LEN) as u32;
+        let packet_info = bw_estimator.on_packet_sent(bytes_in_flight, PACKET_LEN, None, now);
+        in_flight.push_back((now, packet_info));
+    }
+
+    // The acknowledgements arrive at the rate the packets were sent:
+    // 1000 bytes every 10ms = 100,000 bytes per second
+    assert_eq!(
+        Some(100_000),
+        bw_estimator
+            .delivery_rate()
+            .map(|rate| rate.as_bytes_per_second())
+    );
+}
@@ -533,6 +533,11 @@ impl CongestionController for BbrCongestionController {
     fn send_quantum(&self) -> Option<usize> {
         Some(self.pacer.send_quantum())
     }
+
+    #[inline]
+    fn delivery_rate(&self) -> Option<Bandwidth> {
+        self.bw_estimator.delivery_rate()
+    }
 }
 
 impl BbrCongestionController {
@@ -245,6 +245,14 @@ pub trait CongestionController: 'static + Clone + Send + Debug + private::Sealed
     fn send_quantum(&self) -> Option<usize> {
         None
     }
+
+    /// The delivery rate measured from the most recent acknowledgements
+    ///
+    /// If the value is `None`, the congestion controller does not sample the delivery rate or
+    /// has not yet taken a sample.
+    fn delivery_rate(&self) -> Option<Bandwidth> {
+        None
+    }
 }
 
 // Prevent implementation of the `CongestionController` trait if the
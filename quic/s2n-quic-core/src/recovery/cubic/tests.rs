@@ -702,6 +702,49 @@ fn on_packet_lost_below_minimum_window() {
     assert_delta!(cc.congestion_window, cc.cubic.minimum_window(), 0.001);
 }
 
+#[test]
+fn repeated_loss_never_below_minimum_window() {
+    let max_datagram_size = path::MINIMUM_MTU;
+    let mut cc = CubicCongestionController::new(max_datagram_size);
+    let mut publisher = event::testing::Publisher::no_snapshot();
+    let mut publisher = PathPublisher::new(&mut publisher, path::Id::test_id());
+    let mut now = NoopClock.get_time();
+    let random = &mut random::testing::Generator::default();
+    let minimum_window = 2 * max_datagram_size as u32;
+
+    for i in 0..100 {
+        now += Duration::from_millis(100);
+
+        // each loss starts a new congestion event, with every 10th being persistent congestion
+        cc.state = State::congestion_avoidance(now);
+        cc.bytes_in_flight = BytesInFlight::new(cc.congestion_window());
+        let persistent_congestion = i % 10 == 9;
+
+        cc.on_packet_lost(
+            max_datagram_size as u32,
+            (),
+            persistent_congestion,
+            false,
+            random,
+            now,
+            &mut publisher,
+        );
+
+        assert!(
+            cc.congestion_window() >= minimum_window,
+            "congestion window {} dropped below the minimum after {} losses",
+            cc.congestion_window(),
+            i + 1
+        );
+
+        // once the lost bytes are no longer in flight, the window always allows transmission
+        cc.bytes_in_flight = BytesInFlight::new(0);
+        assert!(!cc.is_congestion_limited());
+    }
+
+    assert_delta!(cc.congestion_window, cc.cubic.minimum_window(), 0.001);
+}
+
 #[test]
 fn on_packet_lost_already_in_recovery() {
     let mut cc = CubicCongestionController::new(1000);
@@ -1228,3 +1271,65 @@ fn on_packet_ack_congestion_avoidance_too_large_increase() {
     assert!(cc.cubic.w_cubic(t) > cc.cubic.w_est(t, rtt));
     assert_delta!(cc.congestion_window, 3_600_000.0 + 1000.0 / 2.0, 0.001);
 }
+
+#[test]
+fn congestion_window_and_bytes_in_flight() {
+    let max_datagram_size = 1200;
+    let mut cc = CubicCongestionController::new(max_datagram_size);
+    let mut publisher = event::testing::Publisher::snapshot();
+    let mut publisher = PathPublisher::new(&mut publisher, path::Id::test_id());
+    let rtt_estimator = RttEstimator::default();
+    let random = &mut random::testing::Generator::default();
+    let now = NoopClock.get_time();
+
+    let initial_window = cc.congestion_window();
+    assert_eq!(cc.bytes_in_flight(), 0);
+
+    // Fill the congestion window
+    let packets = initial_window / max_datagram_size as u32;
+    for _ in 0..packets {
+        cc.on_packet_sent(
+            now,
+            max_datagram_size as usize,
+            Some(false),
+            &rtt_estimator,
+            &mut publisher,
+        );
+    }
+    assert_eq!(cc.bytes_in_flight(), packets * max_datagram_size as u32);
+
+    // Acknowledging a packet in slow start grows the window
+    cc.on_ack(
+        now,
+        max_datagram_size as usize,
+        (),
+        &rtt_estimator,
+        random,
+        now + Duration::from_millis(100),
+        &mut publisher,
+    );
+
+    let grown_window = cc.congestion_window();
+    assert!(grown_window > initial_window);
+    assert_eq!(
+        cc.bytes_in_flight(),
+        (packets - 1) * max_datagram_size as u32
+    );
+
+    // Losing a packet shrinks the window
+    cc.on_packet_lost(
+        max_datagram_size as u32,
+        (),
+        false,
+        false,
+        random,
+        now + Duration::from_millis(200),
+        &mut publisher,
+    );
+
+    assert!(cc.congestion_window() < grown_window);
+    assert_eq!(
+        cc.bytes_in_flight(),
+        (packets - 2) * max_datagram_size as u32
+    );
+}
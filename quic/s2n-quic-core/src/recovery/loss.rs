@@ -21,6 +21,60 @@ pub enum Outcome {
     Lost,
 }
 
+/// Declares packets lost once they fall too far behind the largest acknowledged packet
+///
+/// This only applies the packet reordering threshold. Packets within the threshold can still be
+/// declared lost by [`detect`] once the time threshold has elapsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LossDetector {
+    packet_number_threshold: u64,
+}
+
+impl Default for LossDetector {
+    #[inline]
+    fn default() -> Self {
+        Self::new(K_PACKET_THRESHOLD)
+    }
+}
+
+impl LossDetector {
+    /// Creates a detector with the given packet reordering threshold
+    #[inline]
+    pub fn new(packet_number_threshold: u64) -> Self {
+        Self {
+            packet_number_threshold,
+        }
+    }
+
+    /// Returns `true` if `packet_number` was sent at least the packet reordering threshold
+    /// before the largest acknowledged packet
+    #[inline]
+    pub fn is_lost(
+        self,
+        packet_number: PacketNumber,
+        largest_acked_packet_number: PacketNumber,
+    ) -> bool {
+        // packets sent after the largest acknowledged packet can't be considered lost yet
+        matches!(
+            largest_acked_packet_number.checked_distance(packet_number),
+            Some(distance) if distance > 0 && distance >= self.packet_number_threshold
+        )
+    }
+
+    /// Returns the unacknowledged packet numbers that are declared lost by the packet
+    /// reordering threshold
+    #[inline]
+    pub fn lost_packets<I: IntoIterator<Item = PacketNumber>>(
+        self,
+        largest_acked_packet_number: PacketNumber,
+        unacked_packet_numbers: I,
+    ) -> impl Iterator<Item = PacketNumber> {
+        unacked_packet_numbers
+            .into_iter()
+            .filter(move |packet_number| self.is_lost(*packet_number, largest_acked_packet_number))
+    }
+}
+
 /// Detect if the given packet number is lost based on how long ago
 /// it was sent and how far from the largest acked packet number it is.
 pub fn detect(
@@ -46,10 +100,8 @@ pub fn detect(
     // If the `packet_lost_time` exceeds the current time, it's lost
     let time_threshold_exceeded = packet_lost_time.has_elapsed(now);
 
-    let packet_number_threshold_exceeded = largest_acked_packet_number
-        .checked_distance(packet_number)
-        .expect("largest_acked_packet_number > packet_number")
-        >= packet_number_threshold;
+    let packet_number_threshold_exceeded = LossDetector::new(packet_number_threshold)
+        .is_lost(packet_number, largest_acked_packet_number);
 
     //= https://www.rfc-editor.org/rfc/rfc9002#section-6.1
     //# A packet is declared lost if it meets all of the following
@@ -178,6 +230,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contiguous_unacked_packets_are_not_lost() {
+        let detector = LossDetector::default();
+        let largest_acked_packet_number = new_packet_number(10);
+
+        // the unacked packets are all within the threshold of the largest acked, or sent after it
+        let unacked = (8..=9).chain(11..=12).map(new_packet_number);
+        assert_eq!(
+            detector
+                .lost_packets(largest_acked_packet_number, unacked)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn packets_beyond_threshold_are_lost() {
+        let detector = LossDetector::default();
+        let largest_acked_packet_number = new_packet_number(10);
+
+        let unacked = (1..=9).map(new_packet_number);
+        let lost: Vec<_> = detector
+            .lost_packets(largest_acked_packet_number, unacked)
+            .map(PacketNumber::as_u64)
+            .collect();
+
+        // only packets sent K_PACKET_THRESHOLD or more before the largest acked are lost
+        assert_eq!(lost, (1..=10 - K_PACKET_THRESHOLD).collect::<Vec<_>>());
+    }
+
     fn new_packet_number(packet_number: u64) -> PacketNumber {
         PacketNumberSpace::ApplicationData.new_packet_number(packet_number.try_into().unwrap())
     }
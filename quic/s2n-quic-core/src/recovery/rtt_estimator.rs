@@ -31,6 +31,20 @@ pub const K_GRANULARITY: Duration = Duration::from_millis(1);
 //# declaring an RTO after two TLPs.
 const K_PERSISTENT_CONGESTION_THRESHOLD: u64 = 3;
 
+/// A snapshot of the round trip time statistics tracked by an [`RttEstimator`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct RttEstimate {
+    /// An exponentially-weighted moving average of the observed RTT samples
+    pub smoothed: Duration,
+    /// The latest RTT sample
+    pub latest: Duration,
+    /// The minimum RTT observed over the lifetime of the path
+    pub min: Duration,
+    /// The variance in the observed RTT samples
+    pub var: Duration,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct RttEstimator {
     /// Latest RTT sample
@@ -124,6 +138,17 @@ impl RttEstimator {
         self.rttvar
     }
 
+    /// Gets a snapshot of the current round trip time statistics
+    #[inline]
+    pub fn estimate(&self) -> RttEstimate {
+        RttEstimate {
+            smoothed: self.smoothed_rtt,
+            latest: self.latest_rtt,
+            min: self.min_rtt,
+            var: self.rttvar,
+        }
+    }
+
     /// Gets the timestamp of the first RTT sample
     #[inline]
     pub fn first_rtt_sample(&self) -> Option<Timestamp> {
@@ -414,6 +439,33 @@ mod test {
         );
     }
 
+    /// Test a configured initial RTT is used for the first PTO period
+    #[test]
+    fn configured_initial_rtt() {
+        let initial_rtt = Duration::from_millis(1000);
+        let rtt_estimator =
+            RttEstimator::new_with_max_ack_delay(Duration::from_millis(10), initial_rtt);
+        assert_eq!(rtt_estimator.smoothed_rtt(), initial_rtt);
+        assert_eq!(rtt_estimator.rttvar(), initial_rtt / 2);
+
+        // PTO = smoothed_rtt + 4*rttvar = 1000ms + 2000ms
+        assert_eq!(
+            rtt_estimator.pto_period(INITIAL_PTO_BACKOFF, PacketNumberSpace::Initial),
+            Duration::from_millis(3000)
+        );
+        assert_eq!(
+            rtt_estimator.pto_period(INITIAL_PTO_BACKOFF, PacketNumberSpace::ApplicationData),
+            Duration::from_millis(3010)
+        );
+
+        let default_estimator =
+            RttEstimator::new_with_max_ack_delay(Duration::from_millis(10), DEFAULT_INITIAL_RTT);
+        assert!(
+            rtt_estimator.pto_period(INITIAL_PTO_BACKOFF, PacketNumberSpace::Initial)
+                > default_estimator.pto_period(INITIAL_PTO_BACKOFF, PacketNumberSpace::Initial)
+        );
+    }
+
     /// Test a zero RTT value is treated as 1 µs
     #[test]
     fn zero_rtt_sample() {
@@ -435,6 +487,58 @@ mod test {
         );
     }
 
+    /// Test the estimate snapshot reflects each RTT sample
+    #[test]
+    fn estimate_updates_with_samples() {
+        let mut rtt_estimator = RttEstimator::new(DEFAULT_INITIAL_RTT);
+        assert_eq!(
+            rtt_estimator.estimate(),
+            RttEstimate {
+                smoothed: DEFAULT_INITIAL_RTT,
+                latest: DEFAULT_INITIAL_RTT,
+                min: DEFAULT_INITIAL_RTT,
+                var: DEFAULT_INITIAL_RTT / 2,
+            }
+        );
+
+        let now = NoopClock.get_time();
+        rtt_estimator.update_rtt(
+            Duration::ZERO,
+            Duration::from_millis(100),
+            now,
+            true,
+            PacketNumberSpace::ApplicationData,
+        );
+        assert_eq!(
+            rtt_estimator.estimate(),
+            RttEstimate {
+                smoothed: Duration::from_millis(100),
+                latest: Duration::from_millis(100),
+                min: Duration::from_millis(100),
+                var: Duration::from_millis(50),
+            }
+        );
+
+        rtt_estimator.update_rtt(
+            Duration::ZERO,
+            Duration::from_millis(200),
+            now + Duration::from_millis(200),
+            true,
+            PacketNumberSpace::ApplicationData,
+        );
+        // smoothed_rtt = 7/8 * 100ms + 1/8 * 200ms
+        // rttvar = 3/4 * 50ms + 1/4 * |100ms - 200ms|
+        assert_eq!(
+            rtt_estimator.estimate(),
+            RttEstimate {
+                smoothed: Duration::from_micros(112_500),
+                latest: Duration::from_millis(200),
+                min: Duration::from_millis(100),
+                var: Duration::from_micros(62_500),
+            }
+        );
+    }
+
     #[test]
     fn for_new_path() {
         let mut rtt_estimator = RttEstimator::default();
@@ -34,6 +34,20 @@ impl Token {
     pub fn into_inner(self) -> [u8; LEN] {
         self.0
     }
+
+    /// Returns the token a received datagram would carry if it were a Stateless Reset
+    ///
+    /// Returns `None` if the datagram is too short to contain a token.
+    pub fn from_datagram(payload: &[u8]) -> Option<Self> {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-10.3.1
+        //# The endpoint
+        //# identifies a received datagram as a Stateless Reset by comparing the
+        //# last 16 bytes of the datagram with all stateless reset tokens
+        //# associated with the remote address on which the datagram was
+        //# received.
+        let token_index = payload.len().checked_sub(LEN)?;
+        payload[token_index..].try_into().ok()
+    }
 }
 
 impl From<[u8; LEN]> for Token {
@@ -144,7 +158,27 @@ pub mod testing {
 
 #[cfg(test)]
 mod tests {
-    use crate::stateless_reset::token::{testing::TEST_TOKEN_1, LEN};
+    use crate::stateless_reset::token::{testing::TEST_TOKEN_1, Token, LEN};
+
+    #[test]
+    fn from_datagram_test() {
+        let mut payload = vec![0b0100_0000, 1, 2, 3, 4];
+        payload.extend_from_slice(TEST_TOKEN_1.as_ref());
+        assert_eq!(Some(TEST_TOKEN_1), Token::from_datagram(&payload));
+
+        // The token is always taken from the end of the datagram
+        assert_eq!(
+            Some(TEST_TOKEN_1),
+            Token::from_datagram(TEST_TOKEN_1.as_ref())
+        );
+        assert_ne!(
+            Some(TEST_TOKEN_1),
+            Token::from_datagram(&payload[..payload.len() - 1])
+        );
+
+        assert_eq!(None, Token::from_datagram(&TEST_TOKEN_1.as_ref()[1..]));
+        assert_eq!(None, Token::from_datagram(&[]));
+    }
 
     //= https://www.rfc-editor.org/rfc/rfc9000#section-10.3.1
     //= type=test
@@ -20,6 +20,9 @@ pub trait LocalLimits {
 pub struct Limits {
     /// The maximum send buffer size for a Stream
     pub max_send_buffer_size: MaxSendBufferSize,
+    /// The amount of buffered data a Stream must drain to before the application is
+    /// notified that it can resume writing after the send buffer filled up
+    pub send_buffer_low_watermark: SendBufferLowWatermark,
     /// The maximum number of unidirectional streams that may
     /// be opened concurrently by the local endpoint. This value
     /// is not communicated to the peer, it is only used for limiting
@@ -41,6 +44,7 @@ impl Default for Limits {
 impl Limits {
     pub const RECOMMENDED: Self = Self {
         max_send_buffer_size: MaxSendBufferSize::RECOMMENDED,
+        send_buffer_low_watermark: SendBufferLowWatermark::RECOMMENDED,
         max_open_local_unidirectional_streams: LocalUnidirectional::RECOMMENDED,
         max_open_local_bidirectional_streams: LocalBidirectional::RECOMMENDED,
     };
@@ -97,6 +101,28 @@ impl TryFrom<u32> for MaxSendBufferSize {
     }
 }
 
+local_limits!(SendBufferLowWatermark(u32));
+
+impl SendBufferLowWatermark {
+    /// By default the application may resume writing as soon as any space is available in the
+    /// send buffer, since the watermark is capped by the maximum send buffer size
+    pub const RECOMMENDED: Self = Self(u32::MAX);
+
+    #[inline]
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<u32> for SendBufferLowWatermark {
+    type Error = ValidationError;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(Self(value))
+    }
+}
+
 varint_local_limits!(LocalUnidirectional(VarInt));
 
 impl LocalUnidirectional {
@@ -7,11 +7,13 @@ pub mod iter;
 pub mod limits;
 #[cfg(feature = "alloc")]
 pub mod ops;
+mod priority;
 mod type_;
 
 pub use error::*;
 pub use id::*;
 pub use limits::Limits;
+pub use priority::Priority;
 pub use type_::*;
 
 #[cfg(any(test, feature = "testing"))]
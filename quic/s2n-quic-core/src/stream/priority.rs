@@ -0,0 +1,51 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// The relative importance of a stream when scheduling transmissions
+///
+/// Streams with a higher priority are given the chance to transmit before streams with a lower
+/// priority. Streams of equal priority take turns in the order they became ready to transmit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Priority(u8);
+
+impl Priority {
+    /// The priority which is transmitted last
+    pub const LOWEST: Self = Self(u8::MIN);
+
+    /// The priority assigned to streams which don't specify one
+    pub const DEFAULT: Self = Self(128);
+
+    /// The priority which is transmitted first
+    pub const HIGHEST: Self = Self(u8::MAX);
+
+    #[inline]
+    pub const fn new(value: u8) -> Self {
+        Self(value)
+    }
+
+    #[inline]
+    pub const fn as_u8(self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for Priority {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<u8> for Priority {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Priority> for u8 {
+    #[inline]
+    fn from(value: Priority) -> Self {
+        value.0
+    }
+}
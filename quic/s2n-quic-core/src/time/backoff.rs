@@ -0,0 +1,172 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+/// An iterator of exponentially increasing delays
+///
+/// The first delay is `base` and each subsequent delay is the previous one multiplied by
+/// `multiplier`, capped at `max`. The iterator never ends so callers are expected to bound the
+/// number of attempts themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    multiplier: u32,
+    /// The delay which will be yielded by the next call to `next`
+    current: Duration,
+    /// The state of the jitter generator, if enabled
+    jitter: Option<u64>,
+}
+
+impl Backoff {
+    /// Creates a backoff starting at `base` which is multiplied by `multiplier` up to `max`
+    #[inline]
+    pub fn new(base: Duration, max: Duration, multiplier: u32) -> Self {
+        let base = base.min(max);
+        Self {
+            base,
+            max,
+            multiplier,
+            current: base,
+            jitter: None,
+        }
+    }
+
+    /// Randomizes each delay to somewhere between half of its value and its full value
+    ///
+    /// The jitter is derived from `seed` so the same seed always yields the same sequence. This
+    /// spreads out retries from many clients without making tests nondeterministic.
+    #[inline]
+    pub fn with_jitter(mut self, seed: u64) -> Self {
+        self.jitter = Some(seed);
+        self
+    }
+
+    /// Restarts the sequence from the base delay
+    ///
+    /// The jitter generator is not rewound so the delays after a reset aren't identical to the
+    /// ones before it.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.current = self.base;
+    }
+
+    /// Returns the delay the next call to `next` will be derived from, before any jitter
+    #[inline]
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    #[inline]
+    fn apply_jitter(&mut self, delay: Duration) -> Duration {
+        let state = if let Some(state) = self.jitter.as_mut() {
+            state
+        } else {
+            return delay;
+        };
+
+        let half = delay / 2;
+        let range = (delay - half).as_nanos() as u64;
+        if range == 0 {
+            return delay;
+        }
+
+        let offset = splitmix64(state) % (range + 1);
+        half + Duration::from_nanos(offset)
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.current;
+        self.current = delay.saturating_mul(self.multiplier).min(self.max);
+        Some(self.apply_jitter(delay))
+    }
+}
+
+/// Advances the state and returns the next value of the SplitMix64 generator
+///
+/// This isn't suitable for anything security sensitive but is more than enough to spread out
+/// retries.
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn doubles_up_to_max_test() {
+        let backoff = Backoff::new(ms(100), ms(1000), 2);
+        let delays: Vec<_> = backoff.take(6).collect();
+        assert_eq!(
+            delays,
+            [ms(100), ms(200), ms(400), ms(800), ms(1000), ms(1000)]
+        );
+    }
+
+    #[test]
+    fn reset_test() {
+        let mut backoff = Backoff::new(ms(100), ms(1000), 2);
+        assert_eq!(backoff.nth(2), Some(ms(400)));
+        assert_eq!(backoff.current(), ms(800));
+
+        backoff.reset();
+        assert_eq!(backoff.current(), ms(100));
+        assert_eq!(backoff.next(), Some(ms(100)));
+        assert_eq!(backoff.next(), Some(ms(200)));
+    }
+
+    #[test]
+    fn base_above_max_test() {
+        let mut backoff = Backoff::new(ms(500), ms(100), 2);
+        assert_eq!(backoff.next(), Some(ms(100)));
+        assert_eq!(backoff.next(), Some(ms(100)));
+    }
+
+    #[test]
+    fn saturation_test() {
+        let mut backoff = Backoff::new(Duration::from_secs(u64::MAX / 2), Duration::MAX, u32::MAX);
+        backoff.next();
+        // large multipliers saturate rather than overflow
+        assert_eq!(backoff.next(), Some(Duration::MAX));
+        assert_eq!(backoff.next(), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn jitter_test() {
+        let backoff = Backoff::new(ms(100), ms(1000), 2);
+        let jittered = backoff.clone().with_jitter(123);
+
+        // each delay lands between half and all of the un-jittered value
+        for (delay, jittered) in backoff.zip(jittered).take(10) {
+            assert!(jittered >= delay / 2, "{jittered:?} < {delay:?} / 2");
+            assert!(jittered <= delay, "{jittered:?} > {delay:?}");
+        }
+
+        // the same seed yields the same sequence
+        let a: Vec<_> = Backoff::new(ms(100), ms(1000), 2)
+            .with_jitter(456)
+            .take(10)
+            .collect();
+        let b: Vec<_> = Backoff::new(ms(100), ms(1000), 2)
+            .with_jitter(456)
+            .take(10)
+            .collect();
+        assert_eq!(a, b);
+    }
+}
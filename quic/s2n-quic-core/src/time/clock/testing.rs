@@ -44,3 +44,75 @@ impl Clock {
         advance(duration);
     }
 }
+
+/// A clock that only moves forward when explicitly advanced
+///
+/// Unlike [`Clock`], each `ManualClock` tracks its own time rather than sharing a
+/// thread-local value, so independent clocks can be used within the same test.
+#[derive(Clone, Copy, Debug)]
+pub struct ManualClock {
+    now: Timestamp,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self { now: initial() }
+    }
+}
+
+impl ManualClock {
+    /// Returns the current time of the clock
+    #[inline]
+    pub fn now(&self) -> Timestamp {
+        self.now
+    }
+
+    /// Moves the clock forward by the given `duration`
+    #[inline]
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+    }
+}
+
+impl super::Clock for ManualClock {
+    #[inline]
+    fn get_time(&self) -> Timestamp {
+        self.now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::Clock as _;
+
+    #[test]
+    fn manual_clock_advance() {
+        let mut clock = ManualClock::default();
+        let start = clock.now();
+        assert_eq!(start, clock.get_time());
+
+        clock.advance(Duration::from_millis(10));
+        let later = clock.now();
+        assert_eq!(later - start, Duration::from_millis(10));
+        assert!(later > start);
+        assert!(start.has_elapsed(later));
+        assert!(!later.has_elapsed(start));
+
+        // advancing by zero doesn't move the clock
+        clock.advance(Duration::ZERO);
+        assert_eq!(clock.now(), later);
+    }
+
+    #[test]
+    fn manual_clock_is_independent() {
+        let mut a = ManualClock::default();
+        let b = ManualClock::default();
+
+        a.advance(Duration::from_secs(1));
+
+        assert!(a.now() > b.now());
+        // the thread-local clock is unaffected
+        assert_eq!(now(), b.now());
+    }
+}
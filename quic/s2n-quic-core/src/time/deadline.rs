@@ -0,0 +1,140 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::time::{
+    timer::{self, Provider as _},
+    Timer, Timestamp,
+};
+use core::time::Duration;
+
+/// A [`Timer`] which fires once a fixed duration has elapsed since it was armed
+///
+/// This removes the boilerplate of computing the target and checking the timer for drivers
+/// which only need to know when a deadline passes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Deadline {
+    timer: Timer,
+    duration: Duration,
+}
+
+impl Deadline {
+    /// Creates a disarmed deadline with the given duration
+    #[inline]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            timer: Timer::default(),
+            duration,
+        }
+    }
+
+    /// Arms the deadline to fire once the configured duration has elapsed since `now`
+    ///
+    /// If the deadline was already armed, the previous target is replaced.
+    #[inline]
+    pub fn arm(&mut self, now: Timestamp) {
+        self.timer.set(now + self.duration);
+    }
+
+    /// Replaces the configured duration and re-arms the deadline from `now`
+    #[inline]
+    pub fn reset(&mut self, now: Timestamp, duration: Duration) {
+        self.duration = duration;
+        self.arm(now);
+    }
+
+    /// Disarms the deadline without firing it
+    #[inline]
+    pub fn cancel(&mut self) {
+        self.timer.cancel();
+    }
+
+    /// Returns the configured duration
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the timestamp at which the deadline fires, if armed
+    #[inline]
+    pub fn target(&self) -> Option<Timestamp> {
+        self.next_expiration()
+    }
+
+    /// Returns true if the deadline is armed
+    #[inline]
+    pub fn is_armed(&self) -> bool {
+        self.timer.is_armed()
+    }
+
+    /// Returns `true` if the deadline has passed
+    ///
+    /// The deadline is disarmed when it fires so it only returns `true` once per arming.
+    #[inline]
+    pub fn poll(&mut self, now: Timestamp) -> bool {
+        self.timer.poll_expiration(now).is_ready()
+    }
+}
+
+impl timer::Provider for Deadline {
+    #[inline]
+    fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
+        self.timer.timers(query)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::clock::{Clock, NoopClock};
+
+    #[test]
+    fn arm_test() {
+        let now = NoopClock.get_time();
+        let mut deadline = Deadline::new(Duration::from_millis(100));
+
+        assert!(!deadline.is_armed());
+        assert_eq!(deadline.target(), None);
+        assert!(!deadline.poll(now + Duration::from_secs(1)));
+
+        deadline.arm(now);
+        assert!(deadline.is_armed());
+        assert_eq!(deadline.target(), Some(now + Duration::from_millis(100)));
+
+        deadline.cancel();
+        assert!(!deadline.is_armed());
+        assert!(!deadline.poll(now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn poll_test() {
+        let now = NoopClock.get_time();
+        let mut deadline = Deadline::new(Duration::from_millis(100));
+        deadline.arm(now);
+
+        assert!(!deadline.poll(now));
+        assert!(!deadline.poll(now + Duration::from_millis(99)));
+
+        // the deadline fires exactly at the target
+        assert!(deadline.poll(now + Duration::from_millis(100)));
+
+        // and only fires once
+        assert!(!deadline.is_armed());
+        assert!(!deadline.poll(now + Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn reset_test() {
+        let now = NoopClock.get_time();
+        let mut deadline = Deadline::new(Duration::from_millis(100));
+        deadline.arm(now);
+
+        // resetting before the deadline fires pushes out the target
+        let later = now + Duration::from_millis(50);
+        deadline.reset(later, Duration::from_millis(200));
+        assert_eq!(deadline.duration(), Duration::from_millis(200));
+        assert!(!deadline.poll(now + Duration::from_millis(100)));
+        assert!(!deadline.poll(later + Duration::from_millis(199)));
+        assert!(deadline.poll(later + Duration::from_millis(200)));
+    }
+}
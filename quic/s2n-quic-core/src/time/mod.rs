@@ -1,12 +1,16 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod backoff;
 pub mod clock;
+pub mod deadline;
 pub mod timer;
 mod timestamp;
 pub mod token_bucket;
 
+pub use backoff::Backoff;
 pub use clock::*;
 pub use core::time::Duration;
+pub use deadline::Deadline;
 pub use timer::Timer;
 pub use timestamp::*;
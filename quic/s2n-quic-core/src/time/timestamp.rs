@@ -130,6 +130,44 @@ impl Timestamp {
         Duration::from_micros(self.0.get())
     }
 
+    /// Rounds the `Timestamp` down to the nearest multiple of `granularity`
+    ///
+    /// The result is never less than 1us after the clock epoch. A `granularity` of less than
+    /// 1us leaves the `Timestamp` unchanged.
+    #[inline]
+    pub fn round_down_to(self, granularity: Duration) -> Self {
+        let granularity = u64::try_from(granularity.as_micros()).unwrap_or(u64::MAX);
+        if granularity <= 1 {
+            return self;
+        }
+
+        let micros = self.0.get();
+        let micros = micros - micros % granularity;
+        // if the value is 0 then round up to 1us after the epoch
+        Self(NonZeroU64::new(micros).unwrap_or(ONE_MICROSECOND))
+    }
+
+    /// Rounds the `Timestamp` up to the nearest multiple of `granularity`
+    ///
+    /// A `granularity` of less than 1us leaves the `Timestamp` unchanged.
+    #[inline]
+    pub fn round_up_to(self, granularity: Duration) -> Self {
+        let granularity = u64::try_from(granularity.as_micros()).unwrap_or(u64::MAX);
+        if granularity <= 1 {
+            return self;
+        }
+
+        let micros = self.0.get();
+        let remainder = micros % granularity;
+        if remainder == 0 {
+            return self;
+        }
+
+        let micros = (micros - remainder).saturating_add(granularity);
+        // the value is greater than `self`, which is non-zero
+        Self(NonZeroU64::new(micros).unwrap_or(ONE_MICROSECOND))
+    }
+
     /// Compares the timestamp to the current time and returns true if it is in the past
     ///
     /// Note that this compares milliseconds, as any finer resolution would result in
@@ -247,6 +285,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn timestamp_rounding_test() {
+        let ms = Duration::from_millis(1);
+
+        let ts = Timestamp::from_duration_impl(Duration::from_micros(12_345));
+        assert_eq!(
+            Duration::from_millis(12),
+            ts.round_down_to(ms).as_duration_impl()
+        );
+        assert_eq!(
+            Duration::from_millis(13),
+            ts.round_up_to(ms).as_duration_impl()
+        );
+
+        // rounding a value on a boundary is idempotent
+        let ts = Timestamp::from_duration_impl(Duration::from_millis(12));
+        assert_eq!(ts, ts.round_down_to(ms));
+        assert_eq!(ts, ts.round_up_to(ms));
+
+        // rounding down never goes below 1us
+        let ts = Timestamp::from_duration_impl(Duration::from_micros(999));
+        assert_eq!(
+            Duration::from_micros(1),
+            ts.round_down_to(ms).as_duration_impl()
+        );
+
+        // a granularity below 1us is a no-op
+        let ts = Timestamp::from_duration_impl(Duration::from_micros(12_345));
+        assert_eq!(ts, ts.round_down_to(Duration::from_nanos(10)));
+        assert_eq!(ts, ts.round_up_to(Duration::ZERO));
+
+        // a granularity that doesn't fit in a u64 of microseconds saturates instead of truncating
+        assert_eq!(
+            Duration::from_micros(1),
+            ts.round_down_to(Duration::MAX).as_duration_impl()
+        );
+        assert_eq!(
+            Duration::from_micros(u64::MAX),
+            ts.round_up_to(Duration::MAX).as_duration_impl()
+        );
+    }
+
+    #[test]
+    fn timestamp_rounding_alignment_test() {
+        bolero::check!()
+            .with_type::<(u64, u16)>()
+            .cloned()
+            .for_each(|(micros, granularity)| {
+                let ts = Timestamp::from_duration_impl(Duration::from_micros(micros >> 1));
+                let granularity = Duration::from_micros(granularity as u64 + 1);
+                let g = u64::try_from(granularity.as_micros()).unwrap_or(u64::MAX);
+
+                let down = ts.round_down_to(granularity);
+                assert!(down <= ts);
+                assert!(down.0.get() % g == 0 || down.0.get() == 1);
+                assert_eq!(down, down.round_down_to(granularity));
+
+                let up = ts.round_up_to(granularity);
+                assert!(up >= ts);
+                assert_eq!(up.0.get() % g, 0);
+                assert_eq!(up, up.round_up_to(granularity));
+            });
+    }
+
     #[test]
     fn timestamp_math_test() {
         // Start at a high initial timestamp to let the overflow check work
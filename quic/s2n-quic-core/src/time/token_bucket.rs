@@ -54,6 +54,26 @@ impl TokenBucket {
         credits
     }
 
+    /// Takes `amount` tokens from the bucket only if all of them are available
+    ///
+    /// Unlike [`Self::take`], no tokens are consumed if the bucket can't cover the full amount.
+    #[inline]
+    pub fn try_acquire(&mut self, amount: u64, now: Timestamp) -> bool {
+        // refill the bucket with any credits that have accrued since the last call
+        self.on_timeout(now);
+
+        if self.current < amount {
+            return false;
+        }
+
+        self.current -= amount;
+
+        // arm the refill timer if we dropped below the max
+        self.on_timeout(now);
+
+        true
+    }
+
     #[inline]
     pub fn set_refill_interval(&mut self, new_interval: Duration) {
         // if the value didn't change, then no need to update
@@ -215,4 +235,78 @@ mod tests {
         assert_eq!(bucket.take(100, clock.get_time()), 15);
         assert!(bucket.refill_timer.is_armed());
     }
+
+    #[test]
+    fn try_acquire_burst_test() {
+        let mut bucket = TokenBucket::default();
+        let clock = Clock::default();
+        let now = clock.get_time();
+
+        // the bucket starts full so the entire burst can be acquired at once
+        assert!(bucket.try_acquire(60, now));
+        assert!(bucket.refill_timer.is_armed());
+
+        // not enough credits remain so nothing should be taken
+        assert!(!bucket.try_acquire(50, now));
+        assert_eq!(bucket.current, 40);
+
+        assert!(bucket.try_acquire(40, now));
+        assert!(!bucket.try_acquire(1, now));
+
+        // acquiring nothing always succeeds
+        assert!(bucket.try_acquire(0, now));
+    }
+
+    #[test]
+    fn try_acquire_refill_test() {
+        let mut bucket = TokenBucket::builder()
+            .with_max(10)
+            .with_refill_amount(2)
+            .with_refill_interval(Duration::from_millis(100))
+            .build();
+        let mut clock = Clock::default();
+
+        assert!(bucket.try_acquire(10, clock.get_time()));
+        assert!(!bucket.try_acquire(1, clock.get_time()));
+
+        // a partial interval doesn't refill the bucket
+        clock.inc_by(Duration::from_millis(50));
+        assert!(!bucket.try_acquire(1, clock.get_time()));
+
+        clock.inc_by(Duration::from_millis(50));
+        assert!(!bucket.try_acquire(3, clock.get_time()));
+        assert!(bucket.try_acquire(2, clock.get_time()));
+
+        // multiple elapsed intervals are all credited
+        clock.inc_by(Duration::from_millis(300));
+        assert!(bucket.try_acquire(6, clock.get_time()));
+        assert!(!bucket.try_acquire(1, clock.get_time()));
+
+        // the bucket never refills past the max
+        clock.inc_by(Duration::from_secs(10));
+        assert!(!bucket.try_acquire(11, clock.get_time()));
+        assert!(bucket.try_acquire(10, clock.get_time()));
+        assert!(bucket.refill_timer.is_armed());
+    }
+
+    #[test]
+    fn try_acquire_clock_not_advancing_test() {
+        let mut bucket = TokenBucket::builder()
+            .with_max(3)
+            .with_refill_amount(1)
+            .with_refill_interval(Duration::from_millis(10))
+            .build();
+        let clock = Clock::default();
+        let now = clock.get_time();
+
+        for _ in 0..3 {
+            assert!(bucket.try_acquire(1, now));
+        }
+
+        // repeatedly querying at the same time should never produce new credits
+        for _ in 0..100 {
+            assert!(!bucket.try_acquire(1, now));
+        }
+        assert_eq!(bucket.current, 0);
+    }
 }
@@ -58,6 +58,18 @@ pub trait Format: 'static + Send {
         context: &mut Context<'_>,
         token: &[u8],
     ) -> Option<connection::InitialId>;
+
+    /// Returns `true` if the token was issued in a NEW_TOKEN frame and is still valid.
+    ///
+    /// This is called for tokens rejected by [`Self::validate_token`]. A valid token means the
+    /// client's address has been validated so the handshake proceeds without a Retry.
+    /// Callers should detect duplicate tokens and treat them as invalid.
+    #[inline]
+    fn validate_new_token(&mut self, context: &mut Context<'_>, token: &[u8]) -> bool {
+        let _ = context;
+        let _ = token;
+        false
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -151,6 +151,44 @@ fn load_client_limits() {
     insta::assert_debug_snapshot!("load_client_limits", params);
 }
 
+#[test]
+fn load_disabled_migration_limits() {
+    let limits = crate::connection::limits::Limits::default()
+        .with_active_migration(false)
+        .unwrap();
+    let mut params = ServerTransportParameters::default();
+    params.load_limits(&limits);
+    assert_eq!(params.migration_support, MigrationSupport::Disabled);
+
+    // the parameter should survive a round trip to the peer
+    let encoded = params.encode_to_vec();
+    let decoded = ServerTransportParameters::decode(DecoderBuffer::new(&encoded))
+        .unwrap()
+        .0;
+    assert_eq!(decoded.migration_support, MigrationSupport::Disabled);
+}
+
+#[test]
+fn max_idle_timeout_load_peer_test() {
+    fn negotiate(local: u64, peer: u64) -> Option<Duration> {
+        let mut timeout: MaxIdleTimeout = Duration::from_secs(local).try_into().unwrap();
+        let peer: MaxIdleTimeout = Duration::from_secs(peer).try_into().unwrap();
+        timeout.load_peer(&peer);
+        timeout.as_duration()
+    }
+
+    // the minimum of both values is used
+    assert_eq!(negotiate(30, 10), Some(Duration::from_secs(10)));
+    assert_eq!(negotiate(10, 30), Some(Duration::from_secs(10)));
+
+    // a value of 0 disables the timeout for that endpoint so the other value governs
+    assert_eq!(negotiate(0, 10), Some(Duration::from_secs(10)));
+    assert_eq!(negotiate(10, 0), Some(Duration::from_secs(10)));
+
+    // the timeout is only disabled if both endpoints disable it
+    assert_eq!(negotiate(0, 0), None);
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-7.4.2
 //= type=test
 //# An endpoint MUST ignore transport parameters that it does
@@ -180,6 +218,32 @@ fn ignore_unknown_parameter() {
     assert_eq!(0, remaining.len());
 }
 
+/// Reserved transport parameters can carry arbitrary values and appear anywhere in the list
+#[test]
+fn ignore_greased_parameter() {
+    use s2n_codec::EncoderBuffer;
+
+    let value = server_transport_parameters();
+
+    let mut buffer = vec![0; 32 * 1024];
+    let mut encoder = EncoderBuffer::new(&mut buffer);
+
+    // Reserved parameters have tags of the form 31 * N + 27
+    // We inject one with a payload before the supported parameters
+    let id: TransportParameterId = VarInt::from_u32(31 * 1_000 + 27);
+    encoder.encode(&id);
+    encoder.encode_with_len_prefix::<TransportParameterLength, _>(&&[0xff; 16][..]);
+
+    encoder.encode(&value);
+
+    let (encoded, _) = encoder.split_off();
+    let decoder = DecoderBuffer::new(encoded);
+    let (decoded_params, remaining) =
+        ServerTransportParameters::decode(decoder).expect("Decoding succeeds");
+    assert_eq!(value, decoded_params);
+    assert_eq!(0, remaining.len());
+}
+
 #[test]
 fn compute_data_window_test() {
     assert_eq!(
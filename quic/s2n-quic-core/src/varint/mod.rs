@@ -116,6 +116,19 @@ impl VarInt {
         Self::new(self.0.saturating_add(value.0)).unwrap_or(Self::MAX)
     }
 
+    /// Returns the value incremented by 1, or `None` if it is already [`VarInt::MAX`]
+    #[inline]
+    pub fn checked_increment(self) -> Option<Self> {
+        self.checked_add(Self::from_u8(1))
+    }
+
+    /// Returns the value incremented by 1, or [`VarInt::MAX`] if it would overflow
+    #[inline]
+    #[must_use]
+    pub fn saturating_increment(self) -> Self {
+        self.saturating_add(Self::from_u8(1))
+    }
+
     #[inline]
     pub fn checked_sub(self, value: Self) -> Option<Self> {
         Some(Self(self.0.checked_sub(value.0)?))
@@ -157,6 +170,15 @@ impl VarInt {
             .encode_maybe_undersized(encoder)
     }
 
+    /// Returns the number of bytes needed to encode the value, which is one of 1, 2, 4, or 8
+    ///
+    /// This is computed from the value's range so callers sizing a frame don't need to run the
+    /// full encoder to learn the length.
+    #[inline(always)]
+    pub fn encoded_len(self) -> usize {
+        self.table_entry().len
+    }
+
     #[inline(always)]
     fn table_entry(self) -> table::Entry {
         table::Entry::read(self.0)
@@ -176,7 +198,7 @@ impl EncoderValue for VarInt {
 
     #[inline(always)]
     fn encoding_size(&self) -> usize {
-        self.format().encoding_size()
+        self.encoded_len()
     }
 
     #[inline(always)]
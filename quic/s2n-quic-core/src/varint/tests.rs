@@ -29,6 +29,31 @@ fn round_trip_values_test() {
     })
 }
 
+#[test]
+fn encoded_len_test() {
+    // the first and last value of each length range
+    for (value, expected) in [
+        (0, 1),
+        (63, 1),
+        (64, 2),
+        (16_383, 2),
+        (16_384, 4),
+        (1_073_741_823, 4),
+        (1_073_741_824, 8),
+        (MAX_VARINT_VALUE, 8),
+    ] {
+        let value = VarInt::new(value).unwrap();
+        assert_eq!(value.encoded_len(), expected, "{value}");
+        assert_eq!(value.encode_to_vec().len(), expected, "{value}");
+    }
+
+    check!().with_type().cloned().for_each(|v| {
+        if let Ok(v) = VarInt::new(v) {
+            assert_eq!(v.encoded_len(), v.encode_to_vec().len());
+        }
+    })
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // snapshot tests don't work on miri
 fn table_snapshot_test() {
@@ -306,3 +331,18 @@ fn checked_ops_test() {
         }
     })
 }
+
+#[test]
+fn increment_test() {
+    let one = VarInt::from_u8(1);
+    assert_eq!(VarInt::from_u8(0).checked_increment(), Some(one));
+    assert_eq!(VarInt::from_u8(0).saturating_increment(), one);
+
+    // the largest value that can still be incremented
+    let below_max = VarInt::MAX - one;
+    assert_eq!(below_max.checked_increment(), Some(VarInt::MAX));
+    assert_eq!(below_max.saturating_increment(), VarInt::MAX);
+
+    assert_eq!(VarInt::MAX.checked_increment(), None);
+    assert_eq!(VarInt::MAX.saturating_increment(), VarInt::MAX);
+}
@@ -28,10 +28,13 @@ impl crypto::OneRttHeaderKey for OneRttHeaderKey {}
 
 #[cfg(test)]
 mod tests {
-    use crate::{cipher_suite::TLS_CHACHA20_POLY1305_SHA256, hkdf};
+    use super::*;
+    use crate::{
+        cipher_suite::TLS_CHACHA20_POLY1305_SHA256, hkdf, ring_aead::CHACHA20_POLY1305, SecretPair,
+    };
     use hex_literal::hex;
-    use s2n_codec::{encoder::scatter, EncoderBuffer};
-    use s2n_quic_core::crypto::Key;
+    use s2n_codec::{encoder::scatter, Encoder, EncoderBuffer};
+    use s2n_quic_core::crypto::{tls::CipherSuite, Key, OneRttKey as _};
 
     //= https://www.rfc-editor.org/rfc/rfc9001#appendix-A.5
     //# In this example, TLS produces an application write secret from which
@@ -123,4 +126,52 @@ mod tests {
             }
         }
     }
+
+    /// Seals a packet with `sealer` and returns `true` if `opener` decrypts it to the original
+    /// payload
+    fn round_trip(sealer: &OneRttKey, opener: &OneRttKey, packet_number: u64) -> bool {
+        let header = b"header";
+        let payload = b"payload";
+
+        let mut buffer = vec![0; payload.len() + sealer.tag_len()];
+        {
+            let mut buffer = EncoderBuffer::new(&mut buffer);
+            buffer.write_slice(payload);
+            let mut buffer = scatter::Buffer::new(buffer);
+            sealer.encrypt(packet_number, header, &mut buffer).unwrap();
+        }
+        assert_ne!(&buffer[..payload.len()], payload);
+
+        opener.decrypt(packet_number, header, &mut buffer).is_ok()
+            && &buffer[..payload.len()] == payload
+    }
+
+    /// Both endpoints derive the same next generation of ChaCha20 keys on a key update and keep
+    /// decrypting each other's packets
+    #[test]
+    fn chacha20_key_update_round_trip_test() {
+        let secrets = SecretPair {
+            server: hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &SECRET),
+            client: hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &KU_SECRET),
+        };
+        let (mut server_key, _) =
+            OneRttKey::new_server(&CHACHA20_POLY1305, secrets.clone()).unwrap();
+        let (mut client_key, _) = OneRttKey::new_client(&CHACHA20_POLY1305, secrets).unwrap();
+        assert!(matches!(
+            client_key.cipher_suite(),
+            CipherSuite::TLS_CHACHA20_POLY1305_SHA256
+        ));
+
+        for packet_number in 0..3 {
+            assert!(round_trip(&client_key, &server_key, packet_number));
+            assert!(round_trip(&server_key, &client_key, packet_number));
+
+            // the peer can't decrypt packets in the next key phase until it updates as well
+            let next_client_key = client_key.derive_next_key();
+            assert!(!round_trip(&next_client_key, &server_key, packet_number));
+
+            client_key = next_client_key;
+            server_key = server_key.derive_next_key();
+        }
+    }
 }
@@ -57,6 +57,16 @@ struct PathCreated<'a> {
     new: Path<'a>,
 }
 
+#[event("connectivity:peer_address_changed")]
+/// A packet was received from a peer address that differs from the active path
+///
+/// This is emitted once for each new peer address and can be caused by either NAT
+/// rebinding or the peer actively migrating.
+struct PeerAddressChanged<'a> {
+    old: SocketAddress<'a>,
+    new: SocketAddress<'a>,
+}
+
 #[event("transport:frame_sent")]
 //= https://tools.ietf.org/id/draft-marx-qlog-event-definitions-quic-h3-02#5.3.5
 // This diverges a bit from the qlog spec, which prefers to log data as part of the
@@ -105,6 +115,22 @@ struct RecoveryMetrics<'a> {
     congestion_limited: bool,
 }
 
+#[event("recovery:transmission_budget")]
+/// The congestion and pacing budget of the path when an application packet was written
+///
+/// This is emitted for every 1-RTT packet, other than the final CONNECTION_CLOSE, and is
+/// useful for diagnosing why a transfer isn't using all of the available congestion window.
+struct TransmissionBudget {
+    path_id: u64,
+    congestion_window: u32,
+    /// The bytes in flight before the packet was sent
+    bytes_in_flight: u32,
+    /// The maximum burst size allowed by the pacer, if the congestion controller sets one
+    send_quantum: Option<usize>,
+    /// The number of bytes written to the packet
+    bytes_written: usize,
+}
+
 #[event("recovery:congestion")]
 /// Congestion (ECN or packet loss) has occurred
 struct Congestion<'a> {
@@ -271,6 +297,24 @@ struct PathChallengeUpdated<'a> {
     challenge_data: &'a [u8],
 }
 
+#[event("connectivity:path_validation_updated")]
+/// Path validation completed for a path
+///
+/// This is emitted when a PATH_RESPONSE validates the path or when the challenge is abandoned
+/// after its timer expires. The time validation took is the difference between
+/// `challenge_sent_at` and `completed_at`.
+struct PathValidationUpdated<'a> {
+    status: PathChallengeStatus,
+    local_addr: SocketAddress<'a>,
+    remote_addr: SocketAddress<'a>,
+    /// When the first PATH_CHALLENGE for the path was sent
+    #[builder(crate::time::Timestamp)]
+    challenge_sent_at: crate::event::Timestamp,
+    /// When the path was validated or the challenge was abandoned
+    #[builder(crate::time::Timestamp)]
+    completed_at: crate::event::Timestamp,
+}
+
 #[event("tls:client_hello")]
 struct TlsClientHello<'a> {
     payload: &'a [&'a [u8]],
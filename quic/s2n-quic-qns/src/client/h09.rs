@@ -1,31 +1,100 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::Result;
-use bytes::Bytes;
-use s2n_quic::{client::Connect, connection::Handle, stream::SendStream, Client};
+use crate::{
+    interop::Testcase,
+    tls::{InMemorySessionTicketStore, SessionTicketStore},
+    Result,
+};
+use s2n_quic::{
+    application, client::Connect, connection::Handle, stream::SendStream, Client, Connection,
+};
+use s2n_quic_core::time::Backoff;
 use std::{
+    future::Future,
     path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
 };
-use tokio::{fs::File, io::AsyncWriteExt, task::JoinSet};
+use tokio::{
+    fs::File,
+    io::{AsyncWrite, AsyncWriteExt},
+    task::JoinSet,
+};
 use url::Url;
 
+/// The MTU every QUIC path is required to support, before any probing
+const BASE_MTU: usize = s2n_quic_core::path::MINIMUM_MTU as usize;
+
+/// The number of times a connection is attempted in the Multiconnect testcase
+const MULTICONNECT_ATTEMPTS: u32 = 3;
+
+/// The delay before the first retry of a failed connection in the Multiconnect testcase
+const MULTICONNECT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The longest delay between retries of a failed connection in the Multiconnect testcase
+const MULTICONNECT_MAX_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Returns the retry delays for the Multiconnect testcase
+fn multiconnect_backoff() -> Backoff {
+    Backoff::new(MULTICONNECT_BACKOFF, MULTICONNECT_MAX_BACKOFF, 2)
+}
+
+/// Per-connection settings for [`create_connection`]
+#[derive(Debug, Default)]
+pub(crate) struct ConnectionOptions {
+    /// Keeps the connection open for this long after all of the requests complete
+    pub keep_alive: Option<Duration>,
+    /// Closes the connection with this error once the requests complete
+    pub close_error: Option<application::Error>,
+    /// The testcase the connection is running
+    pub testcase: Option<Testcase>,
+    /// Resumes the session with a ticket from this store
+    pub session_tickets: Option<InMemorySessionTicketStore>,
+}
+
 pub(crate) async fn create_connection<R: IntoIterator<Item = Url>>(
     client: Client,
     connect: Connect,
     requests: R,
     download_dir: Arc<Option<PathBuf>>,
-    keep_alive: Option<Duration>,
+    options: ConnectionOptions,
 ) -> Result<()> {
+    let ConnectionOptions {
+        keep_alive,
+        close_error,
+        testcase,
+        session_tickets,
+    } = options;
+
     eprintln!("connecting to {connect:#}");
-    let mut connection = client.connect(connect).await?;
+    let mut connection = if let Some(store) = session_tickets {
+        connect_resumed(&client, connect, &store).await?
+    } else if matches!(testcase, Some(Testcase::Multiconnect)) {
+        // a single failed handshake shouldn't abort the rest of the connections
+        connect_with_retries(
+            &client,
+            connect,
+            MULTICONNECT_ATTEMPTS,
+            multiconnect_backoff(),
+        )
+        .await?
+    } else {
+        client.connect(connect).await?
+    };
+    eprintln!("QUIC version: {:#010x}", connection.version()?);
+    if let Some(parameters) = connection.peer_transport_parameters()? {
+        eprintln!("peer transport parameters: {parameters:?}");
+    }
 
     if keep_alive.is_some() {
         connection.keep_alive(true)?;
     }
 
+    // log the RTT estimates of each connection in the Transfer testcase
+    let log_rtt = matches!(testcase, Some(Testcase::Transfer));
+    let rtt_logger = log_rtt.then(|| tokio::spawn(log_rtt_estimates(connection.handle())));
+
     let mut streams = JoinSet::new();
     for request in requests {
         streams.spawn(create_stream(
@@ -40,14 +109,117 @@ pub(crate) async fn create_connection<R: IntoIterator<Item = Url>>(
         result??;
     }
 
+    if let Some(rtt_logger) = rtt_logger {
+        rtt_logger.abort();
+    }
+
+    if matches!(testcase, Some(Testcase::PathMtu)) {
+        let mtu = connection.mtu()?;
+        eprintln!("path MTU: {mtu}");
+
+        // the probes sent during the transfer should have raised the MTU above the baseline
+        if mtu <= BASE_MTU {
+            return Err(
+                format!("expected the MTU to be raised above {BASE_MTU}, got {mtu}").into(),
+            );
+        }
+    }
+
     if let Some(keep_alive) = keep_alive {
         tokio::time::sleep(keep_alive).await;
         connection.keep_alive(false)?;
     }
 
+    if let Some(close_error) = close_error {
+        // the server logs the application error it receives from the peer
+        connection.close(close_error);
+    }
+
+    if let Ok(stats) = connection.stats() {
+        eprintln!(
+            "connection stats: packets_sent={} packets_received={} bytes_sent={} \
+             bytes_received={} packets_lost={} smoothed_rtt={:?} cwnd={}",
+            stats.packets_sent,
+            stats.packets_received,
+            stats.bytes_sent,
+            stats.bytes_received,
+            stats.packets_lost,
+            stats.smoothed_rtt,
+            stats.congestion_window,
+        );
+    }
+
     Ok(())
 }
 
+/// Logs the RTT and delivery rate estimates of the connection once per smoothed RTT until the
+/// connection closes
+async fn log_rtt_estimates(connection: Handle) {
+    while let Ok(rtt) = connection.rtt_estimate() {
+        eprintln!(
+            "rtt: smoothed={:?} latest={:?} min={:?} var={:?}",
+            rtt.smoothed, rtt.latest, rtt.min, rtt.var
+        );
+        // only rate-based congestion controllers sample the delivery rate
+        if let Ok(Some(rate)) = connection.delivery_rate() {
+            eprintln!("delivery rate: {rate} bytes/sec");
+        }
+        tokio::time::sleep(rtt.smoothed).await;
+    }
+}
+
+/// Connects to the server, resuming the session with a ticket stored by a previous connection
+///
+/// The TLS provider takes the ticket out of the `store` when it initializes the connection.
+pub(crate) async fn connect_resumed<S: SessionTicketStore>(
+    client: &Client,
+    connect: Connect,
+    store: &S,
+) -> Result<Connection> {
+    // a full handshake would silently fail the Resumption testcase so bail early instead
+    if store.is_empty() {
+        return Err("no session ticket was received on the previous connection".into());
+    }
+
+    Ok(client.connect(connect).await?)
+}
+
+/// Connects to the server, retrying failed attempts up to `attempts` times in total
+///
+/// The delay before each retry is taken from `backoff`. The error from the last attempt is
+/// returned if none of them succeed.
+pub(crate) async fn connect_with_retries(
+    client: &Client,
+    connect: Connect,
+    attempts: u32,
+    backoff: Backoff,
+) -> Result<Connection> {
+    retry_with_backoff(attempts, backoff, || async {
+        Ok(client.connect(connect.clone()).await?)
+    })
+    .await
+}
+
+async fn retry_with_backoff<T, F, Fut>(attempts: u32, mut backoff: Backoff, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt >= attempts => return Err(error),
+            Err(error) => {
+                let delay = backoff.next().unwrap_or_default();
+                eprintln!("connection attempt {attempt} failed: {error}; retrying in {delay:?}");
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
 async fn create_stream(
     connection: Handle,
     request: String,
@@ -97,18 +269,90 @@ async fn create_stream_inner(
 }
 
 async fn write_request(mut stream: SendStream, request: &str) -> Result<()> {
-    static GET: Bytes = Bytes::from_static(b"GET ");
-    static END_OF_REQUEST: Bytes = Bytes::from_static(b"\r\n");
-
-    stream
-        .send_vectored(&mut [
-            GET.clone(),
-            Bytes::copy_from_slice(request.as_bytes()),
-            END_OF_REQUEST.clone(),
-        ])
-        .await?;
+    write_request_line(&mut stream, request).await?;
 
     stream.finish()?;
 
     Ok(())
 }
+
+/// Writes the request line to the stream
+///
+/// If the stream's flow control window can't fit the entire request, the unsent portion is
+/// retried once the stream has capacity.
+async fn write_request_line<S: AsyncWrite + Unpin>(stream: &mut S, request: &str) -> Result<()> {
+    stream.write_all(b"GET ").await?;
+    stream.write_all(request.as_bytes()).await?;
+    stream.write_all(b"\r\n").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_request_line_flow_control_test() {
+    use tokio::io::AsyncReadExt;
+
+    let request = format!("/{}", "a".repeat(1000));
+
+    // limit the stream to a few bytes at a time so the request must be sent in pieces
+    let (mut tx, mut rx) = tokio::io::duplex(16);
+
+    let reader = tokio::spawn(async move {
+        let mut received = vec![];
+        rx.read_to_end(&mut received).await.unwrap();
+        received
+    });
+
+    write_request_line(&mut tx, &request).await.unwrap();
+    drop(tx);
+
+    let received = reader.await.unwrap();
+    assert_eq!(received, format!("GET {request}\r\n").as_bytes());
+}
+
+#[test]
+fn multiconnect_backoff_test() {
+    let mut backoff = multiconnect_backoff();
+    assert_eq!(backoff.next(), Some(Duration::from_millis(100)));
+    assert_eq!(backoff.next(), Some(Duration::from_millis(200)));
+    assert_eq!(backoff.next(), Some(Duration::from_millis(400)));
+
+    // later retries are capped rather than growing without bound
+    assert_eq!(backoff.nth(100), Some(MULTICONNECT_MAX_BACKOFF));
+}
+
+#[tokio::test]
+async fn retry_with_backoff_test() {
+    let backoff = Backoff::new(Duration::from_millis(10), Duration::from_secs(1), 2);
+
+    // a connector that fails the first two attempts succeeds on the third
+    let mut calls = 0;
+    let start = tokio::time::Instant::now();
+    let result = retry_with_backoff(3, backoff.clone(), || {
+        calls += 1;
+        let attempt = calls;
+        async move {
+            if attempt < 3 {
+                Err(format!("attempt {attempt} failed").into())
+            } else {
+                Ok(attempt)
+            }
+        }
+    })
+    .await;
+    assert_eq!(result.unwrap(), 3);
+    assert_eq!(calls, 3);
+    // the retries waited for 10ms and then 20ms
+    assert!(start.elapsed() >= Duration::from_millis(30));
+
+    // the last error is returned once the attempts run out
+    let mut calls = 0;
+    let result: Result<()> = retry_with_backoff(2, backoff, || {
+        calls += 1;
+        let attempt = calls;
+        async move { Err(format!("attempt {attempt} failed").into()) }
+    })
+    .await;
+    assert_eq!(result.unwrap_err().to_string(), "attempt 2 failed");
+    assert_eq!(calls, 2);
+}
@@ -5,11 +5,13 @@ use crate::{
     client,
     client::{h09, h3},
     intercept::Intercept,
-    interop::Testcase,
-    task, tls, Result,
+    interop::{TestResult, Testcase, UnsupportedTestcase},
+    task, tls,
+    tx_trace::TxTrace,
+    Result,
 };
 use core::time::Duration;
-use s2n_quic::{client::Connect, provider::event, Client};
+use s2n_quic::{application, client::Connect, connection, provider::event, stream, Client};
 use std::{
     collections::{hash_map::Entry, HashMap},
     path::PathBuf,
@@ -19,6 +21,9 @@ use structopt::StructOpt;
 use tokio::net::lookup_host;
 use url::{Host, Url};
 
+/// The application error code used to close connections in the `ConnectionClose` testcase
+const CONNECTION_CLOSE_ERROR_CODE: u32 = 0x5151;
+
 #[derive(Debug, StructOpt)]
 pub struct Interop {
     #[structopt(short, long)]
@@ -36,12 +41,21 @@ pub struct Interop {
     #[structopt(long, parse(try_from_str = parse_duration))]
     keep_alive: Option<Duration>,
 
-    #[structopt(long, env = "TESTCASE", possible_values = &Testcase::supported(is_supported_testcase))]
+    /// Unsupported testcases are reported to `--results` before exiting with code 127
+    #[structopt(long, env = "TESTCASE")]
     testcase: Option<Testcase>,
 
     #[structopt(long, default_value = "20")]
     concurrency: u64,
 
+    /// Writes the outcome of the testcase to the given path as JSON
+    #[structopt(long)]
+    results: Option<PathBuf>,
+
+    /// Writes the congestion and pacing budget of every 1-RTT packet to the given path as CSV
+    #[structopt(long)]
+    tx_trace: Option<PathBuf>,
+
     #[structopt(min_values = 1, required = true)]
     requests: Vec<Url>,
 
@@ -66,7 +80,46 @@ pub struct Interop {
 
 impl Interop {
     pub fn run(&self) -> Result<()> {
-        self.runtime.build()?.block_on(self.task())
+        if let Some(testcase) = self.testcase.filter(|t| !is_supported_testcase(*t)) {
+            self.write_results(TestResult::unsupported(testcase))?;
+            return Err(UnsupportedTestcase(testcase).into());
+        }
+
+        self.runtime.build()?.block_on(async {
+            let testcase = if let Some(testcase) = self.testcase {
+                testcase
+            } else {
+                return self.task().await;
+            };
+
+            // fail the testcase rather than hanging if the peer stops making progress
+            let timeout = testcase.default_timeout();
+            let result = tokio::time::timeout(timeout, self.task())
+                .await
+                .map_err(|_| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        format!(
+                            "testcase {} did not complete within {timeout:?}",
+                            testcase.as_str()
+                        ),
+                    )
+                    .into()
+                })
+                .and_then(|result| result);
+
+            self.write_results(TestResult::new(testcase, &result))?;
+
+            result
+        })
+    }
+
+    fn write_results(&self, result: TestResult) -> Result<()> {
+        if let Some(path) = &self.results {
+            let json = TestResult::to_json(&[result])?;
+            std::fs::write(path, json)?;
+        }
+        Ok(())
     }
 
     async fn task(&self) -> Result<()> {
@@ -94,25 +147,44 @@ impl Interop {
         };
         let mut tasks = task::Limiter::new(concurrency);
 
+        let close_error = if matches!(self.testcase, Some(Testcase::ConnectionClose)) {
+            Some(application::Error::from(CONNECTION_CLOSE_ERROR_CODE))
+        } else {
+            None
+        };
+
         // https://github.com/marten-seemann/quic-interop-runner#test-cases
         // Handshake Loss (multiconnect): Tests resilience of the handshake to high loss.
         // The client is expected to establish multiple connections, sequential or in parallel,
         // and use each connection to download a single file.
         if let Some(Testcase::Multiconnect | Testcase::Resumption) = self.testcase {
-            for request in self.requests.iter().cloned() {
+            for (index, request) in self.requests.iter().cloned().enumerate() {
                 let connect = endpoints.get(&request.host().unwrap()).unwrap().clone();
                 let requests = core::iter::once(request);
 
+                // every connection after the first resumes with the ticket from the previous one
+                let session_tickets =
+                    if matches!(self.testcase, Some(Testcase::Resumption)) && index > 0 {
+                        self.tls.session_ticket_store()
+                    } else {
+                        None
+                    };
+
                 let task = h09::create_connection(
                     client.clone(),
                     connect,
                     requests,
                     download_dir.clone(),
-                    keep_alive,
+                    h09::ConnectionOptions {
+                        keep_alive,
+                        close_error,
+                        testcase: self.testcase,
+                        session_tickets,
+                    },
                 );
 
                 if let Some(task) = tasks.spawn(task).await {
-                    task??;
+                    self.check_result(task?)?;
                 }
             }
         } else {
@@ -143,20 +215,25 @@ impl Interop {
                         connect,
                         requests,
                         download_dir.clone(),
-                        self.keep_alive,
+                        h09::ConnectionOptions {
+                            keep_alive: self.keep_alive,
+                            close_error,
+                            testcase: self.testcase,
+                            session_tickets: None,
+                        },
                     );
 
                     tasks.spawn(task).await
                 };
 
                 if let Some(task) = prev {
-                    task??;
+                    self.check_result(task?)?;
                 }
             }
         }
 
         while let Some(task) = tasks.join_next().await {
-            task??;
+            self.check_result(task?)?;
         }
 
         client.wait_idle().await?;
@@ -164,6 +241,23 @@ impl Interop {
         Ok(())
     }
 
+    /// Checks the outcome of a connection task
+    ///
+    /// In the `StatelessReset` testcase the server loses its connection state, so a connection
+    /// being closed by a stateless reset is the expected outcome.
+    fn check_result(&self, result: Result<()>) -> Result<()> {
+        match result {
+            Err(error)
+                if matches!(self.testcase, Some(Testcase::StatelessReset))
+                    && is_stateless_reset(&*error) =>
+            {
+                eprintln!("connection was closed by a stateless reset");
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
     fn client(&self) -> Result<Client> {
         let io = self.io.build()?;
 
@@ -172,7 +266,10 @@ impl Interop {
         let client = Client::builder()
             .with_io(io)?
             .with_limits(limits)?
-            .with_event(event::tracing::Subscriber::default())?;
+            .with_event((
+                event::tracing::Subscriber::default(),
+                TxTrace::new(self.tx_trace.as_deref())?,
+            ))?;
 
         // setup the packet interceptor if internal dev
         #[cfg(s2n_internal_dev)]
@@ -243,19 +340,104 @@ fn is_supported_testcase(testcase: Testcase) -> bool {
         ChaCha20 => false,
         // TODO add the ability to trigger a key update from the application
         KeyUpdate => false,
+        // requires both of the ChaCha20 and KeyUpdate TODOs
+        ChaCha20KeyUpdate => false,
         Retry => true,
         Resumption => true,
         // TODO implement 0rtt
         ZeroRtt => false,
+        ZeroRttReject => false,
         Http3 => true,
         Multiconnect => true,
         Ecn => true,
         // TODO support the ability to actively migrate on the client
         ConnectionMigration => false,
+        ZeroLengthCid => true,
+        ConnectionClose => true,
+        LargeCertChain => true,
+        StatelessReset => true,
+        PathMtu => true,
+        // the requests share a connection so opening streams blocks on the server's limit
+        StreamLimits => true,
+        // unknown transport parameters from the peer are ignored while decoding
+        Grease => true,
+        // reordering is handled by the network simulator
+        Reorder => true,
+    }
+}
+
+/// Returns true if the error was caused by the peer responding with a stateless reset
+fn is_stateless_reset(error: &(dyn std::error::Error + 'static)) -> bool {
+    // stream errors are wrapped in an `io::Error` when using the `AsyncRead` and `AsyncWrite` impls
+    if let Some(error) = error.downcast_ref::<std::io::Error>() {
+        return error
+            .get_ref()
+            .map_or(false, |error| is_stateless_reset(error));
+    }
+
+    if let Some(stream::Error::ConnectionError { error, .. }) = error.downcast_ref() {
+        return matches!(error, connection::Error::StatelessReset { .. });
     }
+
+    matches!(
+        error.downcast_ref(),
+        Some(connection::Error::StatelessReset { .. })
+    )
 }
 
 fn parse_duration(duration: &str) -> Result<Duration> {
     let seconds = duration.parse()?;
     Ok(Duration::from_secs(seconds))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The testcases this endpoint has an implementation for
+    const IMPLEMENTED_TESTCASES: &[Testcase] = &[
+        Testcase::Handshake,
+        Testcase::Transfer,
+        Testcase::Retry,
+        Testcase::Resumption,
+        Testcase::Http3,
+        Testcase::Multiconnect,
+        Testcase::Ecn,
+        Testcase::ZeroLengthCid,
+        Testcase::ConnectionClose,
+        Testcase::LargeCertChain,
+        Testcase::StatelessReset,
+        Testcase::PathMtu,
+        Testcase::StreamLimits,
+        Testcase::Grease,
+        Testcase::Reorder,
+    ];
+
+    #[test]
+    fn supported_testcases_are_implemented() {
+        Testcase::assert_supported_implemented(is_supported_testcase, IMPLEMENTED_TESTCASES);
+    }
+
+    #[test]
+    fn stateless_reset_errors_are_detected() {
+        let reset = connection::Error::stateless_reset();
+        let errors: [crate::Error; 3] = [
+            reset.into(),
+            stream::Error::from(reset).into(),
+            std::io::Error::from(stream::Error::from(reset)).into(),
+        ];
+        for error in &errors {
+            assert!(is_stateless_reset(&**error), "{error:?}");
+        }
+
+        let closed = connection::Error::idle_timer_expired();
+        let errors: [crate::Error; 3] = [
+            closed.into(),
+            stream::Error::from(closed).into(),
+            "request failed".into(),
+        ];
+        for error in &errors {
+            assert!(!is_stateless_reset(&**error), "{error:?}");
+        }
+    }
+}
@@ -67,12 +67,125 @@ impl Stream for File {
     }
 }
 
-pub(crate) fn abs_path(path: &str, www_dir: &Path) -> PathBuf {
-    let mut abs_path = www_dir.to_path_buf();
-    abs_path.extend(
-        path.split('/')
-            .filter(|segment| !segment.starts_with('.'))
-            .map(std::path::Path::new),
-    );
-    abs_path
+/// Resolves a request path to a file in `www_dir`
+///
+/// Returns `None` if the path is not safe to serve. See [`normalize_request_path`].
+pub(crate) fn abs_path(path: &str, www_dir: &Path) -> Option<PathBuf> {
+    let path = normalize_request_path(path)?;
+    Some(www_dir.join(path))
+}
+
+/// Normalizes a request path into a path relative to the server root
+///
+/// The path is percent-decoded before being split into segments, so encoded separators and dots
+/// are validated the same as literal ones. Empty and `.` segments are removed, which also strips
+/// any leading slashes. `None` is returned if any segment would escape the root or refer to a
+/// hidden file, i.e. it starts with a `.`, or if it contains a `\` or NUL byte.
+pub(crate) fn normalize_request_path(path: &str) -> Option<String> {
+    let path = percent_decode(path)?;
+    let mut normalized = String::with_capacity(path.len());
+
+    for segment in path.split('/') {
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+
+        if segment.starts_with('.') || segment.contains(['\\', '\0']) {
+            return None;
+        }
+
+        if !normalized.is_empty() {
+            normalized.push('/');
+        }
+        normalized.push_str(segment);
+    }
+
+    Some(normalized)
+}
+
+/// Decodes `%XX` escapes in the path
+///
+/// Returns `None` if an escape is malformed or the decoded path is not valid UTF-8.
+fn percent_decode(path: &str) -> Option<String> {
+    if !path.contains('%') {
+        return Some(path.to_string());
+    }
+
+    let mut bytes = path.bytes();
+    let mut decoded = Vec::with_capacity(path.len());
+
+    while let Some(byte) = bytes.next() {
+        if byte != b'%' {
+            decoded.push(byte);
+            continue;
+        }
+
+        let high = (bytes.next()? as char).to_digit(16)?;
+        let low = (bytes.next()? as char).to_digit(16)?;
+        decoded.push((high << 4 | low) as u8);
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_request_path_test() {
+        // legitimate paths
+        assert_eq!(normalize_request_path("").as_deref(), Some(""));
+        assert_eq!(normalize_request_path("abc").as_deref(), Some("abc"));
+        assert_eq!(normalize_request_path("/abc").as_deref(), Some("abc"));
+        assert_eq!(
+            normalize_request_path("a/b/c.txt").as_deref(),
+            Some("a/b/c.txt")
+        );
+        assert_eq!(normalize_request_path("//a/./b//").as_deref(), Some("a/b"));
+        assert_eq!(normalize_request_path("a%20b").as_deref(), Some("a b"));
+
+        // traversal attempts
+        for path in [
+            "..",
+            "/../../etc/passwd",
+            "a/../../b",
+            "a/..",
+            "..\\etc",
+            "a\\..\\..\\b",
+            ".hidden",
+            "a/.git/config",
+        ] {
+            assert_eq!(normalize_request_path(path), None, "{path:?}");
+        }
+
+        // encoded traversal attempts
+        for path in [
+            "%2e%2e",
+            "%2E%2E/etc/passwd",
+            ".%2e/a",
+            "a/..%2fb",
+            "a%2f..%2f..%2fb",
+            "a%5c..",
+            "a%00b",
+        ] {
+            assert_eq!(normalize_request_path(path), None, "{path:?}");
+        }
+
+        // malformed escapes
+        for path in ["%", "%2", "%zz", "%ff"] {
+            assert_eq!(normalize_request_path(path), None, "{path:?}");
+        }
+    }
+
+    #[test]
+    fn abs_path_test() {
+        let www_dir = Path::new("/www");
+        assert_eq!(
+            abs_path("/a/b.txt", www_dir),
+            Some(PathBuf::from("/www/a/b.txt"))
+        );
+        assert_eq!(abs_path("/", www_dir), Some(PathBuf::from("/www")));
+        assert_eq!(abs_path("/../etc/passwd", www_dir), None);
+    }
 }
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::Result;
-use core::str::FromStr;
+use core::{fmt, str::FromStr, time::Duration};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::io;
 
 /// From <https://github.com/marten-seemann/quic-interop-runner#test-cases>
@@ -39,6 +40,13 @@ pub enum Testcase {
     /// It doesn't matter which peer actually initiated the update.
     KeyUpdate,
 
+    /// Tests key updates with ChaCha20 (client only)
+    ///
+    /// Client and server are expected to offer only ChaCha20 as a ciphersuite, as in the ChaCha20
+    /// testcase, and the client is expected to force a key update during the transfer, as in the
+    /// KeyUpdate testcase. This exercises the ChaCha20 key derivation for the next key phase.
+    ChaCha20KeyUpdate,
+
     /// Tests that the server can generate a Retry, and that the client can act upon it.
     ///
     /// The client should use the Token provided in the Retry packet in the Initial packet.
@@ -59,6 +67,13 @@ pub enum Testcase {
     ///  the first file, the client has to close the connection, establish and request the remaining file(s) in 0-RTT.
     ZeroRtt,
 
+    /// Tests QUIC 0-RTT rejection.
+    ///
+    /// The client is expected to attempt a 0-RTT connection with a session ticket the server no
+    /// longer accepts. The server rejects the early data and the client is expected to resend the
+    /// rejected requests over 1-RTT and download all of the files.
+    ZeroRttReject,
+
     /// Tests a simple HTTP/3 connection.
     ///
     /// The client is expected to download multiple files using HTTP/3. Files should be requested and transferred in parallel.
@@ -76,6 +91,60 @@ pub enum Testcase {
     ///
     /// A transfer succeeded during which the client performed an active migration.
     ConnectionMigration,
+
+    /// Tests a transfer with a server that issues zero-length connection IDs
+    ///
+    /// The client is expected to complete the transfer even though the packets it sends to the
+    /// server carry an empty Destination Connection ID.
+    ZeroLengthCid,
+
+    /// Tests closing a connection with an application error code
+    ///
+    /// The client is expected to download the files and then close the connection with an
+    /// application error code. Both endpoints should report the same error code.
+    ConnectionClose,
+
+    /// Tests a handshake with a large certificate chain
+    ///
+    /// The server is configured with a certificate chain large enough that its CRYPTO data spans
+    /// multiple Initial and Handshake packets. The client is expected to complete the handshake
+    /// and download the files.
+    LargeCertChain,
+
+    /// Tests detection of a stateless reset
+    ///
+    /// The server loses its connection state during the transfer and responds to the client's
+    /// packets with a stateless reset. The client is expected to recognize the reset token it
+    /// received in a NEW_CONNECTION_ID frame and tear down the connection.
+    StatelessReset,
+
+    /// Tests Datagram Packetization Layer Path MTU Discovery (DPLPMTUD)
+    ///
+    /// The endpoints probe for a larger MTU with padded PING packets during the transfer. The
+    /// client is expected to download the files and report an MTU larger than the 1200 byte
+    /// baseline once the probes have been acknowledged.
+    PathMtu,
+
+    /// Tests stream concurrency limits
+    ///
+    /// The server advertises a small MAX_STREAMS limit and the client requests more files than it
+    /// is allowed to open streams for. The client is expected to block on opening new streams and
+    /// resume as the server raises the limit with MAX_STREAMS frames when streams complete.
+    StreamLimits,
+
+    /// Tests tolerance of greased transport parameters
+    ///
+    /// The peer includes reserved transport parameters, with identifiers of the form
+    /// `31 * N + 27`, in its handshake. The endpoint is expected to ignore them and complete the
+    /// handshake and download the files.
+    Grease,
+
+    /// Tests resilience of the handshake and a transfer to heavy reordering
+    ///
+    /// The network delivers packets out of order, but doesn't drop them. The client is expected to
+    /// complete the handshake and download the files, which requires the CRYPTO and STREAM data
+    /// to be reassembled and the out-of-order packets to be acknowledged.
+    Reorder,
 }
 
 impl Testcase {
@@ -85,13 +154,23 @@ impl Testcase {
         Self::Transfer,
         Self::ChaCha20,
         Self::KeyUpdate,
+        Self::ChaCha20KeyUpdate,
         Self::Retry,
         Self::Resumption,
         Self::ZeroRtt,
+        Self::ZeroRttReject,
         Self::Http3,
         Self::Multiconnect,
         Self::Ecn,
         Self::ConnectionMigration,
+        Self::ZeroLengthCid,
+        Self::ConnectionClose,
+        Self::LargeCertChain,
+        Self::StatelessReset,
+        Self::PathMtu,
+        Self::StreamLimits,
+        Self::Grease,
+        Self::Reorder,
     ];
 
     pub const fn as_str(self) -> &'static str {
@@ -102,13 +181,40 @@ impl Testcase {
             Transfer => "transfer",
             ChaCha20 => "chacha20",
             KeyUpdate => "keyupdate",
+            ChaCha20KeyUpdate => "chacha20keyupdate",
             Retry => "retry",
             Resumption => "resumption",
             ZeroRtt => "zerortt",
+            ZeroRttReject => "zerorttreject",
             Http3 => "http3",
             Multiconnect => "multiconnect",
             Ecn => "ecn",
             ConnectionMigration => "connectionmigration",
+            ZeroLengthCid => "zerolengthconnid",
+            ConnectionClose => "connectionclose",
+            LargeCertChain => "largecertchain",
+            StatelessReset => "statelessreset",
+            PathMtu => "pathmtu",
+            StreamLimits => "streamlimits",
+            Grease => "grease",
+            Reorder => "reorder",
+        }
+    }
+
+    /// Returns the amount of time a single run of the testcase is allowed to take
+    ///
+    /// Testcases which transfer larger files or establish many connections under loss are
+    /// given more time than the ones which only complete a handshake.
+    pub const fn default_timeout(self) -> Duration {
+        use Testcase::*;
+        match self {
+            VersionNegotiation | Handshake | ChaCha20 | Retry | Ecn | ZeroLengthCid
+            | ConnectionClose | LargeCertChain | StatelessReset | Grease => Duration::from_secs(60),
+            Transfer | KeyUpdate | ChaCha20KeyUpdate | Resumption | ZeroRtt | ZeroRttReject
+            | Http3 | ConnectionMigration | PathMtu | StreamLimits | Reorder => {
+                Duration::from_secs(120)
+            }
+            Multiconnect => Duration::from_secs(300),
         }
     }
 
@@ -123,6 +229,40 @@ impl Testcase {
 
         results
     }
+
+    /// Panics if the `supported` predicate advertises a testcase that isn't in `implemented`
+    ///
+    /// This keeps the cases reported to the interop runner in sync with what the endpoint can run.
+    #[cfg(test)]
+    pub fn assert_supported_implemented(supported: impl Fn(Self) -> bool, implemented: &[Self]) {
+        let unimplemented: Vec<_> = Self::supported(supported)
+            .into_iter()
+            .filter(|name| !implemented.iter().any(|case| case.as_str() == *name))
+            .collect();
+
+        assert!(
+            unimplemented.is_empty(),
+            "advertised testcases are not implemented: {}",
+            Self::format_list(&unimplemented)
+        );
+    }
+
+    /// Joins the given testcase names into the comma-separated interop runner format
+    pub fn format_list(cases: &[&str]) -> String {
+        cases.join(",")
+    }
+
+    /// Parses a comma-separated list of testcase names
+    ///
+    /// Returns an error if any of the names is not a known testcase.
+    #[allow(dead_code)] // the endpoints currently only accept a single testcase
+    pub fn parse_list(list: &str) -> Result<Vec<Self>> {
+        if list.trim().is_empty() {
+            return Ok(vec![]);
+        }
+
+        list.split(',').map(|case| case.trim().parse()).collect()
+    }
 }
 
 impl FromStr for Testcase {
@@ -137,20 +277,241 @@ impl FromStr for Testcase {
             "transfer" => Transfer,
             "chacha20" => ChaCha20,
             "keyupdate" => KeyUpdate,
+            "chacha20keyupdate" => ChaCha20KeyUpdate,
             "retry" => Retry,
             "resumption" => Resumption,
             "zerortt" => ZeroRtt,
+            "zerorttreject" => ZeroRttReject,
             "http3" => Http3,
             "multiconnect" => Multiconnect,
             "ecn" => Ecn,
             "connectionmigration" => ConnectionMigration,
+            "zerolengthconnid" => ZeroLengthCid,
+            "connectionclose" => ConnectionClose,
+            "largecertchain" => LargeCertChain,
+            "statelessreset" => StatelessReset,
+            "pathmtu" => PathMtu,
+            "streamlimits" => StreamLimits,
+            "grease" => Grease,
+            "reorder" => Reorder,
             _ => {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidInput,
-                    format!("Unsupported test case: {s}"),
+                    format!(
+                        "Unsupported test case: {s}; expected one of {}",
+                        Testcase::format_list(&Testcase::supported(|_| true))
+                    ),
                 )
                 .into())
             }
         })
     }
 }
+
+impl Serialize for Testcase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Testcase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // JSON strings with escapes, or values from a reader, can't be borrowed from the input
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The outcome of running a single testcase
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Unsupported,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "fail"),
+            Self::Unsupported => write!(f, "unsupported"),
+        }
+    }
+}
+
+/// The machine-readable result of a testcase, as reported to the interop dashboard
+#[derive(Clone, Debug, Serialize)]
+pub struct TestResult {
+    pub case: Testcase,
+    pub outcome: Outcome,
+    /// Explains the outcome, e.g. the error that failed the testcase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl TestResult {
+    /// Creates a result from the outcome of running the testcase
+    pub fn new<T>(case: Testcase, result: &Result<T>) -> Self {
+        match result {
+            Ok(_) => Self {
+                case,
+                outcome: Outcome::Pass,
+                detail: None,
+            },
+            Err(error) => Self {
+                case,
+                outcome: Outcome::Fail,
+                detail: Some(error.to_string()),
+            },
+        }
+    }
+
+    /// Creates a result for a testcase the endpoint doesn't implement
+    pub fn unsupported(case: Testcase) -> Self {
+        Self {
+            case,
+            outcome: Outcome::Unsupported,
+            detail: None,
+        }
+    }
+
+    /// Serializes the results into the JSON array consumed by the interop dashboard
+    pub fn to_json(results: &[Self]) -> Result<String> {
+        Ok(serde_json::to_string(results)?)
+    }
+}
+
+/// Returned when an endpoint is asked to run a testcase it doesn't implement
+///
+/// The interop runner expects the process to exit with code 127 in this case.
+#[derive(Clone, Copy, Debug)]
+pub struct UnsupportedTestcase(pub Testcase);
+
+impl fmt::Display for UnsupportedTestcase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsupported testcase: {}", self.0.as_str())
+    }
+}
+
+impl std::error::Error for UnsupportedTestcase {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_round_trip_test() {
+        let cases = Testcase::supported(|_| true);
+        let list = Testcase::format_list(&cases);
+        let parsed = Testcase::parse_list(&list).unwrap();
+        let names: Vec<_> = parsed.iter().map(|case| case.as_str()).collect();
+        assert_eq!(names, cases);
+        assert_eq!(
+            Testcase::format_list(&["handshake", "transfer"]),
+            "handshake,transfer"
+        );
+    }
+
+    #[test]
+    fn default_timeout_test() {
+        for testcase in Testcase::TESTCASES.iter().copied() {
+            let timeout = testcase.default_timeout();
+            assert!(!timeout.is_zero(), "{testcase:?}");
+            assert!(timeout <= Duration::from_secs(600), "{testcase:?}");
+        }
+
+        assert!(Testcase::Multiconnect.default_timeout() > Testcase::Handshake.default_timeout());
+    }
+
+    #[test]
+    fn empty_list_test() {
+        assert_eq!(Testcase::format_list(&[]), "");
+        assert!(Testcase::parse_list("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn single_entry_test() {
+        assert_eq!(Testcase::format_list(&["handshake"]), "handshake");
+        let parsed = Testcase::parse_list("handshake").unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert!(matches!(parsed[0], Testcase::Handshake));
+    }
+
+    #[test]
+    fn supported_implemented_test() {
+        Testcase::assert_supported_implemented(
+            |case| matches!(case, Testcase::Handshake | Testcase::Transfer),
+            &[Testcase::Handshake, Testcase::Transfer, Testcase::Retry],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "advertised testcases are not implemented: transfer")]
+    fn supported_unimplemented_test() {
+        Testcase::assert_supported_implemented(
+            |case| matches!(case, Testcase::Handshake | Testcase::Transfer),
+            &[Testcase::Handshake],
+        );
+    }
+
+    #[test]
+    fn unknown_entry_test() {
+        assert!(Testcase::parse_list("handshake,handshak").is_err());
+        assert!(Testcase::parse_list("handshake,,transfer").is_err());
+    }
+
+    #[test]
+    fn result_json_test() {
+        let results = [
+            TestResult::new(Testcase::Handshake, &Ok(())),
+            TestResult::new(Testcase::Transfer, &Err::<(), _>("stream reset".into())),
+            TestResult::unsupported(Testcase::KeyUpdate),
+        ];
+
+        let json = TestResult::to_json(&results).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"[{"case":"handshake","outcome":"pass"},"#,
+                r#"{"case":"transfer","outcome":"fail","detail":"stream reset"},"#,
+                r#"{"case":"keyupdate","outcome":"unsupported"}]"#,
+            )
+        );
+    }
+
+    #[test]
+    fn outcome_round_trip_test() {
+        for outcome in [Outcome::Pass, Outcome::Fail, Outcome::Unsupported] {
+            let json = serde_json::to_string(&outcome).unwrap();
+            assert_eq!(json, format!("\"{outcome}\""));
+            assert_eq!(serde_json::from_str::<Outcome>(&json).unwrap(), outcome);
+        }
+    }
+
+    #[test]
+    fn testcase_round_trip_test() {
+        for testcase in Testcase::TESTCASES.iter().copied() {
+            let json = serde_json::to_string(&testcase).unwrap();
+            let parsed: Testcase = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.as_str(), testcase.as_str());
+        }
+
+        assert!(serde_json::from_str::<Testcase>(r#""handshak""#).is_err());
+    }
+
+    #[test]
+    fn testcase_owned_deserialize_test() {
+        let value = serde_json::json!("transfer");
+        let parsed: Testcase = serde_json::from_value(value).unwrap();
+        assert!(matches!(parsed, Testcase::Transfer));
+
+        let parsed: Testcase = serde_json::from_reader(&br#""handshake""#[..]).unwrap();
+        assert!(matches!(parsed, Testcase::Handshake));
+
+        // escaped strings can't be borrowed from the input
+        let parsed: Testcase = serde_json::from_str(r#""\u0072etry""#).unwrap();
+        assert!(matches!(parsed, Testcase::Retry));
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::Result;
 use core::time::Duration;
 
 #[derive(Debug, structopt::StructOpt)]
@@ -23,6 +24,31 @@ pub struct Limits {
     /// The maximum time (in seconds) a connection will remain open without contact from the peer
     #[structopt(long, default_value = "300")]
     pub max_idle_timeout: u64,
+
+    /// The initial RTT estimate in milliseconds, used before an RTT sample is taken
+    #[structopt(long, parse(try_from_str = parse_initial_rtt_ms))]
+    pub initial_rtt_ms: Option<u64>,
+
+    /// The maximum time in milliseconds the endpoint will delay sending an ACK-only packet
+    #[structopt(long)]
+    pub max_ack_delay_ms: Option<u64>,
+
+    /// The maximum size in bytes the connection receive window can be auto-tuned to
+    #[structopt(long)]
+    pub max_data_window: Option<u64>,
+
+    /// The factor the connection receive window is multiplied by when auto-tuned
+    #[structopt(long)]
+    pub data_window_growth_factor: Option<u8>,
+
+    /// The initial number of bidirectional and unidirectional streams the peer may open
+    #[structopt(long)]
+    pub max_streams: Option<u64>,
+
+    /// Sends the `disable_active_migration` transport parameter, forbidding the peer from
+    /// actively migrating the connection
+    #[structopt(long)]
+    pub disable_migration: bool,
 }
 
 impl Limits {
@@ -51,6 +77,38 @@ impl Limits {
             limits = limits.with_max_send_buffer_size(size).unwrap();
         }
 
+        if let Some(rtt) = self.initial_rtt_ms {
+            limits = limits
+                .with_initial_round_trip_time(Duration::from_millis(rtt))
+                .unwrap();
+        }
+
+        if let Some(delay) = self.max_ack_delay_ms {
+            limits = limits
+                .with_max_ack_delay(Duration::from_millis(delay))
+                .unwrap();
+        }
+
+        if let Some(window) = self.max_data_window {
+            limits = limits.with_max_data_window(window).unwrap();
+        }
+
+        if let Some(factor) = self.data_window_growth_factor {
+            limits = limits.with_data_window_growth_factor(factor).unwrap();
+        }
+
+        if let Some(max_streams) = self.max_streams {
+            limits = limits
+                .with_max_open_remote_bidirectional_streams(max_streams)
+                .unwrap()
+                .with_max_open_remote_unidirectional_streams(max_streams)
+                .unwrap();
+        }
+
+        if self.disable_migration {
+            limits = limits.with_active_migration(false).unwrap();
+        }
+
         limits
     }
 
@@ -63,3 +121,31 @@ impl Limits {
         .as_u64()
     }
 }
+
+/// Parses the initial RTT estimate in milliseconds
+///
+/// The RTT estimate can't be 0, so it is rejected here rather than when the limits are built.
+fn parse_initial_rtt_ms(rtt: &str) -> Result<u64> {
+    let rtt = rtt.parse()?;
+
+    if rtt == 0 {
+        return Err("the initial RTT must be at least 1 millisecond".into());
+    }
+
+    Ok(rtt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_rtt_ms_test() {
+        assert_eq!(parse_initial_rtt_ms("1").unwrap(), 1);
+        assert_eq!(parse_initial_rtt_ms("333").unwrap(), 333);
+
+        for rtt in ["0", "-1", "rtt"] {
+            assert!(parse_initial_rtt_ms(rtt).is_err(), "{rtt}");
+        }
+    }
+}
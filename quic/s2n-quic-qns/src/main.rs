@@ -18,6 +18,7 @@ mod runtime;
 mod server;
 mod task;
 mod tls;
+mod tx_trace;
 #[cfg(feature = "xdp")]
 mod xdp;
 
@@ -46,6 +47,12 @@ fn main() {
         Ok(args) => {
             if let Err(error) = args.run() {
                 eprintln!("Error: {error:?}");
+
+                // The interop runner also expects code 127 for testcases we don't implement
+                if error.is::<interop::UnsupportedTestcase>() {
+                    std::process::exit(127);
+                }
+
                 std::process::exit(1);
             }
         }
@@ -5,6 +5,8 @@ mod h09;
 mod h3;
 pub mod interop;
 pub mod perf;
+mod rate_limit;
+mod responder;
 #[cfg(all(s2n_quic_unstable, feature = "unstable_client_hello"))]
 mod unstable;
 
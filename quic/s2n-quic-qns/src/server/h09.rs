@@ -3,31 +3,69 @@
 
 use crate::{
     file::{abs_path, File},
-    server::interop::MyConnectionContext,
+    server::{
+        interop::MyConnectionContext,
+        rate_limit::{RequestRateLimiter, REQUEST_RATE_LIMITED_ERROR},
+        responder::{ChunkedResponder, ResponseSink},
+    },
     Result,
 };
-use bytes::Bytes;
-use futures::StreamExt;
+use bytes::{Bytes, BytesMut};
+use futures::{future::poll_fn, StreamExt};
 use s2n_quic::{
-    stream::{BidirectionalStream, ReceiveStream, SendStream},
+    stream::{BidirectionalStream, ReceiveStream},
     Connection,
 };
-use std::{path::Path, sync::Arc, time::Duration};
+use s2n_quic_core::stream::testing::Data;
+use std::{
+    fmt,
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
 use tokio::time::timeout;
 use tracing::debug;
 
-pub(crate) async fn handle_connection(mut connection: Connection, www_dir: Arc<Path>) {
+pub(crate) async fn handle_connection(
+    mut connection: Connection,
+    www_dir: Arc<Path>,
+    chunk_size: usize,
+    request_reader: RequestReader,
+    rate_limiter: RequestRateLimiter,
+) {
+    // the handshake has completed by the time the connection is accepted
+    if let Ok(Some(parameters)) = connection.peer_transport_parameters() {
+        eprintln!("peer transport parameters: {parameters:?}");
+    }
+
     loop {
         match connection.accept_bidirectional_stream().await {
-            Ok(Some(stream)) => {
+            Ok(Some(mut stream)) => {
+                if let Ok(remote_addr) = connection.remote_addr() {
+                    if !rate_limiter.on_request(remote_addr) {
+                        debug!("rate limiting Stream({}) from {remote_addr}", stream.id());
+                        let error = REQUEST_RATE_LIMITED_ERROR.into();
+                        let _ = stream.stop_sending(error);
+                        let _ = stream.reset(error);
+                        continue;
+                    }
+                }
+
                 let _ = connection.query_event_context_mut(|context: &mut MyConnectionContext| {
                     context.stream_requests += 1
                 });
 
                 let www_dir = www_dir.clone();
+                let request_reader = request_reader.clone();
                 // spawn a task per stream
                 tokio::spawn(async move {
-                    if let Err(err) = handle_stream(stream, www_dir).await {
+                    if let Err(err) =
+                        handle_stream(stream, www_dir, chunk_size, &request_reader).await
+                    {
                         eprintln!("Stream error: {err:?}")
                     }
                 });
@@ -52,27 +90,37 @@ pub(crate) async fn handle_connection(mut connection: Connection, www_dir: Arc<P
     }
 }
 
-async fn handle_stream(stream: BidirectionalStream, www_dir: Arc<Path>) -> Result<()> {
-    let (rx_stream, mut tx_stream) = stream.split();
-    let path = read_request(rx_stream).await?;
+async fn handle_stream(
+    stream: BidirectionalStream,
+    www_dir: Arc<Path>,
+    chunk_size: usize,
+    request_reader: &RequestReader,
+) -> Result<()> {
+    let (mut rx_stream, mut tx_stream) = stream.split();
+    let path = request_reader.read(&mut rx_stream).await?;
 
     if let Some(amount) = path.strip_prefix("_perf/").and_then(|v| v.parse().ok()) {
-        return handle_perf_stream(amount, tx_stream).await;
+        return ResponseWriter::new(amount, chunk_size)
+            .write(&mut tx_stream)
+            .await;
     }
 
-    let abs_path = abs_path(&path, &www_dir);
+    let abs_path = abs_path(&path, &www_dir).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("invalid request path: {path:?}"),
+        )
+    })?;
     let mut file = File::open(&abs_path).await?;
+    let stream_id = tx_stream.id();
+    let mut responder = ChunkedResponder::new(&mut tx_stream);
     loop {
         match timeout(Duration::from_secs(1), file.next()).await {
             Ok(Some(Ok(chunk))) => {
                 let len = chunk.len();
-                debug!(
-                    "{:?} bytes ready to send on Stream({:?})",
-                    len,
-                    tx_stream.id()
-                );
-                tx_stream.send(chunk).await?;
-                debug!("{:?} bytes sent on Stream({:?})", len, tx_stream.id());
+                debug!("{:?} bytes ready to send on Stream({:?})", len, stream_id);
+                responder.write(chunk).await?;
+                debug!("{:?} bytes sent on Stream({:?})", len, stream_id);
             }
             Ok(Some(Err(err))) => {
                 eprintln!("error opening {abs_path:?}");
@@ -80,8 +128,7 @@ async fn handle_stream(stream: BidirectionalStream, www_dir: Arc<Path>) -> Resul
                 return Err(err.into());
             }
             Ok(None) => {
-                tx_stream.finish()?;
-                return Ok(());
+                return responder.finish();
             }
             Err(_) => {
                 eprintln!("timeout opening {abs_path:?}");
@@ -90,36 +137,201 @@ async fn handle_stream(stream: BidirectionalStream, www_dir: Arc<Path>) -> Resul
     }
 }
 
-async fn handle_perf_stream(amount: u64, mut stream: SendStream) -> Result<()> {
-    let mut data = s2n_quic_core::stream::testing::Data::new(amount);
+/// Streams a generated response body in chunks of at most `chunk_size` bytes
+///
+/// The body is produced on demand so memory usage is bounded by the chunk size rather than the
+/// length of the response.
+struct ResponseWriter {
+    data: Data,
+    chunk_size: usize,
+}
+
+impl ResponseWriter {
+    fn new(len: u64, chunk_size: usize) -> Self {
+        Self {
+            data: Data::new(len),
+            // a chunk size of 0 would never make progress
+            chunk_size: chunk_size.max(1),
+        }
+    }
+
+    fn next_chunk(&mut self) -> Option<Bytes> {
+        self.data.send_one(self.chunk_size)
+    }
+
+    async fn write<S: ResponseSink>(mut self, stream: &mut S) -> Result<()> {
+        let mut responder = ChunkedResponder::new(stream);
+        while let Some(chunk) = self.next_chunk() {
+            responder.write(chunk).await?;
+            // give other connections a chance to make progress between chunks
+            tokio::task::yield_now().await;
+        }
+
+        responder.finish()
+    }
+}
+
+/// A source of request chunks
+///
+/// This is implemented by [`ReceiveStream`] and allows the request loop to be driven by other
+/// sources in tests.
+trait RequestSource {
+    fn poll_receive_vectored(
+        &mut self,
+        chunks: &mut [Bytes],
+        cx: &mut Context,
+    ) -> Poll<Result<(usize, bool)>>;
+}
 
-    while let Some(chunk) = data.send_one(usize::MAX) {
-        stream.send(chunk).await?;
+impl RequestSource for ReceiveStream {
+    fn poll_receive_vectored(
+        &mut self,
+        chunks: &mut [Bytes],
+        cx: &mut Context,
+    ) -> Poll<Result<(usize, bool)>> {
+        ReceiveStream::poll_receive_vectored(self, chunks, cx).map_err(Into::into)
     }
+}
 
-    stream.finish()?;
+/// Reads requests from streams and counts the number of requests that were read successfully
+///
+/// Clones share the same counter so a single reader can be handed to every connection.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestReader {
+    yield_interval: usize,
+    request_count: Arc<AtomicU64>,
+}
 
-    Ok(())
+impl RequestReader {
+    pub(crate) fn new(yield_interval: usize) -> Self {
+        Self {
+            yield_interval,
+            request_count: Default::default(),
+        }
+    }
+
+    /// Returns the number of requests that have been read across all of the clones
+    pub(crate) fn request_count(&self) -> u64 {
+        self.request_count.load(Ordering::Relaxed)
+    }
+
+    async fn read<S: RequestSource>(&self, stream: &mut S) -> Result<String> {
+        let path = read_request(stream, self.yield_interval).await?;
+        self.request_count.fetch_add(1, Ordering::Relaxed);
+        Ok(path)
+    }
 }
 
-async fn read_request(mut stream: ReceiveStream) -> Result<String> {
+/// Reads the request path from the stream
+///
+/// A peer that sends many small chunks can keep the stream ready indefinitely, so the task yields
+/// to the executor after every `yield_interval` received chunks. An interval of 0 is treated as 1.
+async fn read_request<S: RequestSource>(stream: &mut S, yield_interval: usize) -> Result<String> {
+    let request = read_raw_request(stream, yield_interval).await?;
+
     let mut path = String::new();
-    let mut chunks = vec![Bytes::new(), Bytes::new()];
-    let mut total_chunks = 0;
+    // the whole request line has been read so the parser doesn't need to wait for more data
+    // and the parse error is converted into the crate error so it's reported as a stream error
+    parse_h09_request(&[request], &mut path, false)?;
+    Ok(path)
+}
+
+/// Reads the raw request line from the stream without parsing it
+///
+/// The returned bytes include everything up to and including the terminating `\n`, or up to the
+/// end of the stream if the peer finished it without a newline. Anything the peer sent after the
+/// newline is discarded, as HTTP/0.9 requests do not carry a body.
+async fn read_raw_request<S: RequestSource>(
+    stream: &mut S,
+    yield_interval: usize,
+) -> Result<Bytes> {
+    let yield_interval = yield_interval.max(1);
+    let mut request = BytesMut::new();
+    let mut chunks = vec![Bytes::new(); 2];
+    let mut chunks_since_yield = 0;
     loop {
-        // grow the chunks
-        if chunks.len() == total_chunks {
-            chunks.push(Bytes::new());
+        let (consumed, is_open) =
+            poll_fn(|cx| stream.poll_receive_vectored(&mut chunks, cx)).await?;
+
+        for chunk in &mut chunks[..consumed] {
+            let chunk = core::mem::take(chunk);
+            let newline = chunk.iter().position(|&b| b == b'\n');
+            let len = newline.map(|idx| idx + 1).unwrap_or(chunk.len());
+            request.extend_from_slice(&chunk[..len]);
+
+            if request.len() > MAX_REQUEST_LINE_LEN {
+                return Err(RequestParseError::LineTooLong.into());
+            }
+
+            if newline.is_some() {
+                return Ok(request.freeze());
+            }
+        }
+
+        if !is_open {
+            if request.is_empty() {
+                return Err(RequestParseError::Incomplete.into());
+            }
+            return Ok(request.freeze());
+        }
+
+        chunks_since_yield += consumed;
+        if chunks_since_yield >= yield_interval {
+            chunks_since_yield = 0;
+            tokio::task::yield_now().await;
         }
-        let (consumed, is_open) = stream.receive_vectored(&mut chunks[total_chunks..]).await?;
-        total_chunks += consumed;
-        if parse_h09_request(&chunks[..total_chunks], &mut path, is_open)? {
-            return Ok(path);
+    }
+}
+
+/// The maximum length of a request path
+const MAX_PATH_LEN: usize = 4096;
+
+/// The maximum length of a raw request line, including the terminating newline
+const MAX_REQUEST_LINE_LEN: usize = 2 * MAX_PATH_LEN;
+
+/// The reason an HTTP/0.9 request could not be parsed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RequestParseError {
+    /// The request did not start with `GET /`
+    NotGet,
+    /// The request path contained an unsupported character
+    InvalidChar(u8),
+    /// The request path exceeded `MAX_PATH_LEN`
+    TooLong,
+    /// The raw request line exceeded `MAX_REQUEST_LINE_LEN`
+    LineTooLong,
+    /// The stream was closed before the request line was complete
+    Incomplete,
+}
+
+impl fmt::Display for RequestParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotGet => write!(f, "invalid request: expected `GET /`"),
+            Self::InvalidChar(c) => write!(f, "invalid request: unexpected character {c:#04x}"),
+            Self::TooLong => write!(
+                f,
+                "invalid request: path is longer than {MAX_PATH_LEN} bytes"
+            ),
+            Self::LineTooLong => write!(
+                f,
+                "invalid request: request line is longer than {MAX_REQUEST_LINE_LEN} bytes"
+            ),
+            Self::Incomplete => write!(
+                f,
+                "invalid request: stream closed before the request line was complete"
+            ),
         }
     }
 }
 
-fn parse_h09_request(chunks: &[Bytes], path: &mut String, is_open: bool) -> Result<bool> {
+impl std::error::Error for RequestParseError {}
+
+fn parse_h09_request(
+    chunks: &[Bytes],
+    path: &mut String,
+    is_open: bool,
+) -> Result<bool, RequestParseError> {
     let mut bytes = chunks.iter().flat_map(|chunk| chunk.iter().cloned());
 
     macro_rules! expect {
@@ -127,7 +339,8 @@ fn parse_h09_request(chunks: &[Bytes], path: &mut String, is_open: bool) -> Resu
             match bytes.next() {
                 Some($char) => {}
                 None if is_open => return Ok(false),
-                _ => return Err("invalid request".into()),
+                None => return Err(RequestParseError::Incomplete),
+                _ => return Err(RequestParseError::NotGet),
             }
         };
     }
@@ -142,6 +355,10 @@ fn parse_h09_request(chunks: &[Bytes], path: &mut String, is_open: bool) -> Resu
     path.clear();
 
     loop {
+        if path.len() > MAX_PATH_LEN {
+            return Err(RequestParseError::TooLong);
+        }
+
         match bytes.next() {
             Some(c @ b'0'..=b'9') => path.push(c as char),
             Some(c @ b'a'..=b'z') => path.push(c as char),
@@ -156,7 +373,7 @@ fn parse_h09_request(chunks: &[Bytes], path: &mut String, is_open: bool) -> Resu
             // > If any further words are found on the request line, they MUST either be ignored,
             // > or else treated according to the full HTTP spec.
             Some(b' ') => return Ok(true),
-            Some(c) => return Err(format!("invalid request {}", c as char).into()),
+            Some(c) => return Err(RequestParseError::InvalidChar(c)),
             None => return Ok(!is_open),
         }
     }
@@ -164,7 +381,7 @@ fn parse_h09_request(chunks: &[Bytes], path: &mut String, is_open: bool) -> Resu
 
 #[test]
 fn parse_h09_request_test() {
-    fn parse(chunks: &[&str]) -> Result<Option<String>> {
+    fn parse(chunks: &[&str]) -> Result<Option<String>, RequestParseError> {
         let chunks: Vec<_> = chunks
             .iter()
             .map(|v| Bytes::copy_from_slice(v.as_bytes()))
@@ -189,11 +406,297 @@ fn parse_h09_request_test() {
         }}
     }
 
-    assert!(parse(&[]).is_err());
+    assert_eq!(parse(&[]), Err(RequestParseError::Incomplete));
     test!(["GET /"], Some(""));
     test!(["GET /abc"], Some("abc"));
     test!(["GET /abc/123"], Some("abc/123"));
     test!(["GET /CAPS/lower"], Some("CAPS/lower"));
     test!(["GET /abc\rextra stuff"], Some("abc"));
     test!(["G", "E", "T", " ", "/", "t", "E", "s", "T"], Some("tEsT"));
+
+    assert_eq!(parse(&["GE"]), Err(RequestParseError::Incomplete));
+    assert_eq!(parse(&["POST /abc"]), Err(RequestParseError::NotGet));
+    assert_eq!(parse(&["GET abc"]), Err(RequestParseError::NotGet));
+    assert_eq!(
+        parse(&["GET /a?b"]),
+        Err(RequestParseError::InvalidChar(b'?'))
+    );
+    assert_eq!(
+        parse(&["GET /%20"]),
+        Err(RequestParseError::InvalidChar(b'%'))
+    );
+
+    let path = "a".repeat(MAX_PATH_LEN);
+    assert_eq!(parse(&[&format!("GET /{path}\r\n")]), Ok(Some(path)));
+    let path = "a".repeat(MAX_PATH_LEN + 1);
+    assert_eq!(
+        parse(&[&format!("GET /{path}")]),
+        Err(RequestParseError::TooLong)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_writer_bounded_memory_test() {
+        const LEN: u64 = 4 << 30;
+        const CHUNK_SIZE: usize = 16 * 1024;
+
+        let mut writer = ResponseWriter::new(LEN, CHUNK_SIZE);
+        let mut total = 0;
+        let mut start = usize::MAX;
+        let mut end = 0;
+        while let Some(chunk) = writer.next_chunk() {
+            assert!(chunk.len() <= CHUNK_SIZE);
+            total += chunk.len() as u64;
+
+            let ptr = chunk.as_ptr() as usize;
+            start = start.min(ptr);
+            end = end.max(ptr + chunk.len());
+        }
+
+        assert_eq!(total, LEN);
+
+        // every chunk is a view into the same shared buffer rather than a new allocation, so the
+        // memory backing the response doesn't grow with its length
+        let span = end - start;
+        assert!(span <= Data::MAX_CHUNK_LEN, "chunks span {span} bytes");
+    }
+
+    #[test]
+    fn response_writer_zero_chunk_size_test() {
+        let mut writer = ResponseWriter::new(10, 0);
+        let mut total = 0;
+        while let Some(chunk) = writer.next_chunk() {
+            assert_eq!(chunk.len(), 1);
+            total += chunk.len();
+        }
+        assert_eq!(total, 10);
+    }
+
+    /// Sends the request one byte at a time without ever returning `Pending`
+    struct ByteSource {
+        request: Bytes,
+    }
+
+    impl RequestSource for ByteSource {
+        fn poll_receive_vectored(
+            &mut self,
+            chunks: &mut [Bytes],
+            _cx: &mut Context,
+        ) -> Poll<Result<(usize, bool)>> {
+            if self.request.is_empty() {
+                return Poll::Ready(Ok((0, false)));
+            }
+            chunks[0] = self.request.split_to(1);
+            Poll::Ready(Ok((1, true)))
+        }
+    }
+
+    #[test]
+    fn read_request_yield_test() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let path = "a".repeat(1000);
+            let mut source = ByteSource {
+                request: Bytes::from(format!("GET /{path}\r\n")),
+            };
+
+            // count how many times another task gets scheduled while the request is read
+            let polls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let counter = tokio::spawn({
+                let polls = polls.clone();
+                async move {
+                    loop {
+                        polls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        tokio::task::yield_now().await;
+                    }
+                }
+            });
+
+            let request = read_request(&mut source, 16).await.unwrap();
+            assert_eq!(request, path);
+
+            // the source never returns `Pending` so the other task only runs if the loop yields
+            let polls = polls.load(std::sync::atomic::Ordering::Relaxed);
+            assert!(polls >= 1000 / 16, "other task was polled {polls} times");
+
+            counter.abort();
+        });
+    }
+
+    #[test]
+    fn request_reader_count_test() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let reader = RequestReader::new(16);
+            assert_eq!(reader.request_count(), 0);
+
+            for idx in 0..10 {
+                // requests read through a clone are counted by the original reader
+                let reader = reader.clone();
+                let mut source = ByteSource {
+                    request: Bytes::from(format!("GET /{idx}\r\n")),
+                };
+                assert_eq!(reader.read(&mut source).await.unwrap(), idx.to_string());
+            }
+
+            assert_eq!(reader.request_count(), 10);
+
+            // requests that fail to parse are not counted
+            let mut source = ByteSource {
+                request: Bytes::from_static(b"POST /abc\r\n"),
+            };
+            assert!(reader.read(&mut source).await.is_err());
+            assert_eq!(reader.request_count(), 10);
+        });
+    }
+
+    #[test]
+    fn read_raw_request_test() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let read = |request: &str| {
+                let mut source = ByteSource {
+                    request: Bytes::copy_from_slice(request.as_bytes()),
+                };
+                async move { read_raw_request(&mut source, 16).await }
+            };
+
+            // the raw line is returned as sent, including the `GET ` prefix and line ending
+            let raw = read("GET /abc/123 HTTP/1.0\r\n").await.unwrap();
+            assert_eq!(&raw[..], b"GET /abc/123 HTTP/1.0\r\n");
+
+            // characters rejected by the path parser are preserved
+            let raw = read("GET /a?b=%20\n").await.unwrap();
+            assert_eq!(&raw[..], b"GET /a?b=%20\n");
+
+            // anything after the newline is not part of the request
+            let raw = read("GET /abc\nextra").await.unwrap();
+            assert_eq!(&raw[..], b"GET /abc\n");
+
+            // the end of the stream also ends the request line
+            let raw = read("GET /abc").await.unwrap();
+            assert_eq!(&raw[..], b"GET /abc");
+
+            let err = read("").await.unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<RequestParseError>(),
+                Some(&RequestParseError::Incomplete)
+            );
+
+            let line = format!("GET /{}\n", "a".repeat(MAX_REQUEST_LINE_LEN));
+            let err = read(&line).await.unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<RequestParseError>(),
+                Some(&RequestParseError::LineTooLong)
+            );
+        });
+    }
+
+    #[test]
+    fn rate_limited_request_test() {
+        use s2n_quic::{
+            application, client::Connect, provider::tls::rustls, stream, Client, Server,
+        };
+        use s2n_quic_core::crypto::tls::testing::certificates::{CERT_PEM, KEY_PEM};
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let tls = rustls::Server::builder()
+                .with_certificate(CERT_PEM, KEY_PEM)
+                .unwrap()
+                .with_application_protocols(["hq-interop"].iter())
+                .unwrap()
+                .build()
+                .unwrap();
+            let mut server = Server::builder()
+                .with_io("127.0.0.1:0")
+                .unwrap()
+                .with_tls(tls)
+                .unwrap()
+                .with_event(crate::server::interop::EventSubscriber)
+                .unwrap()
+                .start()
+                .unwrap();
+            let server_addr = server.local_addr().unwrap();
+
+            tokio::spawn(async move {
+                while let Some(connection) = server.accept().await {
+                    // only allow a single request per second so the second one is rejected
+                    tokio::spawn(handle_connection(
+                        connection,
+                        Arc::from(Path::new(".")),
+                        usize::MAX,
+                        RequestReader::new(16),
+                        RequestRateLimiter::new(Some(1)),
+                    ));
+                }
+            });
+
+            let tls = rustls::Client::builder()
+                .with_certificate(CERT_PEM)
+                .unwrap()
+                .with_application_protocols(["hq-interop"].iter())
+                .unwrap()
+                .build()
+                .unwrap();
+            let client = Client::builder()
+                .with_io("0.0.0.0:0")
+                .unwrap()
+                .with_tls(tls)
+                .unwrap()
+                .start()
+                .unwrap();
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // the first request is within the limit and is served
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream
+                .send(Bytes::from_static(b"GET /_perf/5\r\n"))
+                .await
+                .unwrap();
+            stream.finish().unwrap();
+            let mut response = BytesMut::new();
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                response.extend_from_slice(&chunk);
+            }
+            assert_eq!(response.len(), 5);
+
+            // the second request exceeds the limit and the stream is reset with the rate limit error
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            let _ = stream.send(Bytes::from_static(b"GET /_perf/5\r\n")).await;
+            let _ = stream.finish();
+            let err = loop {
+                match stream.receive().await {
+                    Ok(Some(_)) => continue,
+                    Ok(None) => panic!("the rate limited stream should be reset"),
+                    Err(err) => break err,
+                }
+            };
+            assert!(matches!(
+                err,
+                stream::Error::StreamReset { error, .. }
+                    if error == application::Error::from(REQUEST_RATE_LIMITED_ERROR)
+            ));
+
+            connection.close(0u32.into());
+        });
+    }
 }
@@ -6,10 +6,15 @@ use crate::{
     Result,
 };
 use bytes::Bytes;
-use futures::StreamExt;
-use h3::{quic::BidiStream, server::RequestStream};
+use futures::{future::poll_fn, StreamExt};
+use h3::{
+    frame::FrameStream, proto::frame::Frame, quic::BidiStream, server::RequestStream,
+    stream::BufRecvStream,
+};
 use http::StatusCode;
+use s2n_codec::{DecoderBuffer, EncoderValue};
 use s2n_quic::Connection;
+use s2n_quic_core::varint::VarInt;
 use s2n_quic_h3::h3;
 use std::{path::Path, sync::Arc, time::Duration};
 use tokio::time::timeout;
@@ -19,8 +24,8 @@ pub async fn handle_connection(connection: Connection, www_dir: Arc<Path>) {
         .await
         .unwrap();
 
-    while let Ok(Some((req, mut stream))) = conn.accept().await {
-        match req.uri().path() {
+    while let Some((path, mut stream)) = accept_request(&mut conn).await {
+        match path.as_str() {
             "" | "/" => {
                 tokio::spawn(async move {
                     let resp = http::Response::builder().status(StatusCode::OK).body(())?;
@@ -48,23 +53,80 @@ pub async fn handle_connection(connection: Connection, www_dir: Arc<Path>) {
         }
 
         let www_dir = www_dir.clone();
-        tokio::spawn(async {
-            if let Err(err) = handle_stream(req, stream, www_dir).await {
+        tokio::spawn(async move {
+            if let Err(err) = handle_stream(&path, stream, www_dir).await {
                 eprintln!("Stream error: {err:?}")
             }
         });
     }
 }
 
+type H3Connection = h3::server::Connection<s2n_quic_h3::Connection, Bytes>;
+type H3Stream = RequestStream<s2n_quic_h3::BidiStream<Bytes>, Bytes>;
+
+/// Accepts the next request on the connection along with the path it requests
+///
+/// The HEADERS frame is read before it's handed to the `h3` crate so the path can be taken from
+/// [`parse_h3_request`]. Requests that the parser doesn't support, such as ones with
+/// Huffman-encoded strings, fall back to the path decoded by the `h3` crate.
+async fn accept_request(conn: &mut H3Connection) -> Option<(String, H3Stream)> {
+    let stream = match poll_fn(|cx| conn.poll_accept_request(cx)).await {
+        Ok(Some(stream)) => stream,
+        Ok(None) => {
+            // let the client know which was the last request that was accepted
+            let _ = conn.shutdown(0).await;
+            return None;
+        }
+        Err(_) => return None,
+    };
+
+    let mut stream = FrameStream::new(BufRecvStream::new(stream));
+    let frame = poll_fn(|cx| stream.poll_next(cx)).await;
+
+    let parsed_path = if let Ok(Some(Frame::Headers(field_section))) = &frame {
+        parse_h3_request(&encode_headers_frame(field_section)).ok()
+    } else {
+        None
+    };
+
+    let (req, stream) = conn
+        .accept_with_frame(stream, frame)
+        .ok()??
+        .resolve()
+        .await
+        .ok()?;
+    let path = parsed_path.unwrap_or_else(|| req.uri().path().to_string());
+
+    Some((path, stream))
+}
+
+/// Encodes a HEADERS frame with the given field section
+fn encode_headers_frame(field_section: &[u8]) -> Vec<u8> {
+    let mut frame = VarInt::from_u8(HEADERS_FRAME_TYPE as u8).encode_to_vec();
+    // a field section that was read off of a stream always fits in a VarInt
+    frame.extend(
+        VarInt::try_from(field_section.len())
+            .unwrap()
+            .encode_to_vec(),
+    );
+    frame.extend_from_slice(field_section);
+    frame
+}
+
 async fn handle_stream<T>(
-    req: http::Request<()>,
+    path: &str,
     mut stream: RequestStream<T, Bytes>,
     www_dir: Arc<Path>,
 ) -> Result<()>
 where
     T: BidiStream<Bytes>,
 {
-    let abs_path = abs_path(req.uri().path(), &www_dir);
+    let abs_path = abs_path(path, &www_dir).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("invalid request path: {path:?}"),
+        )
+    })?;
     let mut file = File::open(&abs_path).await?;
     let resp = http::Response::builder().status(StatusCode::OK).body(())?;
 
@@ -114,3 +176,233 @@ where
 
     Ok(())
 }
+
+//= https://www.rfc-editor.org/rfc/rfc9114#section-7.2.2
+//# The HEADERS frame (type=0x01) is used to carry an HTTP field section
+//# that is encoded using QPACK.
+const HEADERS_FRAME_TYPE: u64 = 0x01;
+
+/// The number of entries in the QPACK static table
+///
+/// See https://www.rfc-editor.org/rfc/rfc9204#appendix-A
+const QPACK_STATIC_TABLE_LEN: u64 = 99;
+
+/// The index of the `:path: /` entry in the QPACK static table
+const QPACK_STATIC_PATH_INDEX: u64 = 1;
+
+/// Extracts the `:path` pseudo-header from an HTTP/3 HEADERS frame
+///
+/// Only field sections which reference the QPACK static table are supported, which is sufficient
+/// for the GET requests in the interop workload. Field sections which require the dynamic table
+/// or contain Huffman-encoded strings are rejected.
+fn parse_h3_request(frame: &[u8]) -> Result<String> {
+    let buffer = DecoderBuffer::new(frame);
+    let (frame_type, buffer) = buffer.decode::<VarInt>().map_err(<&str>::from)?;
+    if frame_type != HEADERS_FRAME_TYPE {
+        return Err(format!("expected a HEADERS frame, got frame type {frame_type}").into());
+    }
+    let (field_section, buffer) = buffer
+        .decode_slice_with_len_prefix::<VarInt>()
+        .map_err(<&str>::from)?;
+    buffer.ensure_empty().map_err(<&str>::from)?;
+
+    //= https://www.rfc-editor.org/rfc/rfc9204#section-4.5.1
+    //# Each encoded field section is prefixed with two integers.  The
+    //# Required Insert Count is encoded as an integer with an 8-bit prefix
+    //# using the encoding described in Section 4.5.1.1.  The Base is encoded
+    //# as a Sign bit ('S') and a Delta Base value with a 7-bit prefix; see
+    //# Section 4.5.1.2.
+    let (required_insert_count, buffer) = decode_prefix_int(field_section, 8)?;
+    if required_insert_count != 0 {
+        return Err("the field section requires the QPACK dynamic table".into());
+    }
+    // the base is only used to reference the dynamic table
+    let (_delta_base, mut buffer) = decode_prefix_int(buffer, 7)?;
+
+    let mut path = None;
+
+    while !buffer.is_empty() {
+        let first = buffer.peek_byte(0).map_err(<&str>::from)?;
+
+        let (value, remaining) = if first & 0b1000_0000 != 0 {
+            // Indexed Field Line
+            ensure_static_reference(first & 0b0100_0000)?;
+            let (index, remaining) = decode_static_index(buffer, 6)?;
+            let value = (index == QPACK_STATIC_PATH_INDEX).then(|| "/".to_string());
+            (value, remaining)
+        } else if first & 0b0100_0000 != 0 {
+            // Literal Field Line with Name Reference
+            ensure_static_reference(first & 0b0001_0000)?;
+            let (index, remaining) = decode_static_index(buffer, 4)?;
+            let (value, remaining) = decode_string(remaining, 7)?;
+            let value = (index == QPACK_STATIC_PATH_INDEX).then_some(value);
+            (value, remaining)
+        } else if first & 0b0010_0000 != 0 {
+            // Literal Field Line with Literal Name
+            let (name, remaining) = decode_string(buffer, 3)?;
+            let (value, remaining) = decode_string(remaining, 7)?;
+            let value = (name == ":path").then_some(value);
+            (value, remaining)
+        } else {
+            // Indexed Field Line with Post-Base Index and Literal Field Line with Post-Base Name
+            // Reference always refer to the dynamic table
+            return Err("the field section requires the QPACK dynamic table".into());
+        };
+
+        if value.is_some() {
+            path = value;
+        }
+        buffer = remaining;
+    }
+
+    path.ok_or_else(|| "the request is missing the :path pseudo-header".into())
+}
+
+/// Returns an error if the `T` bit of a field line refers to the dynamic table
+fn ensure_static_reference(static_bit: u8) -> Result<()> {
+    if static_bit == 0 {
+        return Err("the field section requires the QPACK dynamic table".into());
+    }
+    Ok(())
+}
+
+/// Decodes an index into the QPACK static table
+fn decode_static_index(buffer: DecoderBuffer, prefix: u32) -> Result<(u64, DecoderBuffer)> {
+    let (index, buffer) = decode_prefix_int(buffer, prefix)?;
+    if index >= QPACK_STATIC_TABLE_LEN {
+        return Err(format!("invalid QPACK static table index {index}").into());
+    }
+    Ok((index, buffer))
+}
+
+/// Decodes a string literal whose length has the given prefix size
+///
+/// See https://www.rfc-editor.org/rfc/rfc9204#section-4.1.2
+fn decode_string(buffer: DecoderBuffer, prefix: u32) -> Result<(String, DecoderBuffer)> {
+    let first = buffer.peek_byte(0).map_err(<&str>::from)?;
+    // the Huffman flag immediately precedes the length prefix
+    if first & (1 << prefix) != 0 {
+        return Err("Huffman-encoded strings are not supported".into());
+    }
+
+    let (len, buffer) = decode_prefix_int(buffer, prefix)?;
+    let len = usize::try_from(len).map_err(|_| "string literal is too long")?;
+    let (value, buffer) = buffer.decode_slice(len).map_err(<&str>::from)?;
+    let value = core::str::from_utf8(value.into_less_safe_slice())?;
+
+    Ok((value.to_string(), buffer))
+}
+
+/// Decodes an integer with an N-bit prefix
+///
+/// See https://www.rfc-editor.org/rfc/rfc7541#section-5.1
+fn decode_prefix_int(buffer: DecoderBuffer, prefix: u32) -> Result<(u64, DecoderBuffer)> {
+    let (first, mut buffer) = buffer.decode::<u8>().map_err(<&str>::from)?;
+    let max_prefix_value = (1u64 << prefix) - 1;
+    let mut value = first as u64 & max_prefix_value;
+
+    if value < max_prefix_value {
+        return Ok((value, buffer));
+    }
+
+    for shift in (0..63).step_by(7) {
+        let (byte, remaining) = buffer.decode::<u8>().map_err(<&str>::from)?;
+        buffer = remaining;
+        value += ((byte & 0b0111_1111) as u64) << shift;
+
+        if byte & 0b1000_0000 == 0 {
+            return Ok((value, buffer));
+        }
+    }
+
+    Err("QPACK integer overflow".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a string literal without Huffman encoding, with a 7-bit length prefix
+    fn string(value: &str) -> Vec<u8> {
+        let mut bytes = vec![value.len() as u8];
+        bytes.extend_from_slice(value.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn static_table_request_test() {
+        let mut field_section = vec![
+            // Required Insert Count = 0, Base = 0
+            0x00,
+            0x00,
+            // :method: GET (static index 17)
+            0b1100_0000 | 17,
+            // :scheme: https (static index 23)
+            0b1100_0000 | 23,
+            // :authority (static index 0) with a literal value
+            0b0101_0000,
+        ];
+        field_section.extend(string("localhost"));
+        // :path (static index 1) with a literal value
+        field_section.push(0b0101_0000 | 1);
+        field_section.extend(string("/index.html"));
+
+        let path = parse_h3_request(&encode_headers_frame(&field_section)).unwrap();
+        assert_eq!(path, "/index.html");
+    }
+
+    #[test]
+    fn static_table_path_test() {
+        // :method: GET and :path: / both come from the static table
+        let field_section = [0x00, 0x00, 0b1100_0000 | 17, 0b1100_0000 | 1];
+        let path = parse_h3_request(&encode_headers_frame(&field_section)).unwrap();
+        assert_eq!(path, "/");
+    }
+
+    #[test]
+    fn literal_name_path_test() {
+        let mut field_section = vec![0x00, 0x00, 0b1100_0000 | 17];
+        // a literal :path name, with a 3-bit length prefix
+        field_section.push(0b0010_0000 | 5);
+        field_section.extend_from_slice(b":path");
+        field_section.extend(string("/abc"));
+
+        let path = parse_h3_request(&encode_headers_frame(&field_section)).unwrap();
+        assert_eq!(path, "/abc");
+    }
+
+    #[test]
+    fn dynamic_table_test() {
+        // a non-zero Required Insert Count requires the dynamic table
+        let field_section = [0x02, 0x00, 0b1000_0000];
+        assert!(parse_h3_request(&encode_headers_frame(&field_section)).is_err());
+
+        // an indexed field line referencing the dynamic table
+        let field_section = [0x00, 0x00, 0b1000_0000 | 1];
+        assert!(parse_h3_request(&encode_headers_frame(&field_section)).is_err());
+
+        // a post-base index always refers to the dynamic table
+        let field_section = [0x00, 0x00, 0b0001_0000];
+        assert!(parse_h3_request(&encode_headers_frame(&field_section)).is_err());
+    }
+
+    #[test]
+    fn invalid_request_test() {
+        // missing :path
+        let field_section = [0x00, 0x00, 0b1100_0000 | 17];
+        assert!(parse_h3_request(&encode_headers_frame(&field_section)).is_err());
+
+        // Huffman-encoded :path value
+        let field_section = [0x00, 0x00, 0b0101_0000 | 1, 0b1000_0001, 0x63];
+        assert!(parse_h3_request(&encode_headers_frame(&field_section)).is_err());
+
+        // a DATA frame instead of a HEADERS frame
+        let mut frame = encode_headers_frame(&[0x00, 0x00, 0b1100_0000 | 1]);
+        frame[0] = 0x00;
+        assert!(parse_h3_request(&frame).is_err());
+
+        // a truncated frame
+        let frame = encode_headers_frame(&[0x00, 0x00, 0b1100_0000 | 1]);
+        assert!(parse_h3_request(&frame[..frame.len() - 1]).is_err());
+    }
+}
@@ -3,14 +3,14 @@
 
 use crate::{
     intercept::Intercept,
-    interop::Testcase,
+    interop::{Testcase, UnsupportedTestcase},
     server,
-    server::{h09, h3},
+    server::{h09, h3, rate_limit::RequestRateLimiter},
     tls, Result,
 };
 use s2n_quic::{
     provider::{
-        endpoint_limits,
+        connection_id, endpoint_limits,
         event::{events, Subscriber},
     },
     Server,
@@ -18,10 +18,14 @@ use s2n_quic::{
 use std::{
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 use structopt::StructOpt;
 use tokio::spawn;
 
+/// The MAX_STREAMS limit advertised in the `StreamLimits` testcase when `--max-streams` isn't set
+const STREAM_LIMITS_MAX_STREAMS: u64 = 2;
+
 #[derive(Debug, StructOpt)]
 pub struct Interop {
     #[structopt(long, default_value = "hq-interop")]
@@ -30,9 +34,32 @@ pub struct Interop {
     #[structopt(long, default_value = ".")]
     www_dir: PathBuf,
 
-    #[structopt(long, env = "TESTCASE", possible_values = &Testcase::supported(is_supported_testcase))]
+    /// Unsupported testcases exit with code 127
+    #[structopt(long, env = "TESTCASE")]
     testcase: Option<Testcase>,
 
+    /// The maximum size of each chunk when streaming generated responses
+    #[structopt(long)]
+    chunk_size: Option<usize>,
+
+    /// The number of request chunks to receive before yielding to other tasks
+    #[structopt(long, default_value = "16")]
+    read_yield_interval: usize,
+
+    /// Periodically logs the number of requests served, in seconds
+    #[structopt(long)]
+    stats_interval: Option<u64>,
+
+    /// The maximum number of requests per second accepted from each client address
+    ///
+    /// Requests above the rate are rejected by resetting the stream. Only applies to hq-interop.
+    #[structopt(long)]
+    max_requests_per_sec: Option<u64>,
+
+    /// The length of the connection IDs issued by the server, in bytes
+    #[structopt(long, parse(try_from_str = parse_local_cid_len))]
+    local_cid_len: Option<usize>,
+
     #[structopt(flatten)]
     limits: crate::limits::Limits,
 
@@ -54,6 +81,10 @@ pub struct Interop {
 
 impl Interop {
     pub fn run(&self) -> Result<()> {
+        if let Some(testcase) = self.testcase.filter(|t| !is_supported_testcase(*t)) {
+            return Err(UnsupportedTestcase(testcase).into());
+        }
+
         self.runtime.build()?.block_on(self.task())
     }
 
@@ -61,6 +92,21 @@ impl Interop {
         let mut server = self.server()?;
 
         let www_dir: Arc<Path> = Arc::from(self.www_dir.as_path());
+        let chunk_size = self.chunk_size.unwrap_or(usize::MAX);
+        let request_reader = h09::RequestReader::new(self.read_yield_interval);
+        let rate_limiter = RequestRateLimiter::new(self.max_requests_per_sec);
+
+        if let Some(interval) = self.stats_interval {
+            let request_reader = request_reader.clone();
+            spawn(async move {
+                // an interval of 0 would log in a busy loop
+                let interval = Duration::from_secs(interval.max(1));
+                loop {
+                    tokio::time::sleep(interval).await;
+                    eprintln!("Requests served: {}", request_reader.request_count());
+                }
+            });
+        }
 
         while let Some(connection) = server.accept().await {
             let unspecified: std::net::SocketAddr = ([0, 0, 0, 0], 0).into();
@@ -73,7 +119,13 @@ impl Interop {
             // spawn a task per connection
             match &(connection.application_protocol()?)[..] {
                 b"h3" => spawn(h3::handle_connection(connection, www_dir.clone())),
-                b"hq-interop" => spawn(h09::handle_connection(connection, www_dir.clone())),
+                b"hq-interop" => spawn(h09::handle_connection(
+                    connection,
+                    www_dir.clone(),
+                    chunk_size,
+                    request_reader.clone(),
+                    rate_limiter.clone(),
+                )),
                 _ => spawn(async move {
                     eprintln!(
                         "Unsupported application protocol: {:?}",
@@ -96,12 +148,28 @@ impl Interop {
             .with_inflight_handshake_limit(max_handshakes)?
             .build()?;
 
-        let limits = self.limits.limits();
+        let mut limits = self.limits.limits();
+
+        // advertise a small limit so the client has to wait for MAX_STREAMS updates
+        if matches!(self.testcase, Some(Testcase::StreamLimits))
+            && self.limits.max_streams.is_none()
+        {
+            limits = limits
+                .with_max_open_remote_bidirectional_streams(STREAM_LIMITS_MAX_STREAMS)
+                .map_err(|err| err.to_string())?;
+        }
 
         let io = self.io.build()?;
 
+        let mut connection_id = connection_id::default::Format::builder();
+        if let Some(len) = self.local_cid_len {
+            connection_id = connection_id.with_len(len).map_err(|err| err.to_string())?;
+        }
+        let connection_id = connection_id.build()?;
+
         let server = Server::builder()
             .with_io(io)?
+            .with_connection_id(connection_id)?
             .with_endpoint_limits(endpoint_limits)?
             .with_limits(limits)?
             .with_event((
@@ -135,14 +203,28 @@ fn is_supported_testcase(testcase: Testcase) -> bool {
         ChaCha20 => true,
         // KeyUpdate is client only
         KeyUpdate => false,
+        ChaCha20KeyUpdate => false,
         Retry => true,
         Resumption => true,
         // TODO implement 0rtt
         ZeroRtt => false,
+        ZeroRttReject => false,
         Http3 => true,
         Multiconnect => true,
         Ecn => true,
         ConnectionMigration => true,
+        // s2n-quic doesn't issue zero-length connection IDs
+        ZeroLengthCid => false,
+        ConnectionClose => true,
+        LargeCertChain => true,
+        // TODO generate stateless reset tokens that persist across server restarts
+        StatelessReset => false,
+        PathMtu => true,
+        StreamLimits => true,
+        // unknown transport parameters from the peer are ignored while decoding
+        Grease => true,
+        // reordering is handled by the network simulator
+        Reorder => true,
     }
 }
 
@@ -152,6 +234,27 @@ pub struct MyConnectionContext {
     pub(crate) stream_requests: u64,
 }
 
+/// Parses the length of the connection IDs the server issues
+///
+/// Load balancers encode routing information in the connection ID, so the length must be one
+/// the connection ID generator is able to produce.
+fn parse_local_cid_len(len: &str) -> Result<usize> {
+    use s2n_quic_core::connection::{id::MAX_LEN, LocalId};
+
+    let len = len.parse()?;
+
+    // TODO allow zero-length connection IDs once they are supported by the generator
+    if !(LocalId::MIN_LEN..=MAX_LEN).contains(&len) {
+        return Err(format!(
+            "connection ID length must be between {} and {MAX_LEN} bytes, got {len}",
+            LocalId::MIN_LEN
+        )
+        .into());
+    }
+
+    Ok(len)
+}
+
 pub struct EventSubscriber;
 
 impl Subscriber for EventSubscriber {
@@ -177,3 +280,43 @@ impl Subscriber for EventSubscriber {
         context.packet_sent += 1;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The testcases this endpoint has an implementation for
+    const IMPLEMENTED_TESTCASES: &[Testcase] = &[
+        Testcase::VersionNegotiation,
+        Testcase::Handshake,
+        Testcase::Transfer,
+        Testcase::ChaCha20,
+        Testcase::Retry,
+        Testcase::Resumption,
+        Testcase::Http3,
+        Testcase::Multiconnect,
+        Testcase::Ecn,
+        Testcase::ConnectionMigration,
+        Testcase::ConnectionClose,
+        Testcase::LargeCertChain,
+        Testcase::PathMtu,
+        Testcase::StreamLimits,
+        Testcase::Grease,
+        Testcase::Reorder,
+    ];
+
+    #[test]
+    fn supported_testcases_are_implemented() {
+        Testcase::assert_supported_implemented(is_supported_testcase, IMPLEMENTED_TESTCASES);
+    }
+
+    #[test]
+    fn local_cid_len_test() {
+        assert_eq!(parse_local_cid_len("4").unwrap(), 4);
+        assert_eq!(parse_local_cid_len("20").unwrap(), 20);
+
+        for len in ["0", "3", "21", "-1", "len"] {
+            assert!(parse_local_cid_len(len).is_err(), "{len}");
+        }
+    }
+}
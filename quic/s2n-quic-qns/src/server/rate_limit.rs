@@ -0,0 +1,215 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use lru::LruCache;
+use s2n_quic_core::{
+    inet::{IpAddress, SocketAddress},
+    time::{token_bucket::TokenBucket, Clock, StdClock, Timestamp},
+};
+use std::{
+    net::SocketAddr,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The application error code used to reset streams which exceed the request rate
+pub(crate) const REQUEST_RATE_LIMITED_ERROR: u32 = 2;
+
+/// The maximum number of client addresses to track at once
+///
+/// The least recently seen address is evicted when the limit is reached, which resets its budget.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// The maximum number of times per second a client's budget is refilled
+///
+/// Higher rates refill several requests at a time. This bounds the number of refills the token
+/// bucket steps through while holding the lock, and keeps the refill interval from shrinking
+/// towards zero.
+const MAX_REFILLS_PER_SEC: u64 = 1_000;
+
+/// Limits the rate at which each client address can issue requests
+///
+/// Clones share the same state so a client is limited across all of its connections.
+#[derive(Clone)]
+pub(crate) struct RequestRateLimiter {
+    inner: Option<Arc<Mutex<State>>>,
+}
+
+struct State {
+    max_requests_per_sec: u64,
+    clients: LruCache<IpAddress, TokenBucket>,
+    clock: StdClock,
+}
+
+impl RequestRateLimiter {
+    /// Creates a limiter which allows `max_requests_per_sec` requests per second from each client
+    ///
+    /// `None` disables the limiter.
+    pub(crate) fn new(max_requests_per_sec: Option<u64>) -> Self {
+        let inner = max_requests_per_sec.map(|max_requests_per_sec| {
+            Arc::new(Mutex::new(State {
+                // a rate of 0 would reject every request
+                max_requests_per_sec: max_requests_per_sec.max(1),
+                clients: LruCache::new(NonZeroUsize::new(MAX_TRACKED_CLIENTS).unwrap()),
+                clock: StdClock::default(),
+            }))
+        });
+
+        Self { inner }
+    }
+
+    /// Returns `true` if a new request from `remote_addr` is allowed
+    pub(crate) fn on_request(&self, remote_addr: SocketAddr) -> bool {
+        if let Some(inner) = self.inner.as_ref() {
+            let mut state = inner.lock().unwrap();
+            let now = state.clock.get_time();
+            state.on_request(remote_addr, now)
+        } else {
+            true
+        }
+    }
+
+    #[cfg(test)]
+    fn on_request_at(&self, remote_addr: SocketAddr, now: Timestamp) -> bool {
+        if let Some(inner) = self.inner.as_ref() {
+            inner.lock().unwrap().on_request(remote_addr, now)
+        } else {
+            true
+        }
+    }
+}
+
+impl State {
+    fn on_request(&mut self, remote_addr: SocketAddr, now: Timestamp) -> bool {
+        let max_requests_per_sec = self.max_requests_per_sec;
+
+        self.clients
+            .get_or_insert_mut(ip_key(remote_addr), || {
+                let (refill_amount, refill_interval) = refill_rate(max_requests_per_sec);
+
+                // allow a full second of requests up front
+                TokenBucket::builder()
+                    .with_max(max_requests_per_sec)
+                    .with_refill_amount(refill_amount)
+                    .with_refill_interval(refill_interval)
+                    .build()
+            })
+            .try_acquire(1, now)
+    }
+}
+
+/// Returns the number of requests to refill at a time and the interval between refills
+///
+/// Together they refill exactly `max_requests_per_sec` requests per second while refilling at most
+/// [`MAX_REFILLS_PER_SEC`] times per second.
+fn refill_rate(max_requests_per_sec: u64) -> (u64, Duration) {
+    let refill_amount = max_requests_per_sec / MAX_REFILLS_PER_SEC
+        + (max_requests_per_sec % MAX_REFILLS_PER_SEC != 0) as u64;
+    let refill_interval_nanos =
+        refill_amount as u128 * 1_000_000_000 / max_requests_per_sec as u128;
+
+    (
+        refill_amount,
+        Duration::from_nanos(refill_interval_nanos as u64),
+    )
+}
+
+/// Returns the key used to track a client
+///
+/// The port is ignored so a client can't bypass the limit by opening connections from different
+/// ports, and IPv4-mapped addresses share the key of the IPv4 address.
+fn ip_key(remote_addr: SocketAddr) -> IpAddress {
+    SocketAddress::from(remote_addr).canonical().ip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::time::NoopClock;
+
+    #[test]
+    fn rate_limit_test() {
+        let limiter = RequestRateLimiter::new(Some(10));
+        let abusive: SocketAddr = "192.168.1.1:443".parse().unwrap();
+        let compliant: SocketAddr = "192.168.1.2:443".parse().unwrap();
+        let mut now = NoopClock.get_time();
+
+        // the abusive client sends its full budget at once and is throttled after that
+        for _ in 0..10 {
+            assert!(limiter.on_request_at(abusive, now));
+        }
+        assert!(!limiter.on_request_at(abusive, now));
+        // switching ports or mapping the address to IPv6 doesn't reset the budget
+        assert!(!limiter.on_request_at("192.168.1.1:1234".parse().unwrap(), now));
+        assert!(!limiter.on_request_at("[::ffff:192.168.1.1]:443".parse().unwrap(), now));
+
+        // the compliant client stays within the rate and is never throttled
+        for _ in 0..100 {
+            assert!(limiter.on_request_at(compliant, now));
+            now += Duration::from_millis(100);
+        }
+
+        // the abusive client regains its budget over time
+        assert!(limiter.on_request_at(abusive, now));
+    }
+
+    #[test]
+    fn high_rate_test() {
+        let rate = 5_000;
+        let limiter = RequestRateLimiter::new(Some(rate));
+        let addr: SocketAddr = "192.168.1.1:443".parse().unwrap();
+        let mut now = NoopClock.get_time();
+
+        // the full budget of a rate above the refill frequency is available up front
+        for _ in 0..rate {
+            assert!(limiter.on_request_at(addr, now));
+        }
+        assert!(!limiter.on_request_at(addr, now));
+
+        // each refill restores several requests at once
+        now += Duration::from_secs(1) / MAX_REFILLS_PER_SEC as u32;
+        for _ in 0..rate / MAX_REFILLS_PER_SEC {
+            assert!(limiter.on_request_at(addr, now));
+        }
+        assert!(!limiter.on_request_at(addr, now));
+    }
+
+    #[test]
+    fn refill_rate_test() {
+        for rate in [
+            1,
+            3,
+            999,
+            1_000,
+            1_001,
+            1_500,
+            5_000,
+            123_456,
+            u32::MAX as u64,
+            u64::MAX,
+        ] {
+            let (amount, interval) = refill_rate(rate);
+            assert!(amount >= 1);
+
+            // refills never happen more often than the maximum frequency
+            assert!(interval >= Duration::from_secs(1) / MAX_REFILLS_PER_SEC as u32);
+
+            // and add up to the configured rate, within the precision of the interval
+            let refilled = amount as f64 * 1e9 / interval.as_nanos() as f64;
+            assert!(
+                (refilled - rate as f64).abs() / rate as f64 <= 1e-6,
+                "{rate}"
+            );
+        }
+    }
+
+    #[test]
+    fn disabled_test() {
+        let limiter = RequestRateLimiter::new(None);
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        for _ in 0..1000 {
+            assert!(limiter.on_request(addr));
+        }
+    }
+}
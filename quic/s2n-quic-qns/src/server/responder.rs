@@ -0,0 +1,179 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Result;
+use bytes::Bytes;
+use futures::future::poll_fn;
+use s2n_quic::stream::SendStream;
+use std::task::{Context, Poll};
+
+/// A destination for response chunks
+pub(crate) trait ResponseSink {
+    /// Polls for the number of bytes the sink can currently accept
+    fn poll_send_ready(&mut self, cx: &mut Context) -> Poll<Result<usize>>;
+
+    /// Enqueues a chunk which fits in the capacity returned by `poll_send_ready`
+    fn send_data(&mut self, chunk: Bytes) -> Result<()>;
+
+    /// Marks the end of the response
+    fn finish(&mut self) -> Result<()>;
+}
+
+impl ResponseSink for SendStream {
+    fn poll_send_ready(&mut self, cx: &mut Context) -> Poll<Result<usize>> {
+        SendStream::poll_send_ready(self, cx).map_err(Into::into)
+    }
+
+    fn send_data(&mut self, chunk: Bytes) -> Result<()> {
+        SendStream::send_data(self, chunk)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        SendStream::finish(self)?;
+        Ok(())
+    }
+}
+
+/// Writes a response body while cooperating with the stream's flow control
+///
+/// Chunks are only enqueued when the stream has capacity for them, and are split to fit the
+/// capacity that is available. Once the send buffer is full, the task waits for the peer to raise
+/// the limit with `MAX_STREAM_DATA` rather than polling the stream again.
+pub(crate) struct ChunkedResponder<'a, S: ResponseSink> {
+    stream: &'a mut S,
+}
+
+impl<'a, S: ResponseSink> ChunkedResponder<'a, S> {
+    pub fn new(stream: &'a mut S) -> Self {
+        Self { stream }
+    }
+
+    /// Writes the entire chunk, waiting for capacity as needed
+    pub async fn write(&mut self, mut chunk: Bytes) -> Result<()> {
+        while !chunk.is_empty() {
+            let capacity = poll_fn(|cx| self.stream.poll_send_ready(cx)).await?;
+            debug_assert_ne!(
+                capacity, 0,
+                "the sink should be pending until it has capacity"
+            );
+            let len = capacity.min(chunk.len());
+            self.stream.send_data(chunk.split_to(len))?;
+        }
+
+        Ok(())
+    }
+
+    /// Finishes the response once the body has been written
+    pub fn finish(self) -> Result<()> {
+        self.stream.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        task::Waker,
+    };
+
+    const WINDOW: usize = 1000;
+
+    #[derive(Default)]
+    struct Window {
+        credit: usize,
+        received: Vec<u8>,
+        is_finished: bool,
+        waker: Option<Waker>,
+        polls: usize,
+    }
+
+    /// A sink that only accepts data when the receiver has granted it credit
+    #[derive(Clone, Default)]
+    struct FlowLimitedSink(Arc<Mutex<Window>>);
+
+    impl ResponseSink for FlowLimitedSink {
+        fn poll_send_ready(&mut self, cx: &mut Context) -> Poll<Result<usize>> {
+            let mut window = self.0.lock().unwrap();
+            window.polls += 1;
+            if window.credit == 0 {
+                window.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            Poll::Ready(Ok(window.credit))
+        }
+
+        fn send_data(&mut self, chunk: Bytes) -> Result<()> {
+            let mut window = self.0.lock().unwrap();
+            assert!(chunk.len() <= window.credit, "flow control was exceeded");
+            window.credit -= chunk.len();
+            window.received.extend_from_slice(&chunk);
+            Ok(())
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            self.0.lock().unwrap().is_finished = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn flow_control_test() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let body: Vec<u8> = (0..10_000).map(|v| v as u8).collect();
+            let sink = FlowLimitedSink::default();
+
+            // grant another window of credit each time the responder runs out
+            let receiver = tokio::spawn({
+                let sink = sink.clone();
+                async move {
+                    let mut grants = 0;
+                    loop {
+                        tokio::task::yield_now().await;
+                        let mut window = sink.0.lock().unwrap();
+                        if window.is_finished {
+                            return grants;
+                        }
+                        if let Some(waker) = window.waker.take() {
+                            window.credit += WINDOW;
+                            grants += 1;
+                            waker.wake();
+                        }
+                    }
+                }
+            });
+
+            let mut stream = sink.clone();
+            let mut responder = ChunkedResponder::new(&mut stream);
+            // the chunks are larger than the window so they need to be split
+            let chunks = body.chunks(4096);
+            let chunk_count = chunks.len();
+            for chunk in chunks {
+                responder
+                    .write(Bytes::copy_from_slice(chunk))
+                    .await
+                    .unwrap();
+            }
+            responder.finish().unwrap();
+
+            let grants = receiver.await.unwrap();
+            let window = sink.0.lock().unwrap();
+            assert!(window.is_finished);
+            assert_eq!(window.received, body);
+            assert_eq!(grants, body.len() / WINDOW);
+
+            // the stream is polled once when blocked and once after each grant, plus once for
+            // each chunk that starts with credit left over from the previous one
+            assert!(
+                window.polls <= grants * 2 + chunk_count,
+                "{} polls for {grants} grants",
+                window.polls
+            );
+        });
+    }
+}
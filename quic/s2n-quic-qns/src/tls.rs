@@ -2,7 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::Result;
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -91,12 +96,25 @@ pub struct Client {
     /// disable verification of the server certificate (rustls only)
     #[structopt(long)]
     pub disable_cert_verification: bool,
+
+    /// The session tickets received by the client, shared by all of its connections
+    #[structopt(skip)]
+    pub session_tickets: InMemorySessionTicketStore,
 }
 
 impl Client {
+    /// Returns the store that session tickets are persisted in, if the TLS provider uses one
+    pub fn session_ticket_store(&self) -> Option<InMemorySessionTicketStore> {
+        match self.tls {
+            #[cfg(unix)]
+            TlsProviders::S2N => Some(self.session_tickets.clone()),
+            TlsProviders::Rustls | TlsProviders::Null => None,
+        }
+    }
+
     #[cfg(unix)]
     pub fn build_s2n_tls(&self, alpns: &[String]) -> Result<s2n_tls::Client> {
-        let handler = s2n_tls::SessionTicketHandler::default();
+        let handler = s2n_tls::SessionTicketHandler::new(self.session_tickets.clone());
         let mut tls = s2n_tls::Client::builder()
             .with_certificate(s2n_tls::ca(self.ca.as_ref())?)?
             // the "amplificationlimit" tests generates a very large chain so bump the limit
@@ -142,6 +160,53 @@ impl Client {
     }
 }
 
+/// Persists the session tickets issued by a server so they can be presented to resume a
+/// later connection
+pub trait SessionTicketStore: 'static + Send + Sync {
+    /// Stores a ticket received from the server
+    fn insert(&self, ticket: Vec<u8>);
+
+    /// Removes the oldest stored ticket so it can be presented on a new connection
+    fn take(&self) -> Option<Vec<u8>>;
+
+    /// Returns the number of tickets currently stored
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InMemorySessionTicketStore {
+    tickets: Arc<Mutex<VecDeque<Vec<u8>>>>,
+}
+
+impl InMemorySessionTicketStore {
+    /// The maximum number of tickets to hold on to before discarding the oldest
+    const MAX_TICKETS: usize = 10;
+}
+
+impl SessionTicketStore for InMemorySessionTicketStore {
+    fn insert(&self, ticket: Vec<u8>) {
+        let mut tickets = self.tickets.lock().unwrap();
+        tickets.push_back(ticket);
+
+        // discard any excessive tickets
+        while tickets.len() > Self::MAX_TICKETS {
+            let _ = tickets.pop_front();
+        }
+    }
+
+    fn take(&self) -> Option<Vec<u8>> {
+        self.tickets.lock().unwrap().pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.tickets.lock().unwrap().len()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum TlsProviders {
     /// Use s2n-tls as the tls provider
@@ -204,11 +269,7 @@ pub mod s2n_tls {
         error::Error,
         Client, Server,
     };
-    use std::{
-        collections::VecDeque,
-        pin::Pin,
-        sync::{Arc, Mutex},
-    };
+    use std::pin::Pin;
 
     pub fn ca(ca: Option<&PathBuf>) -> Result<Certificate> {
         Ok(if let Some(pathbuf) = ca.as_ref() {
@@ -226,37 +287,34 @@ pub mod s2n_tls {
         })
     }
 
+    /// Hands session tickets between s2n-tls and a [`SessionTicketStore`]
     #[derive(Clone, Default)]
-    pub struct SessionTicketHandler {
-        ticket_storage: Arc<Mutex<VecDeque<Vec<u8>>>>,
+    pub struct SessionTicketHandler<S = InMemorySessionTicketStore> {
+        store: S,
+    }
+
+    impl<S: SessionTicketStore> SessionTicketHandler<S> {
+        pub fn new(store: S) -> Self {
+            Self { store }
+        }
     }
 
-    impl SessionTicketCallback for SessionTicketHandler {
+    impl<S: SessionTicketStore> SessionTicketCallback for SessionTicketHandler<S> {
         fn on_session_ticket(&self, _connection: &mut Connection, session_ticket: &SessionTicket) {
             let size = session_ticket.len().unwrap();
             let mut data = vec![0; size];
             session_ticket.data(&mut data).unwrap();
-            let mut vec = (*self.ticket_storage).lock().unwrap();
-            vec.push_back(data);
-
-            // discard any excessive tickets
-            while vec.len() > 10 {
-                let _ = vec.pop_front();
-            }
+            self.store.insert(data);
         }
     }
-    impl ConnectionInitializer for SessionTicketHandler {
+
+    impl<S: SessionTicketStore> ConnectionInitializer for SessionTicketHandler<S> {
         fn initialize_connection(
             &self,
             connection: &mut Connection,
         ) -> Result<Option<Pin<Box<(dyn ConnectionFuture)>>>, Error> {
-            if let Some(ticket) = (*self.ticket_storage)
-                .lock()
-                .unwrap()
-                .pop_front()
-                .as_deref()
-            {
-                connection.set_session_ticket(ticket)?;
+            if let Some(ticket) = self.store.take() {
+                connection.set_session_ticket(&ticket)?;
             }
             Ok(None)
         }
@@ -324,3 +382,40 @@ pub mod null {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_ticket_store_test() {
+        let store = InMemorySessionTicketStore::default();
+        assert!(store.is_empty());
+        assert_eq!(store.take(), None);
+
+        // clones share the same storage so tickets received on one connection are visible to the
+        // next connection
+        store.clone().insert(vec![1]);
+        store.insert(vec![2]);
+        assert_eq!(store.len(), 2);
+
+        // tickets are presented in the order they were received
+        assert_eq!(store.take(), Some(vec![1]));
+        assert_eq!(store.take(), Some(vec![2]));
+        assert_eq!(store.take(), None);
+    }
+
+    #[test]
+    fn session_ticket_store_limit_test() {
+        let store = InMemorySessionTicketStore::default();
+        let max = InMemorySessionTicketStore::MAX_TICKETS as u8;
+
+        for ticket in 0..max + 5 {
+            store.insert(vec![ticket]);
+        }
+
+        // the oldest tickets are discarded first
+        assert_eq!(store.len(), max as usize);
+        assert_eq!(store.take(), Some(vec![5]));
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Result;
+use s2n_quic::provider::event::{events, Subscriber};
+use std::{
+    fs::File,
+    io::{LineWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+const HEADER: &str =
+    "connection_id,path_id,congestion_window,bytes_in_flight,send_quantum,bytes_written";
+
+/// Writes the transmission budget of every 1-RTT packet to a CSV file
+///
+/// This is used to debug why a transfer isn't making use of the congestion window.
+#[derive(Clone, Default)]
+pub struct TxTrace {
+    output: Option<Arc<Mutex<LineWriter<File>>>>,
+}
+
+impl TxTrace {
+    /// Creates a trace writing to `path`, or a trace that discards all events if it is `None`
+    pub fn new(path: Option<&Path>) -> Result<Self> {
+        let output = if let Some(path) = path {
+            let mut output = LineWriter::new(File::create(path)?);
+            writeln!(output, "{HEADER}")?;
+            Some(Arc::new(Mutex::new(output)))
+        } else {
+            None
+        };
+
+        Ok(Self { output })
+    }
+}
+
+/// Formats the event as a CSV row matching [`HEADER`]
+fn format_row(connection_id: u64, event: &events::TransmissionBudget) -> String {
+    let send_quantum = event
+        .send_quantum
+        .map(|send_quantum| send_quantum.to_string())
+        .unwrap_or_default();
+
+    format!(
+        "{connection_id},{},{},{},{send_quantum},{}",
+        event.path_id, event.congestion_window, event.bytes_in_flight, event.bytes_written
+    )
+}
+
+impl Subscriber for TxTrace {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_transmission_budget(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &events::ConnectionMeta,
+        event: &events::TransmissionBudget,
+    ) {
+        if let Some(output) = self.output.as_ref() {
+            let row = format_row(meta.id, event);
+            // tracing is best-effort so don't fail the connection if the write fails
+            let _ = writeln!(output.lock().unwrap(), "{row}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::event::{builder, IntoEvent};
+
+    #[test]
+    fn format_row_test() {
+        let event: events::TransmissionBudget = builder::TransmissionBudget {
+            path_id: 0,
+            congestion_window: 12000,
+            bytes_in_flight: 2400,
+            send_quantum: None,
+            bytes_written: 1200,
+        }
+        .into_event();
+        assert_eq!(format_row(3, &event), "3,0,12000,2400,,1200");
+
+        let event: events::TransmissionBudget = builder::TransmissionBudget {
+            path_id: 1,
+            congestion_window: 12000,
+            bytes_in_flight: 0,
+            send_quantum: Some(2400),
+            bytes_written: 1200,
+        }
+        .into_event();
+        assert_eq!(format_row(3, &event), "3,1,12000,0,2400,1200");
+
+        assert_eq!(HEADER.split(',').count(), 6);
+    }
+}
@@ -71,6 +71,8 @@ impl AckElicitingTransmissionSet {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
 pub struct AckElicitingTransmission {
     pub sent_in_packet: PacketNumber,
+    /// The start of the oldest range in the sent ACK frame, or 0 if no older ranges were omitted
+    pub smallest_received_packet_number_acked: PacketNumber,
     pub largest_received_packet_number_acked: PacketNumber,
 }
 
@@ -82,13 +84,11 @@ impl AckElicitingTransmission {
         //# acknowledging packets less than or equal to the Largest Acknowledged
         //# field in the sent ACK frame.
         if ack_set.contains(self.sent_in_packet) {
-            // create a range from 0..=largest
-            let pn_zero = self
-                .largest_received_packet_number_acked
-                .space()
-                .new_packet_number(Default::default());
-
-            Some(pn_zero..=self.largest_received_packet_number_acked)
+            // ranges older than the ones in the frame were omitted and still need to be sent
+            Some(
+                self.smallest_received_packet_number_acked
+                    ..=self.largest_received_packet_number_acked,
+            )
         } else {
             None
         }
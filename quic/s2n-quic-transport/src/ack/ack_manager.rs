@@ -3,13 +3,16 @@
 
 use crate::{
     ack::{
-        ack_eliciting_transmission::{AckElicitingTransmission, AckElicitingTransmissionSet},
+        ack_eliciting_transmission::{
+            AckElicitingTransmission, AckElicitingTransmissionSet, AckRange,
+        },
         ack_transmission_state::AckTransmissionState,
     },
     contexts::WriteContext,
     processed_packet::ProcessedPacket,
     transmission,
 };
+use s2n_codec::EncoderValue;
 use s2n_quic_core::{
     ack,
     counter::{Counter, Saturating},
@@ -18,7 +21,10 @@ use s2n_quic_core::{
         builder::{AckAction, AckProcessed},
         IntoEvent as _,
     },
-    frame::{ack::EcnCounts, Ack, Ping},
+    frame::{
+        ack::{AckRanges, EcnCounts},
+        Ack, Ping,
+    },
     packet::number::{PacketNumber, PacketNumberSpace},
     time::{timer, Timer, Timestamp},
     varint::VarInt,
@@ -71,6 +77,13 @@ pub struct AckManager {
 
     /// Explicit Congestion Notification counts from processed packets
     ecn_counts: EcnCounts,
+
+    /// The packet numbers covered by the last ACK frame written in `on_transmit`
+    transmitted_ack_range: Option<AckRange>,
+
+    /// Whether the next ACK frame that can't fit every range reports the oldest ranges
+    /// rather than the most recent ones
+    send_oldest_ack_ranges: bool,
 }
 
 impl AckManager {
@@ -87,6 +100,8 @@ impl AckManager {
             transmissions_since_elicitation: Counter::new(0),
             transmission_state: AckTransmissionState::default(),
             ecn_counts: EcnCounts::default(),
+            transmitted_ack_range: None,
+            send_oldest_ack_ranges: false,
         }
     }
 
@@ -109,13 +124,83 @@ impl AckManager {
         //# Even if an endpoint does not set an ECT field on packets it sends,
         //# the endpoint MUST provide feedback about ECN markings it receives, if
         //# these are accessible.
-        context
-            .write_ack_frame(&Ack {
-                ack_delay,
-                ack_ranges: &self.ack_ranges,
-                ecn_counts: self.ecn_counts.as_option(),
-            })
-            .is_some()
+        let range_count = self.ack_ranges.interval_len();
+        let mut frame = Ack {
+            ack_delay,
+            ack_ranges: AckRangesWindow {
+                ranges: &self.ack_ranges,
+                skip: 0,
+                len: range_count,
+            },
+            ecn_counts: self.ecn_counts.as_option(),
+        };
+
+        let capacity = context.remaining_capacity();
+        let is_truncated = frame.encoding_size() > capacity;
+        if is_truncated {
+            frame.ack_ranges =
+                Self::fitted_window(frame.clone(), capacity, self.send_oldest_ack_ranges);
+        }
+
+        let ack_range = frame.ack_ranges.ack_range();
+        if context.write_ack_frame(&frame).is_none() {
+            return false;
+        }
+
+        self.transmitted_ack_range = Some(ack_range);
+        if is_truncated {
+            self.send_oldest_ack_ranges = !self.send_oldest_ack_ranges;
+        }
+
+        true
+    }
+
+    /// Returns the largest window of ranges that can be written in `capacity` bytes
+    ///
+    /// If not even a single range fits, a window of 1 range is returned and the write is left
+    /// to fail.
+    fn fitted_window(
+        mut frame: Ack<AckRangesWindow>,
+        capacity: usize,
+        oldest: bool,
+    ) -> AckRangesWindow {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.3
+        //# ACK frames SHOULD always acknowledge the most recently received
+        //# packets, and the more out of order the packets are, the more
+        //# important it is to send an updated ACK frame quickly, to prevent the
+        //# peer from declaring a packet as lost and spuriously retransmitting
+        //# the frames it contains.
+        //
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.3
+        //# A receiver SHOULD include an ACK Range containing the largest
+        //# received packet number in every ACK frame.
+        //
+        // The ranges are ordered from the largest packet number to the smallest. Frames which
+        // can't fit every range alternate between the most recent ranges and the oldest ones,
+        // so the omitted ranges are reported in a later frame instead of being dropped. Once a
+        // packet carrying a frame is acknowledged, only the ranges in that frame are removed
+        // from `ack_ranges`.
+        let range_count = frame.ack_ranges.len;
+        let mut window = |len: usize| {
+            frame.ack_ranges.len = len;
+            frame.ack_ranges.skip = if oldest { range_count - len } else { 0 };
+            frame.encoding_size()
+        };
+
+        // the encoding size grows with the number of ranges so search for the largest that fits
+        let mut low = 1;
+        let mut high = range_count;
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if window(mid) <= capacity {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        window(low);
+        frame.ack_ranges
     }
 
     /// Called after an outgoing packet is assembled and `on_transmit` returned `true`
@@ -162,10 +247,19 @@ impl AckManager {
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.4
             //# When a packet containing an ACK frame is sent, the Largest
             //# Acknowledged field in that frame can be saved.
+            let ack_range = self.transmitted_ack_range.take().unwrap_or_else(|| {
+                let pn_zero = self
+                    .largest_received_packet_number_acked
+                    .space()
+                    .new_packet_number(Default::default());
+                pn_zero..=self.largest_received_packet_number_acked
+            });
+
             self.ack_eliciting_transmissions
                 .on_transmit(AckElicitingTransmission {
                     sent_in_packet: context.packet_number(),
-                    largest_received_packet_number_acked: self.largest_received_packet_number_acked,
+                    smallest_received_packet_number_acked: *ack_range.start(),
+                    largest_received_packet_number_acked: *ack_range.end(),
                 });
         }
 
@@ -180,9 +274,10 @@ impl AckManager {
     /// Called when a set of packets was acknowledged
     pub fn on_packet_ack<A: ack::Set>(&mut self, _timestamp: Timestamp, ack_set: &A) {
         if let Some(ack_range) = self.ack_eliciting_transmissions.on_update(ack_set) {
-            self.ack_ranges
-                .remove(ack_range)
-                .expect("The range should always shrink the interval length");
+            // Removing the ranges of a frame which omitted the oldest ranges can split a range
+            // which has since grown past both ends of the frame. If the split doesn't fit in the
+            // range limit, the packets are acknowledged again in a later frame instead.
+            let _ = self.ack_ranges.remove(ack_range);
 
             // `self.transmission_state` will be automatically notified in `on_processed_packet`
             // so wait for that instead
@@ -396,6 +491,48 @@ impl transmission::interest::Provider for AckManager {
     }
 }
 
+/// The `len` most recently received ranges of packet numbers
+#[derive(Clone, Copy, Debug)]
+struct AckRangesWindow<'a> {
+    ranges: &'a ack::Ranges,
+    /// The number of most recent ranges which are omitted
+    skip: usize,
+    len: usize,
+}
+
+impl<'a> AckRangesWindow<'a> {
+    /// Returns the packet numbers which no longer need to be acknowledged once a frame with
+    /// this window is acknowledged
+    fn ack_range(&self) -> AckRange {
+        let mut ranges = self
+            .ranges
+            .inclusive_ranges()
+            .rev()
+            .skip(self.skip)
+            .take(self.len);
+        let largest_range = ranges.next().expect("at least one ack range is required");
+        let largest = *largest_range.end();
+
+        if self.skip + self.len == self.ranges.interval_len() {
+            // the window reaches the oldest range so nothing below it is still tracked
+            let pn_zero = largest.space().new_packet_number(Default::default());
+            pn_zero..=largest
+        } else {
+            let smallest_range = ranges.next_back().unwrap_or(largest_range);
+            *smallest_range.start()..=largest
+        }
+    }
+}
+
+impl<'a> AckRanges for AckRangesWindow<'a> {
+    type Iter = core::iter::Take<core::iter::Skip<<&'a ack::Ranges as AckRanges>::Iter>>;
+
+    #[inline]
+    fn ack_ranges(&self) -> Self::Iter {
+        self.ranges.ack_ranges().skip(self.skip).take(self.len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{super::tests::*, *};
@@ -451,6 +588,114 @@ mod tests {
         assert!(manager.transmission_state.is_active());
     }
 
+    #[test]
+    fn ack_delay_defers_transmission() {
+        use s2n_quic_core::time::timer::Provider as _;
+
+        let max_ack_delay = Duration::from_millis(50);
+        let mut manager = AckManager::new(
+            PacketNumberSpace::ApplicationData,
+            ack::Settings {
+                max_ack_delay,
+                ..Default::default()
+            },
+        );
+
+        let now = time::now();
+        let pn = PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(1));
+        let datagram = DatagramInfo {
+            ecn: Default::default(),
+            payload_len: 1200,
+            timestamp: now,
+            destination_connection_id: connection::LocalId::TEST_ID,
+            destination_connection_id_classification: connection::id::Classification::Local,
+            source_connection_id: None,
+        };
+        let mut processed_packet = ProcessedPacket::new(pn, &datagram);
+        processed_packet.ack_elicitation = AckElicitation::Eliciting;
+
+        let path = helper_path_server();
+        let path_id = path::Id::test_id();
+        manager.on_processed_packet(
+            &processed_packet,
+            path_event!(path, path_id),
+            &mut Publisher::snapshot(),
+        );
+
+        // A single ack-eliciting packet does not require an immediate ACK
+        assert!(!manager.transmission_state.is_active());
+        assert_eq!(Some(now + max_ack_delay), manager.next_expiration());
+
+        manager.on_timeout(now + max_ack_delay - Duration::from_millis(1));
+        assert!(!manager.transmission_state.is_active());
+
+        // The ACK is sent once the configured delay has passed
+        manager.on_timeout(now + max_ack_delay);
+        assert!(manager.transmission_state.is_active());
+    }
+
+    #[test]
+    fn back_to_back_packets_are_delayed() {
+        let max_ack_delay = Duration::from_millis(50);
+        let mut manager = AckManager::new(
+            PacketNumberSpace::ApplicationData,
+            ack::Settings {
+                max_ack_delay,
+                ..Default::default()
+            },
+        );
+
+        let now = time::now();
+        let path = helper_path_server();
+        let path_id = path::Id::test_id();
+        let mut publisher = Publisher::no_snapshot();
+
+        let mut on_packet = |manager: &mut AckManager, packet_number: u8, timestamp, ecn| {
+            let pn = PacketNumberSpace::ApplicationData
+                .new_packet_number(VarInt::from_u8(packet_number));
+            let datagram = DatagramInfo {
+                ecn,
+                payload_len: 1200,
+                timestamp,
+                destination_connection_id: connection::LocalId::TEST_ID,
+                destination_connection_id_classification: connection::id::Classification::Local,
+                source_connection_id: None,
+            };
+            let mut processed_packet = ProcessedPacket::new(pn, &datagram);
+            processed_packet.ack_elicitation = AckElicitation::Eliciting;
+            manager.on_processed_packet(
+                &processed_packet,
+                path_event!(path, path_id),
+                &mut publisher,
+            );
+        };
+
+        // in-order packets received within the delay window share a single delayed ACK
+        for packet_number in 0..5 {
+            let timestamp = now + Duration::from_millis(packet_number as u64);
+            on_packet(&mut manager, packet_number, timestamp, Default::default());
+            assert!(!manager.transmission_state.is_active());
+        }
+
+        // a gap in the packet numbers requires an immediate ACK
+        on_packet(&mut manager, 7, now, Default::default());
+        assert!(manager.transmission_state.is_active());
+
+        let mut manager = AckManager::new(
+            PacketNumberSpace::ApplicationData,
+            ack::Settings {
+                max_ack_delay,
+                ..Default::default()
+            },
+        );
+        on_packet(&mut manager, 0, now, Default::default());
+        assert!(!manager.transmission_state.is_active());
+
+        // a packet marked with congestion experienced requires an immediate ACK
+        on_packet(&mut manager, 1, now, ExplicitCongestionNotification::Ce);
+        assert!(manager.transmission_state.is_active());
+    }
+
     #[test]
     fn ecn_counts() {
         // Setup:
@@ -634,6 +879,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn on_transmit_truncates_ranges_to_capacity() {
+        let settings = ack::Settings {
+            ack_ranges_limit: 100,
+            ..Default::default()
+        };
+        let mut manager = AckManager::new(PacketNumberSpace::ApplicationData, settings);
+
+        // insert disjoint ranges with large gaps so each range takes several bytes to encode
+        let mut largest = 0;
+        for idx in 0..100u32 {
+            largest = idx * 1000;
+            let pn =
+                PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u32(largest));
+            assert!(manager.ack_ranges.insert_packet_number(pn).is_ok());
+        }
+        assert_eq!(manager.ack_ranges.interval_len(), 100);
+        manager.transmission_state = AckTransmissionState::Active { retransmissions: 0 };
+
+        let max_packet_size = 64;
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(max_packet_size));
+        let mut write_context = MockWriteContext::new(
+            time::now(),
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+
+        assert!(manager.on_transmit(&mut write_context));
+
+        let mut written = write_context
+            .frame_buffer
+            .pop_front()
+            .expect("Frame is written");
+        assert!(written.data.len() <= max_packet_size);
+
+        let ranges: Vec<_> = match written.as_frame() {
+            Frame::Ack(ack) => {
+                assert_eq!(ack.largest_acknowledged(), VarInt::from_u32(largest));
+                ack.ack_ranges().collect()
+            }
+            frame => panic!("expected an ACK frame, got {frame:?}"),
+        };
+
+        // only the most recent ranges are included
+        assert!(ranges.len() > 1);
+        assert!(ranges.len() < 100);
+        for (range, idx) in ranges.iter().zip((0..100u32).rev()) {
+            let pn = VarInt::from_u32(idx * 1000);
+            assert_eq!(*range, pn..=pn);
+        }
+
+        // nothing is removed from the ranges until a packet carrying the ACK is acknowledged
+        assert_eq!(manager.ack_ranges.interval_len(), 100);
+    }
+
+    #[test]
+    fn truncated_ranges_are_eventually_acknowledged() {
+        let settings = ack::Settings {
+            ack_ranges_limit: 100,
+            ..Default::default()
+        };
+        let mut manager = AckManager::new(PacketNumberSpace::ApplicationData, settings);
+
+        // insert disjoint ranges with large gaps so each range takes several bytes to encode
+        let received: Vec<_> = (0..100u32)
+            .map(|idx| VarInt::from_u32(idx * 1000))
+            .collect();
+        for pn in received.iter().copied() {
+            let pn = PacketNumberSpace::ApplicationData.new_packet_number(pn);
+            assert!(manager.ack_ranges.insert_packet_number(pn).is_ok());
+        }
+
+        let now = time::now();
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(64));
+        let mut acknowledged = Vec::new();
+        let mut frame_count = 0;
+
+        while !manager.ack_ranges.is_empty() {
+            frame_count += 1;
+            assert!(frame_count <= 100, "every range should be acknowledged");
+
+            manager.transmission_state = AckTransmissionState::Active { retransmissions: 0 };
+            let mut write_context = MockWriteContext::new(
+                now,
+                &mut frame_buffer,
+                transmission::Constraint::None,
+                transmission::Mode::Normal,
+                endpoint::Type::Server,
+            );
+
+            assert!(manager.on_transmit(&mut write_context));
+            write_context.frame_buffer.ack_elicitation = AckElicitation::Eliciting;
+            let packet_number = write_context.packet_number();
+            manager.on_transmit_complete(&mut write_context);
+
+            let mut written = frame_buffer.pop_front().expect("Frame is written");
+            match written.as_frame() {
+                Frame::Ack(ack) => {
+                    for range in ack.ack_ranges() {
+                        assert_eq!(range.start(), range.end());
+                        acknowledged.push(*range.start());
+                    }
+                }
+                frame => panic!("expected an ACK frame, got {frame:?}"),
+            }
+            frame_buffer.flush();
+
+            // the peer acknowledges the packet carrying the ACK frame
+            manager.on_packet_ack(now, &packet_number);
+        }
+
+        // the ranges didn't fit in a single frame but every one was reported
+        assert!(frame_count > 1);
+        acknowledged.sort();
+        acknowledged.dedup();
+        assert_eq!(acknowledged, received);
+    }
+
     #[test]
     #[cfg(target_pointer_width = "64")]
     fn size_of_snapshots() {
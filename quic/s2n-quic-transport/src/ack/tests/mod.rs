@@ -11,6 +11,7 @@ use s2n_quic_core::{
 pub fn transmissions_iter() -> impl Iterator<Item = AckElicitingTransmission> {
     packet_numbers_iter().map(|pn| AckElicitingTransmission {
         sent_in_packet: pn,
+        smallest_received_packet_number_acked: pn.space().new_packet_number(Default::default()),
         largest_received_packet_number_acked: pn,
     })
 }
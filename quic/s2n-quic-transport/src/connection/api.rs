@@ -5,7 +5,7 @@
 
 use crate::{
     connection::{self, ConnectionApi, OpenToken},
-    stream::{ops, Stream, StreamError, StreamId},
+    stream::{ops, Priority, Stream, StreamError, StreamId},
 };
 use bytes::Bytes;
 use core::{
@@ -17,7 +17,9 @@ use s2n_quic_core::{
     application,
     application::ServerName,
     inet::SocketAddress,
+    path::PathInfo,
     query::{Query, QueryMut},
+    recovery::RttEstimate,
     stream::StreamType,
 };
 
@@ -141,10 +143,16 @@ impl Connection {
     pub fn poll_open_stream(
         &mut self,
         stream_type: StreamType,
+        priority: Priority,
         context: &Context,
     ) -> Poll<Result<Stream, connection::Error>> {
-        self.api
-            .poll_open_stream(&self.api, stream_type, &mut self.open_token, context)
+        self.api.poll_open_stream(
+            &self.api,
+            stream_type,
+            priority,
+            &mut self.open_token,
+            context,
+        )
     }
 
     #[inline]
@@ -157,6 +165,18 @@ impl Connection {
         self.api.poll_request(stream_id, request, context)
     }
 
+    /// Polls for the handshake to be confirmed
+    ///
+    /// The server considers the handshake confirmed once it completes. The client waits for the
+    /// server's HANDSHAKE_DONE frame.
+    #[inline]
+    pub fn poll_handshake_confirmed(
+        &self,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        self.api.poll_handshake_confirmed(context)
+    }
+
     /// Closes the Connection with the provided error code
     ///
     /// This will immediately terminate all outstanding streams.
@@ -199,6 +219,89 @@ impl Connection {
         self.api.remote_address()
     }
 
+    #[inline]
+    pub fn congestion_window(&self) -> Result<u64, connection::Error> {
+        self.api.congestion_window()
+    }
+
+    #[inline]
+    pub fn bytes_in_flight(&self) -> Result<u64, connection::Error> {
+        self.api.bytes_in_flight()
+    }
+
+    #[inline]
+    pub fn connection_flow_control_window(&self) -> Result<u64, connection::Error> {
+        self.api.connection_flow_control_window()
+    }
+
+    #[inline]
+    pub fn stream_send_window(
+        &self,
+        stream_id: StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        self.api.stream_send_window(stream_id)
+    }
+
+    #[inline]
+    pub fn stream_recv_window(
+        &self,
+        stream_id: StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        self.api.stream_recv_window(stream_id)
+    }
+
+    #[inline]
+    pub fn blocked_streams(&self) -> Result<Vec<StreamId>, connection::Error> {
+        self.api.blocked_streams()
+    }
+
+    #[inline]
+    pub fn rtt_estimate(&self) -> Result<RttEstimate, connection::Error> {
+        self.api.rtt_estimate()
+    }
+
+    #[inline]
+    pub fn mtu(&self) -> Result<usize, connection::Error> {
+        self.api.mtu()
+    }
+
+    #[inline]
+    pub fn max_datagram_size(&self) -> Result<Option<usize>, connection::Error> {
+        self.api.max_datagram_size()
+    }
+
+    #[inline]
+    pub fn peer_transport_parameters(
+        &self,
+    ) -> Result<Option<connection::PeerTransportParameters>, connection::Error> {
+        self.api.peer_transport_parameters()
+    }
+
+    #[inline]
+    pub fn delivery_rate(&self) -> Result<Option<u64>, connection::Error> {
+        self.api.delivery_rate()
+    }
+
+    #[inline]
+    pub fn stats(&self) -> Result<connection::ConnectionStats, connection::Error> {
+        self.api.stats()
+    }
+
+    #[inline]
+    pub fn close_reason(&self) -> Option<connection::ConnectionCloseReason> {
+        self.api.close_reason()
+    }
+
+    #[inline]
+    pub fn paths(&self) -> Result<Vec<PathInfo>, connection::Error> {
+        self.api.paths()
+    }
+
+    #[inline]
+    pub fn quic_version(&self) -> Result<u32, connection::Error> {
+        self.api.quic_version()
+    }
+
     #[inline]
     pub fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api.query_event_context(query)
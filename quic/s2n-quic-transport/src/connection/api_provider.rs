@@ -18,8 +18,10 @@ use s2n_quic_core::{
     application,
     application::ServerName,
     inet::SocketAddress,
+    path::PathInfo,
     query::{Query, QueryMut},
-    stream::{ops, StreamId, StreamType},
+    recovery::RttEstimate,
+    stream::{ops, Priority, StreamId, StreamType},
 };
 
 /// A dynamically dispatched connection API
@@ -48,10 +50,13 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
         &self,
         arc_self: &Arc<dyn ConnectionApiProvider>,
         stream_type: StreamType,
+        priority: Priority,
         open_token: &mut connection::OpenToken,
         context: &Context,
     ) -> Poll<Result<Stream, connection::Error>>;
 
+    fn poll_handshake_confirmed(&self, context: &Context) -> Poll<Result<(), connection::Error>>;
+
     fn close_connection(&self, code: Option<application::Error>);
 
     fn server_name(&self) -> Result<Option<ServerName>, connection::Error>;
@@ -68,6 +73,38 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn congestion_window(&self) -> Result<u64, connection::Error>;
+
+    fn bytes_in_flight(&self) -> Result<u64, connection::Error>;
+
+    fn connection_flow_control_window(&self) -> Result<u64, connection::Error>;
+
+    fn stream_send_window(&self, stream_id: StreamId) -> Result<Option<u64>, connection::Error>;
+
+    fn stream_recv_window(&self, stream_id: StreamId) -> Result<Option<u64>, connection::Error>;
+
+    fn blocked_streams(&self) -> Result<Vec<StreamId>, connection::Error>;
+
+    fn rtt_estimate(&self) -> Result<RttEstimate, connection::Error>;
+
+    fn mtu(&self) -> Result<usize, connection::Error>;
+
+    fn max_datagram_size(&self) -> Result<Option<usize>, connection::Error>;
+
+    fn peer_transport_parameters(
+        &self,
+    ) -> Result<Option<connection::PeerTransportParameters>, connection::Error>;
+
+    fn delivery_rate(&self) -> Result<Option<u64>, connection::Error>;
+
+    fn stats(&self) -> Result<connection::ConnectionStats, connection::Error>;
+
+    fn close_reason(&self) -> Option<connection::ConnectionCloseReason>;
+
+    fn paths(&self) -> Result<Vec<PathInfo>, connection::Error>;
+
+    fn quic_version(&self) -> Result<u32, connection::Error>;
+
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error>;
 
     fn query_event_context_mut(&self, query: &mut dyn QueryMut) -> Result<(), connection::Error>;
@@ -35,8 +35,9 @@ use s2n_quic_core::{
     application::ServerName,
     event::supervisor,
     inet::SocketAddress,
+    path::PathInfo,
     query::{Query, QueryMut},
-    recovery::K_GRANULARITY,
+    recovery::{RttEstimate, K_GRANULARITY},
     time::Timestamp,
     transport,
 };
@@ -275,11 +276,13 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         &self,
         arc_self: &ConnectionApi,
         stream_type: stream::StreamType,
+        priority: stream::Priority,
         open_token: &mut connection::OpenToken,
         context: &Context,
     ) -> Poll<Result<stream::Stream, connection::Error>> {
-        let response =
-            self.api_poll_call(|conn| conn.poll_open_stream(stream_type, open_token, context));
+        let response = self.api_poll_call(|conn| {
+            conn.poll_open_stream(stream_type, priority, open_token, context)
+        });
 
         match response {
             Poll::Pending => Poll::Pending,
@@ -294,6 +297,10 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         }
     }
 
+    fn poll_handshake_confirmed(&self, context: &Context) -> Poll<Result<(), connection::Error>> {
+        self.api_poll_call(|conn| conn.poll_handshake_confirmed(context))
+    }
+
     fn close_connection(&self, error: Option<application::Error>) {
         let _: Result<(), connection::Error> = self.api_write_call(|conn| {
             conn.application_close(error);
@@ -329,6 +336,79 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_read_call(|conn| conn.remote_address())
     }
 
+    fn congestion_window(&self) -> Result<u64, connection::Error> {
+        self.api_read_call(|conn| conn.congestion_window())
+    }
+
+    fn bytes_in_flight(&self) -> Result<u64, connection::Error> {
+        self.api_read_call(|conn| conn.bytes_in_flight())
+    }
+
+    fn connection_flow_control_window(&self) -> Result<u64, connection::Error> {
+        self.api_read_call(|conn| conn.connection_flow_control_window())
+    }
+
+    fn stream_send_window(
+        &self,
+        stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        self.api_read_call(|conn| conn.stream_send_window(stream_id))
+    }
+
+    fn stream_recv_window(
+        &self,
+        stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        self.api_read_call(|conn| conn.stream_recv_window(stream_id))
+    }
+
+    fn blocked_streams(&self) -> Result<Vec<stream::StreamId>, connection::Error> {
+        self.api_read_call(|conn| conn.blocked_streams())
+    }
+
+    fn rtt_estimate(&self) -> Result<RttEstimate, connection::Error> {
+        self.api_read_call(|conn| conn.rtt_estimate())
+    }
+
+    fn mtu(&self) -> Result<usize, connection::Error> {
+        self.api_read_call(|conn| conn.mtu())
+    }
+
+    fn max_datagram_size(&self) -> Result<Option<usize>, connection::Error> {
+        self.api_read_call(|conn| conn.max_datagram_size())
+    }
+
+    fn peer_transport_parameters(
+        &self,
+    ) -> Result<Option<connection::PeerTransportParameters>, connection::Error> {
+        self.api_read_call(|conn| conn.peer_transport_parameters())
+    }
+
+    fn delivery_rate(&self) -> Result<Option<u64>, connection::Error> {
+        self.api_read_call(|conn| conn.delivery_rate())
+    }
+
+    fn stats(&self) -> Result<connection::ConnectionStats, connection::Error> {
+        self.api_read_call(|conn| conn.stats())
+    }
+
+    fn close_reason(&self) -> Option<connection::ConnectionCloseReason> {
+        let error = match self.inner.read(|conn| conn.error()) {
+            Ok(error) => error,
+            // the connection panicked so there's no specific reason to report
+            Err(_) => Some(connection::Error::unspecified()),
+        };
+        error.map(Into::into)
+    }
+
+    fn paths(&self) -> Result<Vec<PathInfo>, connection::Error> {
+        self.api_read_call(|conn| conn.paths())
+    }
+
+    fn quic_version(&self) -> Result<u32, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.quic_version()))
+    }
+
     #[inline]
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api_read_call(|conn| {
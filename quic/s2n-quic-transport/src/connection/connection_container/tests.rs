@@ -29,7 +29,7 @@ use s2n_quic_core::{
         version_negotiation::ProtectedVersionNegotiation,
         zero_rtt::ProtectedZeroRtt,
     },
-    path::MaxMtu,
+    path::{MaxMtu, PathInfo},
     query,
     time::{Timer, Timestamp},
 };
@@ -271,12 +271,20 @@ impl connection::Trait for TestConnection {
     fn poll_open_stream(
         &mut self,
         _stream_type: stream::StreamType,
+        _priority: stream::Priority,
         _token: &mut connection::OpenToken,
         _context: &Context,
     ) -> Poll<Result<stream::StreamId, connection::Error>> {
         todo!()
     }
 
+    fn poll_handshake_confirmed(
+        &mut self,
+        _context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        todo!()
+    }
+
     fn application_close(&mut self, _error: Option<application::Error>) {
         // no-op
     }
@@ -305,6 +313,66 @@ impl connection::Trait for TestConnection {
         Ok(SocketAddress::default())
     }
 
+    fn congestion_window(&self) -> Result<u64, connection::Error> {
+        todo!()
+    }
+
+    fn bytes_in_flight(&self) -> Result<u64, connection::Error> {
+        todo!()
+    }
+
+    fn connection_flow_control_window(&self) -> Result<u64, connection::Error> {
+        todo!()
+    }
+
+    fn stream_send_window(
+        &self,
+        _stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        todo!()
+    }
+
+    fn stream_recv_window(
+        &self,
+        _stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        todo!()
+    }
+
+    fn blocked_streams(&self) -> Result<Vec<stream::StreamId>, connection::Error> {
+        todo!()
+    }
+
+    fn rtt_estimate(&self) -> Result<RttEstimate, connection::Error> {
+        todo!()
+    }
+
+    fn mtu(&self) -> Result<usize, connection::Error> {
+        todo!()
+    }
+
+    fn max_datagram_size(&self) -> Result<Option<usize>, connection::Error> {
+        todo!()
+    }
+
+    fn peer_transport_parameters(
+        &self,
+    ) -> Result<Option<connection::PeerTransportParameters>, connection::Error> {
+        todo!()
+    }
+
+    fn delivery_rate(&self) -> Result<Option<u64>, connection::Error> {
+        todo!()
+    }
+
+    fn stats(&self) -> Result<connection::ConnectionStats, connection::Error> {
+        todo!()
+    }
+
+    fn paths(&self) -> Result<Vec<PathInfo>, connection::Error> {
+        todo!()
+    }
+
     fn error(&self) -> Option<connection::Error> {
         None
     }
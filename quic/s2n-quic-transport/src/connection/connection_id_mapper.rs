@@ -417,6 +417,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn stateless_reset_datagram_test() {
+        let mut random_generator = random::testing::Generator(123);
+        let mut mapper = ConnectionIdMapper::new(&mut random_generator, endpoint::Type::Client);
+        let internal_id = InternalConnectionIdGenerator::new().generate_id();
+        let peer_id = id(b"id01");
+
+        let mut registry = mapper.create_client_peer_id_registry(internal_id, true);
+        registry.register_initial_connection_id(peer_id);
+        registry.register_initial_stateless_reset_token(TEST_TOKEN_1);
+
+        // A short header packet with unpredictable bits followed by a token
+        let mut unknown_reset = vec![0b0100_0000, 1, 2, 3, 4, 5, 6];
+        unknown_reset.extend_from_slice(TEST_TOKEN_2.as_ref());
+        let token = stateless_reset::Token::from_datagram(&unknown_reset).unwrap();
+        assert_eq!(
+            None,
+            mapper.remove_internal_connection_id_by_stateless_reset_token(&token)
+        );
+
+        let mut known_reset = vec![0b0100_0000, 1, 2, 3, 4, 5, 6];
+        known_reset.extend_from_slice(TEST_TOKEN_1.as_ref());
+        let token = stateless_reset::Token::from_datagram(&known_reset).unwrap();
+        assert_eq!(
+            Some(internal_id),
+            mapper.remove_internal_connection_id_by_stateless_reset_token(&token)
+        );
+    }
+
+    // A server that uses zero-length connection IDs sends packets with an empty Destination
+    // Connection ID, while its packets to the client still carry the client's own ID
+    #[test]
+    fn zero_length_peer_id_routing_test() {
+        let mut random_generator = random::testing::Generator(123);
+        let mut mapper = ConnectionIdMapper::new(&mut random_generator, endpoint::Type::Client);
+        let internal_id = InternalConnectionIdGenerator::new().generate_id();
+        let local_id = connection::LocalId::try_from_bytes(b"id000001").unwrap();
+
+        let _local_registry =
+            mapper.create_local_id_registry(internal_id, &local_id, None, TEST_TOKEN_1, true);
+        let mut peer_registry = mapper.create_client_peer_id_registry(internal_id, true);
+        peer_registry.register_initial_connection_id(id(&[]));
+
+        assert_eq!(
+            Some((internal_id, connection::id::Classification::Local)),
+            mapper.lookup_internal_connection_id(&local_id)
+        );
+
+        // s2n-quic doesn't issue zero-length local connection IDs, so an empty Destination
+        // Connection ID is never looked up
+        assert!(connection::LocalId::try_from_bytes(&[]).is_none());
+    }
+
     #[test]
     fn initial_id_map() {
         let mut random_generator = random::testing::Generator(123);
@@ -10,9 +10,9 @@ use crate::{
         id::{ConnectionInfo, Interest},
         limits::Limits,
         local_id_registry::LocalIdRegistrationError,
-        ConnectionIdMapper, ConnectionInterests, ConnectionTimers, ConnectionTransmission,
-        ConnectionTransmissionContext, InternalConnectionId, Parameters as ConnectionParameters,
-        ProcessingError,
+        ConnectionCounters, ConnectionIdMapper, ConnectionInterests, ConnectionTimers,
+        ConnectionTransmission, ConnectionTransmissionContext, InternalConnectionId,
+        Parameters as ConnectionParameters, ProcessingError,
     },
     contexts::{ConnectionApiCallContext, ConnectionOnTransmitError},
     endpoint,
@@ -54,9 +54,9 @@ use s2n_quic_core::{
         version_negotiation::ProtectedVersionNegotiation,
         zero_rtt::ProtectedZeroRtt,
     },
-    path::{Handle as _, MaxMtu},
+    path::{Handle as _, MaxMtu, PathInfo},
     query,
-    recovery::CongestionController,
+    recovery::{CongestionController, RttEstimate},
     stateless_reset::token::Generator as _,
     time::{timer, Timestamp},
     transport,
@@ -171,6 +171,10 @@ pub struct ConnectionImpl<Config: endpoint::Config> {
     error: Result<(), connection::Error>,
     /// Sends CONNECTION_CLOSE close frames after the connection is closed
     close_sender: CloseSender,
+    /// Counts the bytes sent and the packets received by the connection
+    counters: ConnectionCounters,
+    /// The application task waiting for the handshake to be confirmed
+    handshake_confirmed_waker: Option<Waker>,
     /// Manages all of the different packet spaces and their respective components
     space_manager: PacketSpaceManager<Config>,
     /// Holds the handle for waking up the endpoint from a application call
@@ -256,6 +260,7 @@ macro_rules! transmission_context {
             outcome: $outcome,
             ecn,
             min_packet_len: None,
+            max_coalesced_packets: $self.limits.max_coalesced_packets(),
             transmission_mode: $transmission_mode,
             publisher: &mut $self.event_context.publisher($timestamp, $subscriber),
             packet_interceptor: $packet_interceptor,
@@ -371,6 +376,8 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             self.timers.reset_peer_idle_timer_on_send = true;
         }
 
+        self.counters.on_processed_packet();
+
         let mut publisher = self
             .event_context
             .publisher(packet.datagram.timestamp, subscriber);
@@ -389,6 +396,12 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
                 .discard_handshake(&mut self.path_manager, &mut publisher);
         }
 
+        if self.space_manager.is_handshake_confirmed() {
+            if let Some(waker) = self.handshake_confirmed_waker.take() {
+                waker.wake();
+            }
+        }
+
         // check to see if we're flushing and should now close the connection
         if self.poll_flush().is_ready() {
             self.error?;
@@ -459,6 +472,7 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
                         local_id_registry: &mut self.local_id_registry,
                         outcome,
                         min_packet_len: None,
+                        max_coalesced_packets: self.limits.max_coalesced_packets(),
                         ecn,
                         transmission_mode,
                         publisher: &mut self.event_context.publisher(timestamp, subscriber),
@@ -597,7 +611,8 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             parameters.max_mtu,
         );
 
-        let path_manager = path::Manager::new(initial_path, parameters.peer_id_registry);
+        let mut path_manager = path::Manager::new(initial_path, parameters.peer_id_registry);
+        path_manager.set_migration_support(parameters.limits.migration_support());
 
         let mut publisher =
             event_context.publisher(parameters.timestamp, parameters.event_subscriber);
@@ -630,6 +645,8 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             limits: parameters.limits,
             error: Ok(()),
             close_sender: CloseSender::default(),
+            counters: ConnectionCounters::default(),
+            handshake_confirmed_waker: None,
             space_manager: parameters.space_manager,
             wakeup_handle,
             waker,
@@ -721,6 +738,11 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         self.state = error.into();
         self.error = Err(error);
 
+        // notify the application if it was still waiting for the handshake to be confirmed
+        if let Some(waker) = self.handshake_confirmed_waker.take() {
+            waker.wake();
+        }
+
         //= https://www.rfc-editor.org/rfc/rfc9000#section-10.3
         //# An endpoint that wishes to communicate a fatal
         //# connection error MUST use a CONNECTION_CLOSE frame if it is able.
@@ -749,12 +771,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 &mut context,
                 packet_buffer,
             ) {
-                //= https://www.rfc-editor.org/rfc/rfc9000#section-10.2
-                //# The closing and draining connection states exist to ensure that
-                //# connections close cleanly and that delayed or reordered packets are
-                //# properly discarded.  These states SHOULD persist for at least three
-                //# times the current PTO interval as defined in [QUIC-RECOVERY].
-                let timeout = 3 * self.current_pto();
+                let timeout = self.path_manager.active_path().closing_period();
 
                 self.close_sender.close(packet, timeout, timestamp);
             } else if cfg!(debug_assertions) {
@@ -955,6 +972,21 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                         .on_transmit_burst_complete(self.path_manager.active_path(), timestamp);
                 }
 
+                self.counters.on_transmit(outcome.bytes_sent);
+
+                //= https://www.rfc-editor.org/rfc/rfc9000#section-12.3
+                //# If the packet number for sending
+                //# reaches 2^62-1, the sender MUST close the connection without sending
+                //# a CONNECTION_CLOSE frame or any further packets
+                if self.space_manager.is_packet_number_exhausted() && self.error.is_ok() {
+                    // an immediate close doesn't send a CONNECTION_CLOSE frame
+                    self.error = Err(connection::Error::immediate_close(
+                        "packet number space exhausted",
+                    ));
+                    // trigger a wake up so we can close
+                    self.wakeup_handle.wakeup();
+                }
+
                 let mut publisher = self.event_context.publisher(timestamp, subscriber);
                 if outcome.bytes_progressed > 0 {
                     publisher.on_tx_stream_progress(TxStreamProgress {
@@ -1158,6 +1190,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         publisher.on_datagram_received(event::builder::DatagramReceived {
             len: datagram.payload_len as u16,
         });
+        self.counters.on_datagram_received(datagram.payload_len);
 
         if amplification_outcome.is_active_path_unblocked() {
             //= https://www.rfc-editor.org/rfc/rfc9002#appendix-A.6
@@ -1637,6 +1670,13 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         //# packet.
 
         // TODO
+        //
+        // 0-RTT packets are discarded without being decrypted, so early data is never delivered
+        // to the application and can't be replayed. Once 0-RTT is accepted, replay protection
+        // needs to come from the TLS provider (single-use tickets or ClientHello recording, as
+        // described in RFC 9001 Section 9.2) rather than from packet numbers: a replayed
+        // ClientHello creates a new connection with its own packet number space, and duplicate
+        // packet numbers within a connection are already rejected by the space's `SlidingWindow`.
         Ok(())
     }
 
@@ -1878,6 +1918,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
     fn poll_open_stream(
         &mut self,
         stream_type: stream::StreamType,
+        priority: stream::Priority,
         open_token: &mut connection::OpenToken,
         context: &Context,
     ) -> Poll<Result<stream::StreamId, connection::Error>> {
@@ -1892,12 +1933,28 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
 
         space.stream_manager.poll_open_local_stream(
             stream_type,
+            priority,
             open_token,
             &mut api_context,
             context,
         )
     }
 
+    fn poll_handshake_confirmed(
+        &mut self,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        if self.space_manager.is_handshake_confirmed() {
+            return Ok(()).into();
+        }
+
+        self.error?;
+
+        self.handshake_confirmed_waker = Some(context.waker().clone());
+
+        Poll::Pending
+    }
+
     fn application_close(&mut self, error: Option<application::Error>) {
         if self.error.is_err() {
             return;
@@ -1967,6 +2024,115 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(*self.path_manager.active_path().handle.remote_address())
     }
 
+    fn congestion_window(&self) -> Result<u64, connection::Error> {
+        Ok(self
+            .path_manager
+            .active_path()
+            .congestion_controller
+            .congestion_window()
+            .into())
+    }
+
+    fn bytes_in_flight(&self) -> Result<u64, connection::Error> {
+        Ok(self
+            .path_manager
+            .active_path()
+            .congestion_controller
+            .bytes_in_flight()
+            .into())
+    }
+
+    fn connection_flow_control_window(&self) -> Result<u64, connection::Error> {
+        let space = self
+            .space_manager
+            .application()
+            .ok_or_else(connection::Error::unspecified)?;
+        Ok(space.stream_manager.connection_send_window().as_u64())
+    }
+
+    fn stream_send_window(
+        &self,
+        stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        let space = self
+            .space_manager
+            .application()
+            .ok_or_else(connection::Error::unspecified)?;
+        Ok(space
+            .stream_manager
+            .stream_send_window(stream_id)
+            .map(|window| window.as_u64()))
+    }
+
+    fn stream_recv_window(
+        &self,
+        stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error> {
+        let space = self
+            .space_manager
+            .application()
+            .ok_or_else(connection::Error::unspecified)?;
+        Ok(space
+            .stream_manager
+            .stream_receive_window(stream_id)
+            .map(|window| window.as_u64()))
+    }
+
+    fn blocked_streams(&self) -> Result<Vec<stream::StreamId>, connection::Error> {
+        let space = self
+            .space_manager
+            .application()
+            .ok_or_else(connection::Error::unspecified)?;
+        Ok(space.stream_manager.blocked_streams())
+    }
+
+    fn rtt_estimate(&self) -> Result<RttEstimate, connection::Error> {
+        Ok(self.path_manager.active_path().rtt_estimator.estimate())
+    }
+
+    fn mtu(&self) -> Result<usize, connection::Error> {
+        Ok(self.path_manager.active_path().mtu_controller.mtu())
+    }
+
+    fn max_datagram_size(&self) -> Result<Option<usize>, connection::Error> {
+        // the peer's limit isn't known until the application space is available
+        if let Some(space) = self.space_manager.application() {
+            let path = self.path_manager.active_path();
+            Ok(space.max_datagram_size(
+                path.mtu(transmission::Mode::Normal),
+                path.peer_connection_id.len(),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn peer_transport_parameters(
+        &self,
+    ) -> Result<Option<connection::PeerTransportParameters>, connection::Error> {
+        Ok(self.space_manager.peer_transport_parameters)
+    }
+
+    fn delivery_rate(&self) -> Result<Option<u64>, connection::Error> {
+        Ok(self
+            .path_manager
+            .active_path()
+            .congestion_controller
+            .delivery_rate()
+            .map(|rate| rate.as_bytes_per_second()))
+    }
+
+    fn stats(&self) -> Result<connection::ConnectionStats, connection::Error> {
+        Ok(self.counters.snapshot(
+            self.space_manager.packet_counts(),
+            self.path_manager.active_path(),
+        ))
+    }
+
+    fn paths(&self) -> Result<Vec<PathInfo>, connection::Error> {
+        Ok(self.path_manager.iter().map(path::Path::info).collect())
+    }
+
     fn error(&self) -> Option<connection::Error> {
         self.error.err()
     }
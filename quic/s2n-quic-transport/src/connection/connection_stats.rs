@@ -0,0 +1,102 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{endpoint, path::Path, space::PacketCounts};
+use s2n_quic_core::{connection::ConnectionStats, recovery::CongestionController};
+
+/// Counters that are tracked by the connection rather than by the packet spaces or paths
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConnectionCounters {
+    bytes_sent: u64,
+    packets_received: u64,
+    bytes_received: u64,
+}
+
+impl ConnectionCounters {
+    /// Called after a burst of packets has been transmitted
+    #[inline]
+    pub fn on_transmit(&mut self, bytes_sent: usize) {
+        self.bytes_sent += bytes_sent as u64;
+    }
+
+    /// Called when a datagram from the peer is accepted by the connection
+    #[inline]
+    pub fn on_datagram_received(&mut self, payload_len: usize) {
+        self.bytes_received += payload_len as u64;
+    }
+
+    /// Called when a packet from the peer is successfully processed
+    #[inline]
+    pub fn on_processed_packet(&mut self) {
+        self.packets_received += 1;
+    }
+
+    /// Combines the counters with the packet space counts and the state of the active path
+    pub fn snapshot<Config: endpoint::Config>(
+        &self,
+        packet_counts: PacketCounts,
+        active_path: &Path<Config>,
+    ) -> ConnectionStats {
+        let mut stats = ConnectionStats::default();
+        stats.packets_sent = packet_counts.sent;
+        stats.packets_received = self.packets_received;
+        stats.bytes_sent = self.bytes_sent;
+        stats.bytes_received = self.bytes_received;
+        stats.packets_lost = packet_counts.lost;
+        stats.spurious_retransmissions = packet_counts.spurious_retransmissions;
+        stats.smoothed_rtt = active_path.rtt_estimator.smoothed_rtt();
+        stats.congestion_window = active_path.congestion_controller.congestion_window();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{path::testing::helper_path_server, recovery, space::TxPacketNumbers};
+    use core::time::Duration;
+    use s2n_quic_core::{packet::number::PacketNumberSpace, time::testing::now, varint::VarInt};
+
+    #[test]
+    fn snapshot_test() {
+        let now = now();
+        let mut path = helper_path_server();
+        let space = PacketNumberSpace::ApplicationData;
+
+        // send 3 packets in one space and 2 in another
+        let mut tx_packet_numbers = TxPacketNumbers::new(space, now);
+        for packet_number in 0..3 {
+            tx_packet_numbers.on_transmit(space.new_packet_number(VarInt::from_u8(packet_number)));
+        }
+        let recovery_manager = recovery::Manager::<endpoint::testing::Server>::new(space);
+        let mut packet_counts = PacketCounts::new(&tx_packet_numbers, &recovery_manager);
+        packet_counts += PacketCounts {
+            sent: 2,
+            lost: 1,
+            spurious_retransmissions: 1,
+        };
+
+        let mut counters = ConnectionCounters::default();
+        counters.on_transmit(1200);
+        counters.on_transmit(300);
+        counters.on_datagram_received(1000);
+        counters.on_processed_packet();
+        counters.on_processed_packet();
+
+        path.rtt_estimator
+            .update_rtt(Duration::ZERO, Duration::from_millis(50), now, true, space);
+
+        let stats = counters.snapshot(packet_counts, &path);
+        assert_eq!(stats.packets_sent, 5);
+        assert_eq!(stats.packets_lost, 1);
+        assert_eq!(stats.spurious_retransmissions, 1);
+        assert_eq!(stats.bytes_sent, 1500);
+        assert_eq!(stats.packets_received, 2);
+        assert_eq!(stats.bytes_received, 1000);
+        assert_eq!(stats.smoothed_rtt, Duration::from_millis(50));
+        assert_eq!(
+            stats.congestion_window,
+            path.congestion_controller.congestion_window()
+        );
+    }
+}
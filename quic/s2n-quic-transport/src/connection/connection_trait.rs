@@ -32,8 +32,9 @@ use s2n_quic_core::{
         zero_rtt::ProtectedZeroRtt,
         ProtectedPacket,
     },
-    path::{Handle as _, MaxMtu},
+    path::{Handle as _, MaxMtu, PathInfo},
     query,
+    recovery::RttEstimate,
     time::Timestamp,
 };
 
@@ -462,10 +463,16 @@ pub trait ConnectionTrait: 'static + Send + Sized {
     fn poll_open_stream(
         &mut self,
         stream_type: stream::StreamType,
+        priority: stream::Priority,
         open_token: &mut connection::OpenToken,
         context: &Context,
     ) -> Poll<Result<stream::StreamId, connection::Error>>;
 
+    fn poll_handshake_confirmed(
+        &mut self,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>>;
+
     fn application_close(&mut self, error: Option<application::Error>);
 
     fn server_name(&self) -> Option<ServerName>;
@@ -480,6 +487,40 @@ pub trait ConnectionTrait: 'static + Send + Sized {
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn congestion_window(&self) -> Result<u64, connection::Error>;
+
+    fn bytes_in_flight(&self) -> Result<u64, connection::Error>;
+
+    fn connection_flow_control_window(&self) -> Result<u64, connection::Error>;
+
+    fn stream_send_window(
+        &self,
+        stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error>;
+
+    fn stream_recv_window(
+        &self,
+        stream_id: stream::StreamId,
+    ) -> Result<Option<u64>, connection::Error>;
+
+    fn blocked_streams(&self) -> Result<Vec<stream::StreamId>, connection::Error>;
+
+    fn rtt_estimate(&self) -> Result<RttEstimate, connection::Error>;
+
+    fn mtu(&self) -> Result<usize, connection::Error>;
+
+    fn max_datagram_size(&self) -> Result<Option<usize>, connection::Error>;
+
+    fn peer_transport_parameters(
+        &self,
+    ) -> Result<Option<connection::PeerTransportParameters>, connection::Error>;
+
+    fn delivery_rate(&self) -> Result<Option<u64>, connection::Error>;
+
+    fn stats(&self) -> Result<connection::ConnectionStats, connection::Error>;
+
+    fn paths(&self) -> Result<Vec<PathInfo>, connection::Error>;
+
     fn error(&self) -> Option<connection::Error>;
 
     fn query_event_context(&self, query: &mut dyn query::Query);
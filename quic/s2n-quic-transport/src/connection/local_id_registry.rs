@@ -16,6 +16,7 @@ use s2n_quic_core::{
     packet::number::PacketNumber,
     stateless_reset,
     time::{timer, Duration, Timer, Timestamp},
+    transport,
 };
 use smallvec::SmallVec;
 use std::sync::{Arc, Mutex};
@@ -218,6 +219,22 @@ impl LocalIdRegistrationError {
     }
 }
 
+impl From<LocalIdRegistrationError> for transport::Error {
+    fn from(err: LocalIdRegistrationError) -> Self {
+        let transport_error = match err {
+            // Local connection IDs are generated by this endpoint, so a collision is not
+            // caused by the peer
+            LocalIdRegistrationError::ConnectionIdInUse => transport::Error::INTERNAL_ERROR,
+            //= https://www.rfc-editor.org/rfc/rfc9000#section-19.16
+            //# Receipt of a RETIRE_CONNECTION_ID frame containing a sequence number
+            //# greater than any previously sent to the peer MUST be treated as a
+            //# connection error of type PROTOCOL_VIOLATION.
+            LocalIdRegistrationError::InvalidSequenceNumber => transport::Error::PROTOCOL_VIOLATION,
+        };
+        transport_error.with_reason(err.message())
+    }
+}
+
 impl Drop for LocalIdRegistry {
     fn drop(&mut self) {
         if let Ok(mut guard) = self.state.lock() {
@@ -447,7 +464,8 @@ impl LocalIdRegistry {
             .registered_ids
             .iter_mut()
             // Filter out IDs that are already PendingRemoval, indicating this was a duplicate
-            // RETIRE_CONNECTION_ID frame
+            // RETIRE_CONNECTION_ID frame. Duplicates received after the ID was removed
+            // aren't found at all, so in both cases the frame has no effect.
             .filter(|id_info| !matches!(id_info.status, PendingRemoval(_)))
             .find(|id_info| id_info.sequence_number == sequence_number);
 
@@ -9,6 +9,7 @@ use s2n_quic_core::{
     random,
     stateless_reset::token::testing::*,
     time::{clock::testing as time, timer::Provider as _},
+    transport,
     varint::VarInt,
 };
 
@@ -343,6 +344,99 @@ fn on_retire_connection_id() {
     assert!(mapper.lookup_internal_connection_id(&ext_id_2).is_none());
 }
 
+#[test]
+fn on_retire_connection_id_exceeds_issued() {
+    let ext_id_1 = id(b"id01");
+    let ext_id_2 = id(b"id02");
+
+    let now = time::now();
+    let (_mapper, mut reg1) = mapper(ext_id_1, None, TEST_TOKEN_1);
+
+    reg1.set_active_connection_id_limit(2);
+    assert!(reg1
+        .register_connection_id(&ext_id_2, None, TEST_TOKEN_2)
+        .is_ok());
+
+    // sequence numbers 0 and 1 have been issued, so 2 was never sent to the peer
+    for sequence_number in [2, u32::MAX] {
+        let err = reg1
+            .on_retire_connection_id(sequence_number, &ext_id_1, Duration::default(), now)
+            .unwrap_err();
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-19.16
+        //= type=test
+        //# Receipt of a RETIRE_CONNECTION_ID frame containing a sequence number
+        //# greater than any previously sent to the peer MUST be treated as a
+        //# connection error of type PROTOCOL_VIOLATION.
+        let transport_error: transport::Error = err.into();
+        assert_eq!(
+            transport::Error::PROTOCOL_VIOLATION.code,
+            transport_error.code
+        );
+    }
+
+    // the issued connection IDs are unaffected
+    assert!(reg1.get_connection_id_info(&ext_id_1).is_some());
+    assert!(reg1.get_connection_id_info(&ext_id_2).is_some());
+}
+
+#[test]
+fn on_retire_connection_id_duplicate() {
+    let ext_id_1 = id(b"id01");
+    let ext_id_2 = id(b"id02");
+
+    let now = time::now();
+    let (mapper, mut reg1) = mapper(ext_id_1, None, TEST_TOKEN_1);
+    reg1.set_active_connection_id_limit(2);
+
+    assert!(reg1
+        .register_connection_id(&ext_id_2, None, TEST_TOKEN_2)
+        .is_ok());
+
+    let rtt = Duration::from_millis(500);
+    let removal_time = now + rtt * RTT_MULTIPLIER;
+
+    assert!(reg1.on_retire_connection_id(1, &ext_id_1, rtt, now).is_ok());
+    assert_eq!(
+        PendingRemoval(removal_time),
+        reg1.get_connection_id_info(&ext_id_2).unwrap().status
+    );
+    assert_eq!(
+        connection::id::Interest::New(1),
+        reg1.connection_id_interest()
+    );
+
+    // A duplicate frame received later, even in a packet sent to the retired ID, doesn't extend
+    // the removal time or free up another slot for a new ID
+    let later = now + Duration::from_millis(100);
+    assert!(reg1
+        .on_retire_connection_id(1, &ext_id_2, rtt, later)
+        .is_ok());
+    assert_eq!(
+        PendingRemoval(removal_time),
+        reg1.get_connection_id_info(&ext_id_2).unwrap().status
+    );
+    assert_eq!(
+        connection::id::Interest::New(1),
+        reg1.connection_id_interest()
+    );
+
+    // A duplicate frame received after the ID was removed is ignored
+    reg1.unregister_expired_ids(removal_time);
+    assert!(mapper.lookup_internal_connection_id(&ext_id_2).is_none());
+    assert!(reg1
+        .on_retire_connection_id(1, &ext_id_1, rtt, removal_time)
+        .is_ok());
+    assert_eq!(
+        Active,
+        reg1.get_connection_id_info(&ext_id_1).unwrap().status
+    );
+    assert_eq!(
+        connection::id::Interest::New(1),
+        reg1.connection_id_interest()
+    );
+}
+
 #[test]
 fn on_retire_connection_id_pending_removal() {
     let ext_id_1 = id(b"id01");
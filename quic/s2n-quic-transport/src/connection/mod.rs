@@ -16,6 +16,7 @@ mod connection_container;
 mod connection_id_mapper;
 mod connection_impl;
 mod connection_interests;
+mod connection_stats;
 mod connection_timers;
 mod connection_trait;
 pub(crate) mod finalization;
@@ -29,6 +30,7 @@ pub(crate) use api_provider::{ConnectionApi, ConnectionApiProvider};
 pub(crate) use connection_container::{ConnectionContainer, ConnectionContainerIterationResult};
 pub(crate) use connection_id_mapper::ConnectionIdMapper;
 pub(crate) use connection_interests::ConnectionInterests;
+pub(crate) use connection_stats::ConnectionCounters;
 pub(crate) use connection_timers::ConnectionTimers;
 pub(crate) use connection_trait::ConnectionTrait as Trait;
 pub(crate) use internal_connection_id::{InternalConnectionId, InternalConnectionIdGenerator};
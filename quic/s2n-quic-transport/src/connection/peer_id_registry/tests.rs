@@ -529,3 +529,16 @@ pub fn client_peer_id_registry_should_not_register_cid() {
     assert!(reg.registered_ids.is_empty());
     assert!(reg.is_empty());
 }
+
+// Servers that don't need connection IDs for routing may use zero-length IDs
+#[test]
+fn zero_length_initial_peer_id() {
+    let empty_id = id(&[]);
+    let mut reg = peer_registry(empty_id, None);
+
+    assert!(empty_id.is_empty());
+    assert!(reg.is_active(&empty_id));
+
+    // A peer that uses zero-length connection IDs will not issue new IDs to consume
+    assert!(reg.consume_new_id_for_new_path().is_none());
+}
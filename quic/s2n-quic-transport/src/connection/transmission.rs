@@ -27,6 +27,8 @@ pub struct ConnectionTransmissionContext<'a, 'sub, Config: endpoint::Config> {
     pub outcome: &'a mut transmission::Outcome,
     pub ecn: ExplicitCongestionNotification,
     pub min_packet_len: Option<usize>,
+    /// The maximum number of packets to coalesce into a single datagram
+    pub max_coalesced_packets: u8,
     pub transmission_mode: transmission::Mode,
     pub publisher: &'a mut event::ConnectionPublisherSubscriber<'sub, Config::EventSubscriber>,
     pub packet_interceptor: &'a mut Config::PacketInterceptor,
@@ -120,6 +122,9 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             "the amplification limit should be checked before trying to transmit"
         );
 
+        let max_coalesced_packets = self.context.max_coalesced_packets as usize;
+        debug_assert_ne!(max_coalesced_packets, 0);
+
         // limit the number of retries to the MAX_BURST_PACKETS
         for _ in 0..MAX_BURST_PACKETS {
             let encoder = EncoderBuffer::new(&mut buffer[..mtu]);
@@ -173,17 +178,24 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             // if there will be an ApplicationData packet, since those packets come at the end of the
             // datagram. If there is no ApplicationData packet, the Handshake packet will come at the
             // end, so we check that next. Finally, if there is no ApplicationData or Handshake packet
-            // to transmit, the Initial packet itself will be padded.
+            // to transmit, the Initial packet itself will be padded. Packets that would exceed the
+            // `max_coalesced_packets` limit are left for the next datagram and can't be padded.
             let mut pn_space_to_pad = {
                 let needs_padding =
                     has_transmission(space_manager.initial(), transmission_constraint);
+                let has_handshake =
+                    has_transmission(space_manager.handshake(), transmission_constraint);
+                // the Initial packet is always first, followed by the Handshake packet, if any
+                let application_index = 1 + has_handshake as usize;
 
                 if !needs_padding {
                     // There is no Initial packet, so no padding is needed
                     None
-                } else if has_transmission(space_manager.application(), transmission_constraint) {
+                } else if application_index < max_coalesced_packets
+                    && has_transmission(space_manager.application(), transmission_constraint)
+                {
                     Some(PacketNumberSpace::ApplicationData)
-                } else if has_transmission(space_manager.handshake(), transmission_constraint) {
+                } else if has_handshake && max_coalesced_packets > 1 {
                     Some(PacketNumberSpace::Handshake)
                 } else {
                     //= https://www.rfc-editor.org/rfc/rfc9001#section-4.9
@@ -199,6 +211,7 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             // here we query all of the spaces to try and fill the current datagram
 
             let is_mtu_probing = self.context.transmission_mode.is_mtu_probing();
+            let mut packet_count = 0;
 
             let encoder = if let Some((space, handshake_status)) = space_manager
                 .initial_mut()
@@ -229,6 +242,7 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                             pn_space_to_pad = None;
                         }
                         *self.context.outcome += outcome;
+                        packet_count += 1;
                         encoder
                     }
                     Err(PacketEncodingError::PacketNumberTruncationError(encoder)) => {
@@ -256,6 +270,7 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                 .handshake_mut()
                 // MTU probes are only sent in the Application Space
                 .filter(|_| !is_mtu_probing)
+                .filter(|_| packet_count < max_coalesced_packets)
             {
                 self.context.min_packet_len = pn_space_to_pad
                     .filter(|pn_space| pn_space.is_handshake())
@@ -269,6 +284,7 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                 ) {
                     Ok((outcome, encoder)) => {
                         *self.context.outcome += outcome;
+                        packet_count += 1;
                         encoder
                     }
                     Err(PacketEncodingError::PacketNumberTruncationError(encoder)) => {
@@ -319,7 +335,10 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             // frames are only allowed in the ApplicationData space, which will always be the highest
             // current-available encryption level.
 
-            let encoder = if let Some((space, handshake_status)) = space_manager.application_mut() {
+            let encoder = if let Some((space, handshake_status)) = space_manager
+                .application_mut()
+                .filter(|_| packet_count < max_coalesced_packets)
+            {
                 self.context.min_packet_len = pn_space_to_pad
                     .filter(|pn_space| pn_space.is_application_data())
                     .map(|_| encoder.capacity());
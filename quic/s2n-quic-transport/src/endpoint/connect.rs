@@ -25,6 +25,7 @@ pub(crate) type ConnectionSender = oneshot::Sender<Result<Connection, connection
 pub struct Connect {
     pub(crate) remote_address: RemoteAddress,
     pub(crate) server_name: Option<ServerName>,
+    pub(crate) token: Option<Vec<u8>>,
 }
 
 impl fmt::Display for Connect {
@@ -49,6 +50,7 @@ impl Connect {
         Self {
             remote_address: addr.into().into(),
             server_name: None,
+            token: None,
         }
     }
 
@@ -60,6 +62,18 @@ impl Connect {
             ..self
         }
     }
+
+    /// Specifies an address validation token to include in the client's Initial packets
+    ///
+    /// The token should have been received in a NEW_TOKEN frame on a previous connection to the
+    /// same server. A valid token allows the server to skip address validation with a Retry.
+    #[must_use]
+    pub fn with_token<Token: Into<Vec<u8>>>(self, token: Token) -> Self {
+        Self {
+            token: Some(token.into()),
+            ..self
+        }
+    }
 }
 
 /// Make it easy for applications to create a connection attempt without importing the `Connect` struct
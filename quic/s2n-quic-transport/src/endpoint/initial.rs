@@ -20,8 +20,9 @@ use s2n_quic_core::{
     event::{self, supervisor, ConnectionPublisher, IntoEvent, Subscriber as _},
     inet::{datagram, DatagramInfo},
     packet::initial::ProtectedInitial,
-    path::Handle as _,
+    path::{self, Handle as _},
     stateless_reset::token::Generator as _,
+    token::{self, Format as _},
     transport::{self, parameters::ServerTransportParameters},
 };
 
@@ -172,6 +173,19 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
 
         transport_parameters.load_limits(&limits);
 
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-18.2
+        //# It is expected that
+        //#    this is the space an endpoint dedicates to holding incoming
+        //#    packets.
+        //
+        // Advertise the largest payload that fits in the receive buffers, which are sized by the
+        // `max_mtu` of the IO provider.
+        if let Ok(max_udp_payload_size) =
+            path::mtu::max_udp_payload(self.max_mtu, &remote_address).try_into()
+        {
+            transport_parameters.max_udp_payload_size = max_udp_payload_size;
+        }
+
         //= https://www.rfc-editor.org/rfc/rfc9000#section-7.3
         //# A server includes the Destination Connection ID field from the first
         //# Initial packet it received from the client in the
@@ -262,7 +276,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             &mut event_context,
         );
 
-        let space_manager = PacketSpaceManager::new(
+        let mut space_manager = PacketSpaceManager::new(
             original_destination_connection_id,
             tls_session,
             initial_key,
@@ -271,6 +285,24 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             &mut publisher,
         );
 
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
+        //# A server MAY provide clients with an address validation token during
+        //# one connection that can be used on a subsequent connection.
+        let mut new_token = vec![0; <Config::TokenFormat as token::Format>::TOKEN_LEN];
+        let mut token_context = token::Context::new(
+            &remote_address,
+            &source_connection_id,
+            endpoint_context.random_generator,
+        );
+        if endpoint_context
+            .token
+            .generate_new_token(&mut token_context, &initial_connection_id, &mut new_token)
+            .is_some()
+        {
+            // the token is sent once the handshake is confirmed
+            space_manager.set_new_token(new_token);
+        }
+
         let max_mtu = self.max_mtu;
         let connection_parameters = connection::Parameters {
             internal_connection_id,
@@ -21,7 +21,7 @@ use core::{
     convert::TryInto,
     task::{self, Poll},
 };
-use s2n_codec::{DecoderBuffer, DecoderBufferMut};
+use s2n_codec::DecoderBufferMut;
 use s2n_quic_core::{
     connection::{
         id::{ConnectionInfo, Generator},
@@ -39,7 +39,7 @@ use s2n_quic_core::{
     path,
     path::{Handle as _, MaxMtu},
     random::Generator as _,
-    stateless_reset::token::{Generator as _, LEN as StatelessResetTokenLen},
+    stateless_reset::token::Generator as _,
     time::{Clock, Timestamp},
     token::{self, Format},
     transport::parameters::ClientTransportParameters,
@@ -682,7 +682,14 @@ impl<Cfg: Config> Endpoint<Cfg> {
                         .token
                         .validate_token(&mut context, packet.token());
 
-                    if outcome.is_none() {
+                    // tokens from NEW_TOKEN frames validate the address without carrying the
+                    // original destination connection id of a Retry
+                    let is_valid = outcome.is_some()
+                        || endpoint_context
+                            .token
+                            .validate_new_token(&mut context, packet.token());
+
+                    if !is_valid {
                         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
                         //= type=TODO
                         //= tracking-issue=344
@@ -833,17 +840,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
         payload: &[u8],
         timestamp: Timestamp,
     ) -> Option<InternalConnectionId> {
-        let buffer = DecoderBuffer::new(payload);
-
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-10.3.1
-        //# The endpoint
-        //# identifies a received datagram as a Stateless Reset by comparing the
-        //# last 16 bytes of the datagram with all stateless reset tokens
-        //# associated with the remote address on which the datagram was
-        //# received.
-        let token_index = payload.len().checked_sub(StatelessResetTokenLen)?;
-        let buffer = buffer.skip(token_index).ok()?;
-        let (token, _) = buffer.decode().ok()?;
+        let token = s2n_quic_core::stateless_reset::Token::from_datagram(payload)?;
         let endpoint_context = self.config.context();
         let internal_id = self
             .connection_id_mapper
@@ -935,6 +932,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
                 endpoint::connect::Connect {
                     remote_address,
                     server_name: hostname,
+                    token,
                 },
             sender,
         } = request;
@@ -1047,6 +1045,19 @@ impl<Cfg: Config> Endpoint<Cfg> {
             .on_connection(&LimitsInfo::new(&remote_address));
         transport_parameters.load_limits(&limits);
 
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-18.2
+        //# It is expected that
+        //#    this is the space an endpoint dedicates to holding incoming
+        //#    packets.
+        //
+        // Advertise the largest payload that fits in the receive buffers, which are sized by the
+        // `max_mtu` of the IO provider.
+        if let Ok(max_udp_payload_size) =
+            path::mtu::max_udp_payload(self.max_mtu, &remote_address).try_into()
+        {
+            transport_parameters.max_udp_payload_size = max_udp_payload_size;
+        }
+
         transport_parameters.max_datagram_frame_size = endpoint_context
             .datagram
             .max_datagram_frame_size(&PreConnectionInfo::new())
@@ -1078,7 +1089,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
                 &transport_parameters,
                 hostname.expect("application should provide a valid server name"),
             );
-        let space_manager = PacketSpaceManager::new(
+        let mut space_manager = PacketSpaceManager::new(
             original_destination_connection_id,
             tls_session,
             initial_key,
@@ -1087,6 +1098,14 @@ impl<Cfg: Config> Endpoint<Cfg> {
             &mut publisher,
         );
 
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
+        //# When connecting to a server for
+        //# which the client retains an applicable and unused token, it SHOULD
+        //# include that token in the Token field of its Initial packet.
+        if let Some(((initial, _), token)) = space_manager.initial_mut().zip(token) {
+            initial.set_token(token);
+        }
+
         let wakeup_handle = self
             .wakeup_queue
             .create_wakeup_handle(internal_connection_id);
@@ -0,0 +1,85 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use s2n_quic_core::counter::{Counter, Saturating};
+
+/// Tracks the number of bytes that may be sent to an address before it is validated
+///
+/// The budget is three times the number of bytes received from the address, minus the number
+/// of bytes that have already been sent to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AmplificationLimiter {
+    tx_allowance: Counter<u32, Saturating>,
+}
+
+impl AmplificationLimiter {
+    /// Called when bytes have been received from the address
+    #[inline]
+    pub fn on_received(&mut self, bytes: usize) {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
+        //# For the purposes of
+        //# avoiding amplification prior to address validation, servers MUST
+        //# count all of the payload bytes received in datagrams that are
+        //# uniquely attributed to a single connection.
+        //
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
+        //# Prior to validating the client address, servers MUST NOT send more
+        //# than three times as many bytes as the number of bytes they have
+        //# received.
+        //
+        self.tx_allowance += bytes.saturating_mul(3) as u32;
+    }
+
+    /// Called when bytes have been sent to the address
+    #[inline]
+    pub fn on_sent(&mut self, bytes: usize) {
+        self.tx_allowance -= bytes as u32;
+    }
+
+    /// Returns the number of bytes that can be sent before the limit is reached
+    #[inline]
+    pub fn remaining_budget(&self) -> usize {
+        *self.tx_allowance as usize
+    }
+
+    /// Returns `true` if nothing more can be sent until more bytes are received
+    ///
+    /// Note: As long as there are _any_ TX credits the limiter is not considered blocked.
+    ///       This may result in sending slightly more than 3x bytes but networking infrastructure
+    ///       mostly cares about the number of packets rather than bytes.
+    #[inline]
+    pub fn is_blocked(&self) -> bool {
+        self.remaining_budget() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_test() {
+        let mut limiter = AmplificationLimiter::default();
+
+        // nothing can be sent before anything is received
+        assert!(limiter.is_blocked());
+        assert_eq!(limiter.remaining_budget(), 0);
+
+        limiter.on_received(100);
+        assert!(!limiter.is_blocked());
+        assert_eq!(limiter.remaining_budget(), 300);
+
+        limiter.on_sent(200);
+        assert_eq!(limiter.remaining_budget(), 100);
+
+        // sending more than the budget saturates rather than wrapping
+        limiter.on_sent(150);
+        assert!(limiter.is_blocked());
+        assert_eq!(limiter.remaining_budget(), 0);
+
+        // receiving more bytes unblocks the limiter
+        limiter.on_received(10);
+        assert!(!limiter.is_blocked());
+        assert_eq!(limiter.remaining_budget(), 30);
+    }
+}
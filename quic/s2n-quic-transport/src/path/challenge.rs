@@ -17,6 +17,8 @@ pub struct Challenge {
     abandon_duration: Duration,
     abandon_timer: Timer,
     data: Data,
+    /// When the first PATH_CHALLENGE frame was sent
+    sent_at: Option<Timestamp>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -68,6 +70,7 @@ impl Challenge {
             abandon_duration,
             abandon_timer: Timer::default(),
             data,
+            sent_at: None,
         }
     }
 
@@ -77,6 +80,7 @@ impl Challenge {
             abandon_duration: Duration::ZERO,
             abandon_timer: Timer::default(),
             data: DISABLED_DATA,
+            sent_at: None,
         }
     }
 
@@ -97,6 +101,7 @@ impl Challenge {
                     if !self.abandon_timer.is_armed() {
                         self.abandon_timer
                             .set(context.current_time() + self.abandon_duration);
+                        self.sent_at = Some(context.current_time());
                     }
                 }
             }
@@ -112,6 +117,16 @@ impl Challenge {
     ) {
         if self.abandon_timer.poll_expiration(timestamp).is_ready() {
             self.abandon(publisher, path);
+
+            if let Some(sent_at) = self.sent_at {
+                publisher.on_path_validation_updated(event::builder::PathValidationUpdated {
+                    status: event::builder::PathChallengeStatus::Abandoned,
+                    local_addr: path.local_addr,
+                    remote_addr: path.remote_addr,
+                    challenge_sent_at: sent_at,
+                    completed_at: timestamp,
+                });
+            }
         }
     }
 
@@ -154,6 +169,11 @@ impl Challenge {
     pub fn challenge_data(&self) -> &[u8] {
         &self.data
     }
+
+    /// Returns when the first PATH_CHALLENGE frame was sent, if it has been
+    pub fn sent_at(&self) -> Option<Timestamp> {
+        self.sent_at
+    }
 }
 
 impl timer::Provider for Challenge {
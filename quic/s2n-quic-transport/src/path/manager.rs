@@ -26,7 +26,10 @@ use s2n_quic_core::{
     recovery::congestion_controller::{self, Endpoint as _},
     stateless_reset,
     time::{timer, Timestamp},
-    transport,
+    transport::{
+        self,
+        parameters::{MaxUdpPayloadSize, MigrationSupport},
+    },
 };
 use smallvec::SmallVec;
 
@@ -66,6 +69,17 @@ pub struct Manager<Config: endpoint::Config> {
     /// The `paths` data structure will need to be enhanced to include garbage collection
     /// of old paths to overcome this limitation.
     pending_packet_authentication: Option<u8>,
+
+    /// Whether the local endpoint allows the peer to actively migrate the connection
+    ///
+    /// This reflects the `disable_active_migration` transport parameter sent to the peer.
+    migration_support: MigrationSupport,
+
+    /// The `max_udp_payload_size` transport parameter advertised by the peer
+    ///
+    /// This limits the size of datagrams sent on every path, including paths created after
+    /// the transport parameters were received.
+    peer_max_udp_payload_size: MaxUdpPayloadSize,
 }
 
 impl<Config: endpoint::Config> Manager<Config> {
@@ -76,12 +90,32 @@ impl<Config: endpoint::Config> Manager<Config> {
             active: 0,
             last_known_active_validated_path: None,
             pending_packet_authentication: None,
+            migration_support: MigrationSupport::Enabled,
+            peer_max_udp_payload_size: Default::default(),
         };
         manager.paths[0].activated = true;
         manager.paths[0].is_active = true;
         manager
     }
 
+    /// Sets whether the peer is allowed to actively migrate the connection
+    ///
+    /// This should match the `disable_active_migration` transport parameter sent to the peer.
+    #[inline]
+    pub fn set_migration_support(&mut self, migration_support: MigrationSupport) {
+        self.migration_support = migration_support;
+    }
+
+    /// Called when the peer's `max_udp_payload_size` transport parameter is received
+    #[inline]
+    pub fn on_peer_max_udp_payload_size(&mut self, max_udp_payload_size: MaxUdpPayloadSize) {
+        self.peer_max_udp_payload_size = max_udp_payload_size;
+
+        for path in self.paths.iter_mut() {
+            path.on_peer_max_udp_payload_size(max_udp_payload_size);
+        }
+    }
+
     /// Update the active path
     fn update_active_path<Pub: event::ConnectionPublisher>(
         &mut self,
@@ -155,6 +189,12 @@ impl<Config: endpoint::Config> Manager<Config> {
         path_id(self.active)
     }
 
+    /// Returns an iterator over all of the paths in the manager
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Path<Config>> {
+        self.paths.iter()
+    }
+
     pub fn check_active_path_is_synced(&self) {
         if cfg!(debug_assertions) {
             for (idx, path) in self.paths.iter().enumerate() {
@@ -324,6 +364,23 @@ impl<Config: endpoint::Config> Manager<Config> {
         //# Clients are responsible for initiating all migrations.
         debug_assert!(Config::ENDPOINT_TYPE.is_server());
 
+        // A peer actively migrating uses a new connection ID on the new path. Address changes
+        // which keep the same connection ID are likely the result of NAT rebinding, so they are
+        // still validated even if active migration was disabled.
+        let is_active_migration =
+            self.active_path().local_connection_id != datagram.destination_connection_id;
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9
+        //# If the peer
+        //# violates this requirement, the endpoint MUST either drop the incoming
+        //# packets on that path without generating a Stateless Reset or proceed
+        //# with path validation and allow the peer to migrate.
+        if is_active_migration && self.migration_support == MigrationSupport::Disabled {
+            let reason = migration::DenyReason::ConnectionMigrationDisabled;
+            publisher.on_connection_migration_denied(reason.into_event());
+            return Err(DatagramDropReason::RejectedConnectionMigration);
+        }
+
         let remote_address = path_handle.remote_address();
         let local_address = path_handle.local_address();
         let active_local_addr = self.active_path().local_address();
@@ -445,6 +502,7 @@ impl<Config: endpoint::Config> Manager<Config> {
             true,
             max_mtu,
         );
+        path.on_peer_max_udp_payload_size(self.peer_max_udp_payload_size);
 
         let amplification_outcome = path.on_bytes_received(datagram.payload_len);
 
@@ -455,6 +513,14 @@ impl<Config: endpoint::Config> Manager<Config> {
             new: path_event!(path, new_path_id),
         });
 
+        // A path is only created once per handle so this is emitted once for each new peer address
+        if !s2n_quic_core::path::Handle::eq(&active_remote_addr, &remote_address) {
+            publisher.on_peer_address_changed(event::builder::PeerAddressChanged {
+                old: active_remote_addr.into_event(),
+                new: remote_address.into_event(),
+            });
+        }
+
         publisher.on_mtu_updated(event::builder::MtuUpdated {
             path_id: new_path_id.into_event(),
             mtu: path.mtu_controller.mtu() as u16,
@@ -560,6 +626,7 @@ impl<Config: endpoint::Config> Manager<Config> {
     pub fn on_path_response<Pub: event::ConnectionPublisher>(
         &mut self,
         response: &frame::PathResponse,
+        timestamp: Timestamp,
         publisher: &mut Pub,
     ) -> AmplificationOutcome {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.2.2
@@ -589,6 +656,16 @@ impl<Config: endpoint::Config> Manager<Config> {
                     path: path_event!(path, id),
                     challenge_data: path.challenge.challenge_data().into_event(),
                 });
+                // a response can only be matched after the challenge has been sent
+                if let Some(sent_at) = path.challenge.sent_at() {
+                    publisher.on_path_validation_updated(event::builder::PathValidationUpdated {
+                        status: event::builder::PathChallengeStatus::Validated,
+                        local_addr: path.local_address().into_event(),
+                        remote_addr: path.remote_address().into_event(),
+                        challenge_sent_at: sent_at,
+                        completed_at: timestamp,
+                    });
+                }
                 // A path was validated so check if it becomes the new
                 // last_known_active_validated_path
                 if path.is_activated() {
@@ -356,7 +356,11 @@ fn validate_path_before_challenge_expiration() {
     let frame = s2n_quic_core::frame::PathResponse {
         data: &helper.second_expected_data,
     };
-    let amplification_outcome = helper.manager.on_path_response(&frame, &mut publisher);
+    let amplification_outcome = helper.manager.on_path_response(
+        &frame,
+        helper.now + helper.challenge_expiration - Duration::from_millis(100),
+        &mut publisher,
+    );
 
     // Expectation 2:
     assert!(amplification_outcome.is_inactivate_path_unblocked());
@@ -425,7 +429,11 @@ fn dont_validate_path_if_path_challenge_is_abandoned() {
     let frame = s2n_quic_core::frame::PathResponse {
         data: &helper.second_expected_data,
     };
-    let amplification_outcome = helper.manager.on_path_response(&frame, &mut publisher);
+    let amplification_outcome = helper.manager.on_path_response(
+        &frame,
+        helper.now + helper.challenge_expiration + Duration::from_millis(200),
+        &mut publisher,
+    );
 
     // Expectation 2:
     assert!(amplification_outcome.is_unchanged());
@@ -749,6 +757,66 @@ fn test_adding_new_path() {
     assert_eq!(manager.paths.len(), 2);
 }
 
+#[test]
+fn peer_address_changed_once_per_address() {
+    // Setup:
+    let mut publisher = Publisher::snapshot();
+    let first_addr: SocketAddr = "127.0.0.1:8001".parse().unwrap();
+    let first_addr = RemoteAddress::from(SocketAddress::from(first_addr));
+    let first_path = ServerPath::new(
+        first_addr,
+        connection::PeerId::try_from_bytes(&[1]).unwrap(),
+        connection::LocalId::TEST_ID,
+        RttEstimator::default(),
+        Default::default(),
+        false,
+        DEFAULT_MAX_MTU,
+    );
+    let mut manager = manager_server(first_path);
+
+    let datagram = DatagramInfo {
+        timestamp: NoopClock {}.get_time(),
+        payload_len: 0,
+        ecn: ExplicitCongestionNotification::default(),
+        destination_connection_id: connection::LocalId::TEST_ID,
+        destination_connection_id_classification: connection::id::Classification::Local,
+        source_connection_id: None,
+    };
+
+    let receive = |manager: &mut ServerManager, addr: &str, publisher: &mut Publisher| {
+        let addr: SocketAddr = addr.parse().unwrap();
+        let addr = RemoteAddress::from(SocketAddress::from(addr));
+        let _ = manager
+            .on_datagram_received(
+                &addr,
+                &datagram,
+                true,
+                &mut Default::default(),
+                &mut migration::allow_all::Validator,
+                DEFAULT_MAX_MTU,
+                DEFAULT_INITIAL_RTT,
+                publisher,
+            )
+            .unwrap();
+    };
+
+    // Trigger:
+    receive(&mut manager, "127.0.0.1:8001", &mut publisher);
+    // Expectation:
+    assert_eq!(publisher.peer_address_changed, 0);
+
+    // Trigger:
+    receive(&mut manager, "127.0.0.2:8001", &mut publisher);
+    receive(&mut manager, "127.0.0.2:8001", &mut publisher);
+    // Expectation:
+    assert_eq!(publisher.peer_address_changed, 1);
+
+    // Trigger:
+    receive(&mut manager, "127.0.0.2:8002", &mut publisher);
+    // Expectation:
+    assert_eq!(publisher.peer_address_changed, 2);
+}
+
 #[test]
 // do NOT add new path if handshake is not confirmed
 // Setup:
@@ -1057,6 +1125,148 @@ fn connection_migration_challenge_behavior() {
     assert!(manager[path_id].is_validated());
 }
 
+#[test]
+fn port_only_change_is_migration() {
+    // Setup:
+    let mut publisher = Publisher::no_snapshot();
+    let first_conn_id = connection::PeerId::try_from_bytes(&[1]).unwrap();
+    let first_addr: SocketAddr = "127.0.0.1:8001".parse().unwrap();
+    let first_addr = RemoteAddress::from(SocketAddress::from(first_addr));
+    let first_path = ServerPath::new(
+        first_addr,
+        first_conn_id,
+        connection::LocalId::TEST_ID,
+        RttEstimator::default(),
+        Default::default(),
+        false,
+        DEFAULT_MAX_MTU,
+    );
+    let mut manager = manager_server(first_path);
+
+    // the peer is rebound to a different port on the same IP, e.g. by a NAT
+    let new_addr: SocketAddr = "127.0.0.1:8002".parse().unwrap();
+    let new_addr = RemoteAddress::from(SocketAddress::from(new_addr));
+    assert!(!path::Handle::eq(&first_addr, &new_addr));
+
+    let now = NoopClock {}.get_time();
+    let datagram = DatagramInfo {
+        timestamp: now,
+        payload_len: 0,
+        ecn: ExplicitCongestionNotification::default(),
+        destination_connection_id: connection::LocalId::TEST_ID,
+        destination_connection_id_classification: connection::id::Classification::Local,
+        source_connection_id: None,
+    };
+
+    // Trigger:
+    let (new_path_id, _amplification_outcome) = manager
+        .on_datagram_received(
+            &new_addr,
+            &datagram,
+            true,
+            &mut Default::default(),
+            &mut migration::allow_all::Validator,
+            DEFAULT_MAX_MTU,
+            DEFAULT_INITIAL_RTT,
+            &mut publisher,
+        )
+        .unwrap();
+    let _ = manager.on_processed_packet(
+        new_path_id,
+        None,
+        path_validation::Probe::NonProbing,
+        &mut random::testing::Generator(123),
+        &mut publisher,
+    );
+
+    // Expectation:
+    // the new port should be treated as a separate path rather than the existing one
+    assert_eq!(manager.paths.len(), 2);
+    assert_ne!(new_path_id, path_id(0));
+    assert!(path::Handle::eq(&manager[new_path_id].handle, &new_addr));
+
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-9
+    //= type=test
+    //# An endpoint MUST
+    //# perform path validation (Section 8.2) if it detects any change to a
+    //# peer's address, unless it has previously validated that address.
+    assert!(manager[new_path_id].is_challenge_pending());
+    assert!(!manager[new_path_id].is_validated());
+}
+
+#[test]
+fn disable_active_migration() {
+    // Setup:
+    let mut publisher = Publisher::no_snapshot();
+    let first_conn_id = connection::PeerId::try_from_bytes(&[1]).unwrap();
+    let first_addr: SocketAddr = "127.0.0.1:8001".parse().unwrap();
+    let first_addr = RemoteAddress::from(SocketAddress::from(first_addr));
+    let first_path = ServerPath::new(
+        first_addr,
+        first_conn_id,
+        connection::LocalId::TEST_ID,
+        RttEstimator::default(),
+        Default::default(),
+        false,
+        DEFAULT_MAX_MTU,
+    );
+    let mut manager = manager_server(first_path);
+    manager.set_migration_support(MigrationSupport::Disabled);
+
+    let mut receive = |manager: &mut ServerManager, addr: &str, local_id| {
+        let addr: SocketAddr = addr.parse().unwrap();
+        let addr = RemoteAddress::from(SocketAddress::from(addr));
+        let datagram = DatagramInfo {
+            timestamp: NoopClock {}.get_time(),
+            payload_len: 0,
+            ecn: ExplicitCongestionNotification::default(),
+            destination_connection_id: local_id,
+            destination_connection_id_classification: connection::id::Classification::Local,
+            source_connection_id: None,
+        };
+        manager.on_datagram_received(
+            &addr,
+            &datagram,
+            true,
+            &mut Default::default(),
+            &mut migration::allow_all::Validator,
+            DEFAULT_MAX_MTU,
+            DEFAULT_INITIAL_RTT,
+            &mut publisher,
+        )
+    };
+
+    // Trigger:
+    // the peer actively migrates by switching to a new connection ID on a new address
+    let new_local_id = connection::LocalId::try_from_bytes(b"id000002").unwrap();
+    let result = receive(&mut manager, "127.0.0.2:8001", new_local_id);
+
+    // Expectation:
+    // the packets are dropped rather than validating the new path
+    assert!(matches!(
+        result,
+        Err(DatagramDropReason::RejectedConnectionMigration)
+    ));
+    assert_eq!(manager.paths.len(), 1);
+
+    // Trigger:
+    // the peer's address changes without a new connection ID, e.g. due to NAT rebinding
+    let (new_path_id, _amplification_outcome) =
+        receive(&mut manager, "127.0.0.1:8002", connection::LocalId::TEST_ID).unwrap();
+    let _ = manager.on_processed_packet(
+        new_path_id,
+        None,
+        path_validation::Probe::NonProbing,
+        &mut random::testing::Generator(123),
+        &mut publisher,
+    );
+
+    // Expectation:
+    // the new address is still validated
+    assert_eq!(manager.paths.len(), 2);
+    assert!(manager[new_path_id].is_challenge_pending());
+}
+
 #[test]
 // Abandon timer should use max PTO of active and new path(new path uses kInitialRtt)
 // Setup 1:
@@ -1677,7 +1887,7 @@ fn last_known_validated_path_should_update_on_path_response() {
     let frame = s2n_quic_core::frame::PathResponse {
         data: &first_expected_data,
     };
-    let amplification_outcome = manager.on_path_response(&frame, &mut publisher);
+    let amplification_outcome = manager.on_path_response(&frame, now, &mut publisher);
     // Expectation 1:
     assert!(amplification_outcome.is_inactivate_path_unblocked());
     assert_eq!(manager.active_path_id(), second_path_id);
@@ -12,15 +12,17 @@ use crate::{
     transmission::{self, Mode},
 };
 use s2n_quic_core::{
-    counter::{Counter, Saturating},
     event::{self, IntoEvent},
     frame, packet, random,
     time::{timer, Timestamp},
+    transport::parameters::MaxUdpPayloadSize,
 };
 
+mod amplification;
 mod challenge;
 mod manager;
 
+pub use amplification::AmplificationLimiter;
 pub use challenge::Challenge;
 pub use manager::*;
 
@@ -33,9 +35,7 @@ enum State {
     Validated,
 
     /// Path has not been validated and is subject to amplification limits
-    AmplificationLimited {
-        tx_allowance: Counter<u32, Saturating>,
-    },
+    AmplificationLimited(AmplificationLimiter),
 }
 
 #[derive(Debug)]
@@ -120,9 +120,7 @@ impl<Config: endpoint::Config> Path<Config> {
                 //# If the client IP address has changed, the
                 //# server MUST adhere to the anti-amplification limit; see Section 8.
                 // Start each path in State::AmplificationLimited until it has been validated.
-                State::AmplificationLimited {
-                    tx_allowance: Default::default(),
-                }
+                State::AmplificationLimited(AmplificationLimiter::default())
             }
             //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
             //# Clients are only constrained by the congestion controller.
@@ -190,8 +188,8 @@ impl<Config: endpoint::Config> Path<Config> {
             "path should not transmit when amplification limited; tried to transmit {bytes}"
         );
 
-        if let State::AmplificationLimited { tx_allowance, .. } = &mut self.state {
-            *tx_allowance -= bytes as u32
+        if let State::AmplificationLimited(limiter) = &mut self.state {
+            limiter.on_sent(bytes)
         }
     }
 
@@ -202,19 +200,8 @@ impl<Config: endpoint::Config> Path<Config> {
     pub fn on_bytes_received(&mut self, bytes: usize) -> AmplificationOutcome {
         let was_at_amplification_limit = self.at_amplification_limit();
 
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
-        //# For the purposes of
-        //# avoiding amplification prior to address validation, servers MUST
-        //# count all of the payload bytes received in datagrams that are
-        //# uniquely attributed to a single connection.
-        //
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
-        //# Prior to validating the client address, servers MUST NOT send more
-        //# than three times as many bytes as the number of bytes they have
-        //# received.
-        //
-        if let State::AmplificationLimited { tx_allowance } = &mut self.state {
-            *tx_allowance += bytes.saturating_mul(3) as u32;
+        if let State::AmplificationLimited(limiter) = &mut self.state {
+            limiter.on_received(bytes);
         }
 
         let unblocked = was_at_amplification_limit && !self.at_amplification_limit();
@@ -374,6 +361,25 @@ impl<Config: endpoint::Config> Path<Config> {
         self.state == State::Validated
     }
 
+    /// Returns a snapshot of the addresses and state of the path
+    #[inline]
+    pub fn info(&self) -> PathInfo {
+        let validation_state = if self.is_validated() {
+            ValidationState::Validated
+        } else if self.failed_validation() {
+            ValidationState::Failed
+        } else {
+            ValidationState::Pending
+        };
+
+        PathInfo::new(
+            self.local_address(),
+            self.remote_address(),
+            validation_state,
+            self.is_active(),
+        )
+    }
+
     /// The path received a non-path-validation-probing packet so mark it as activated.
     #[inline]
     pub fn on_activated(&mut self) {
@@ -485,9 +491,7 @@ impl<Config: endpoint::Config> Path<Config> {
 
     /// Returns whether this path should be limited according to connection establishment amplification limits
     ///
-    /// Note: As long as the path has _any_ TX credits we don't consider it to be amplification-limited.
-    ///       This may result in sending slightly more than 3x bytes but networking infrastructure mostly
-    ///       cares about the number of packets rather than bytes.
+    /// See [`AmplificationLimiter::is_blocked`].
     #[inline]
     pub fn at_amplification_limit(&self) -> bool {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
@@ -496,7 +500,18 @@ impl<Config: endpoint::Config> Path<Config> {
         //# received.
         match self.state {
             State::Validated => false,
-            State::AmplificationLimited { tx_allowance } => tx_allowance == 0,
+            State::AmplificationLimited(limiter) => limiter.is_blocked(),
+        }
+    }
+
+    /// Returns the number of bytes that can be sent before the path is amplification limited
+    ///
+    /// `None` is returned if the path is validated and not subject to amplification limits.
+    #[inline]
+    pub fn amplification_budget(&self) -> Option<usize> {
+        match self.state {
+            State::Validated => None,
+            State::AmplificationLimited(limiter) => Some(limiter.remaining_budget()),
         }
     }
 
@@ -509,6 +524,29 @@ impl<Config: endpoint::Config> Path<Config> {
         self.rtt_estimator.pto_period(self.pto_backoff, space)
     }
 
+    /// Limits the size of datagrams sent on the path to the peer's `max_udp_payload_size`
+    #[inline]
+    pub fn on_peer_max_udp_payload_size(&mut self, max_udp_payload_size: MaxUdpPayloadSize) {
+        // the transport parameter is validated to be no more than 65527
+        let max_udp_payload_size = u16::try_from(max_udp_payload_size.as_u64()).unwrap_or(u16::MAX);
+        self.mtu_controller
+            .on_peer_max_udp_payload_size(max_udp_payload_size);
+    }
+
+    /// Returns the length of the closing and draining periods
+    ///
+    /// The period is derived from the current PTO, including any backoff, so it tracks
+    /// changes to the RTT estimate.
+    #[inline]
+    pub fn closing_period(&self) -> core::time::Duration {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-10.2
+        //# The closing and draining connection states exist to ensure that
+        //# connections close cleanly and that delayed or reordered packets are
+        //# properly discarded.  These states SHOULD persist for at least three
+        //# times the current PTO interval as defined in [QUIC-RECOVERY].
+        3 * self.pto_period(s2n_quic_core::packet::number::PacketNumberSpace::ApplicationData)
+    }
+
     /// Resets the PTO backoff to the initial value
     #[inline]
     pub fn reset_pto_backoff(&mut self) {
@@ -632,9 +670,12 @@ mod tests {
     use s2n_quic_core::{
         connection, endpoint,
         event::testing::Publisher,
+        packet::number::PacketNumberSpace,
         recovery::{CongestionController, RttEstimator},
         time::{Clock, NoopClock},
         transmission,
+        transport::parameters::MaxAckDelay,
+        varint::VarInt,
     };
 
     type Path = super::Path<Config>;
@@ -731,6 +772,42 @@ mod tests {
         assert!(path.challenge.is_pending());
     }
 
+    #[test]
+    fn unvalidated_path_should_schedule_path_challenge() {
+        use transmission::interest::Provider as _;
+
+        // Setup:
+        let mut path = testing::helper_path_server();
+        let helper_challenge = helper_challenge();
+
+        // the initial path doesn't require validation so there is nothing to send
+        assert!(!path.has_transmission_interest());
+
+        // Trigger:
+        path.set_challenge(helper_challenge.challenge);
+
+        // Expectation:
+        assert!(!path.is_validated());
+        assert!(path.has_transmission_interest());
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut context = MockWriteContext::new(
+            helper_challenge.now,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Client,
+        );
+        path.on_transmit(&mut context);
+
+        assert_eq!(context.frame_buffer.len(), 1);
+        let written_data = match context.frame_buffer.pop_front().unwrap().as_frame() {
+            frame::Frame::PathChallenge(frame) => Some(*frame.data),
+            _ => None,
+        };
+        assert_eq!(written_data.unwrap(), helper_challenge.expected_data);
+    }
+
     #[test]
     fn first_path_in_disabled_state_cant_fail_validation() {
         // Setup:
@@ -780,6 +857,39 @@ mod tests {
         assert!(path.failed_validation());
     }
 
+    #[test]
+    fn info_validation_state() {
+        let mut path = testing::helper_path_server();
+        path.set_challenge(helper_challenge().challenge);
+        let info = path.info();
+        assert_eq!(info.validation_state, ValidationState::Pending);
+        assert_eq!(info.remote_address, *path.remote_address());
+        assert!(!info.is_active);
+
+        path.on_validated();
+        assert_eq!(path.info().validation_state, ValidationState::Validated);
+
+        let helper_challenge = helper_challenge();
+        let mut path = testing::helper_path_server();
+        path.set_challenge(helper_challenge.challenge);
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut context = MockWriteContext::new(
+            helper_challenge.now,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Client,
+        );
+        path.on_transmit(&mut context);
+        path.on_timeout(
+            helper_challenge.now + helper_challenge.abandon_duration + Duration::from_millis(10),
+            path::Id::test_id(),
+            &mut random::testing::Generator(123),
+            &mut Publisher::no_snapshot(),
+        );
+        assert_eq!(path.info().validation_state, ValidationState::Failed);
+    }
+
     #[test]
     fn abandon_challenge() {
         // Setup:
@@ -961,6 +1071,38 @@ mod tests {
         assert!(path.is_validated());
     }
 
+    #[test]
+    fn amplification_budget_test() {
+        let mut path = testing::helper_path_server();
+        let now = NoopClock.get_time();
+
+        // nothing can be sent to an unvalidated address before anything is received
+        assert_eq!(path.amplification_budget(), Some(0));
+        assert!(!path.can_transmit(now));
+
+        let _ = path.on_bytes_received(100);
+        assert_eq!(path.amplification_budget(), Some(300));
+        assert!(path.can_transmit(now));
+
+        // exhausting the budget blocks sending
+        path.on_bytes_transmitted(300);
+        assert_eq!(path.amplification_budget(), Some(0));
+        assert!(!path.can_transmit(now));
+
+        // receiving more bytes unblocks sending
+        assert!(path.on_bytes_received(10).is_inactivate_path_unblocked());
+        assert_eq!(path.amplification_budget(), Some(30));
+        assert!(path.can_transmit(now));
+
+        path.on_bytes_transmitted(30);
+        assert!(!path.can_transmit(now));
+
+        // validating the address removes the limit
+        path.on_validated();
+        assert_eq!(path.amplification_budget(), None);
+        assert!(path.can_transmit(now));
+    }
+
     #[test]
     fn amplification_limited_mtu_test() {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
@@ -1089,6 +1231,34 @@ mod tests {
         assert_eq!(0, path.clamp_mtu(10000, transmission::Mode::Normal));
     }
 
+    #[test]
+    fn closing_period_test() {
+        let mut path = testing::helper_path_server();
+        let now = NoopClock {}.get_time();
+        let space = PacketNumberSpace::ApplicationData;
+
+        for rtt in [10, 100, 250] {
+            let rtt = Duration::from_millis(rtt);
+            path.rtt_estimator = RttEstimator::new(Duration::from_millis(30));
+            path.rtt_estimator
+                .update_rtt(Duration::ZERO, rtt, now, true, space);
+
+            // with a single sample, smoothed_rtt = rtt and rttvar = rtt / 2, so
+            // PTO = rtt + 4 * rtt / 2 = 3 * rtt
+            assert_eq!(path.pto_period(space), 3 * rtt);
+            assert_eq!(path.closing_period(), 9 * rtt);
+        }
+
+        // the period includes the peer's max_ack_delay and any PTO backoff
+        path.rtt_estimator
+            .on_max_ack_delay(MaxAckDelay::new(VarInt::from_u8(25)).unwrap());
+        path.pto_backoff = 2;
+        assert_eq!(
+            path.closing_period(),
+            3 * 2 * (Duration::from_millis(750) + Duration::from_millis(25))
+        );
+    }
+
     #[test]
     fn peer_validated_test() {
         let mut path = testing::helper_path_client();
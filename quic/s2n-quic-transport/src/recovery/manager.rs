@@ -8,12 +8,13 @@ use crate::{
     recovery::{SentPacketInfo, SentPackets},
     transmission::{self, interest::Provider as _, Provider as _},
 };
-use core::time::Duration;
+use core::{num::NonZeroUsize, time::Duration};
 use s2n_quic_core::{
     event::{self, builder::CongestionSource, IntoEvent},
     frame,
     frame::ack::EcnCounts,
     inet::ExplicitCongestionNotification,
+    interval_set::IntervalSet,
     packet::number::{PacketNumber, PacketNumberRange, PacketNumberSpace},
     recovery::{congestion_controller, persistent_congestion, CongestionController, Pto},
     time::{timer, timer::Provider, Timer, Timestamp},
@@ -66,6 +67,15 @@ pub struct Manager<Config: endpoint::Config> {
     //
     // Used for updating the PTO timer at the end of a transmission burst.
     pto_update_pending: bool,
+
+    // The number of packets that have been declared lost in this packet space
+    lost_count: u64,
+
+    // The packet numbers most recently declared lost, used to detect spurious retransmissions
+    recently_lost_packets: IntervalSet<PacketNumber>,
+
+    // The number of packets declared lost that were later acknowledged by the peer
+    spurious_retransmission_count: u64,
 }
 
 /// Initial capacity of the SmallVec used for keeping track of packets
@@ -73,6 +83,10 @@ pub struct Manager<Config: endpoint::Config> {
 // TODO: Determine if there is a more appropriate default
 const ACKED_PACKETS_INITIAL_CAPACITY: usize = 32;
 
+/// The number of intervals of lost packet numbers to remember for detecting spurious
+/// retransmissions. The oldest losses are forgotten once the limit is reached.
+const RECENTLY_LOST_INTERVALS_LIMIT: usize = 16;
+
 macro_rules! recovery_event {
     ($path_id:ident, $path:ident) => {
         event::builder::RecoveryMetrics {
@@ -124,6 +138,11 @@ impl<Config: endpoint::Config> Manager<Config> {
             baseline_ecn_counts: EcnCounts::default(),
             sent_packet_ecn_counts: EcnCounts::default(),
             pto_update_pending: false,
+            lost_count: 0,
+            recently_lost_packets: IntervalSet::with_limit(
+                NonZeroUsize::new(RECENTLY_LOST_INTERVALS_LIMIT).unwrap(),
+            ),
+            spurious_retransmission_count: 0,
         }
     }
 
@@ -517,6 +536,8 @@ impl<Config: endpoint::Config> Manager<Config> {
             // notify components of packets acked
             context.on_packet_ack(timestamp, &pn_range);
 
+            self.spurious_retransmission_count += self.on_lost_packets_acked(pn_range);
+
             let mut newly_acked_range: Option<(PacketNumber, PacketNumber)> = None;
 
             for (packet_number, acked_packet_info) in self.sent_packets.remove_range(pn_range) {
@@ -782,6 +803,63 @@ impl<Config: endpoint::Config> Manager<Config> {
         self.pto.has_transmission_interest()
     }
 
+    /// Returns the number of packets that have been declared lost in this packet space
+    #[inline]
+    pub fn lost_count(&self) -> u64 {
+        self.lost_count
+    }
+
+    /// Returns the number of packets declared lost in this packet space that were later
+    /// acknowledged by the peer
+    ///
+    /// The frames in these packets were retransmitted even though the originals were delivered.
+    #[inline]
+    pub fn spurious_retransmission_count(&self) -> u64 {
+        self.spurious_retransmission_count
+    }
+
+    /// Remembers a lost packet number so a late acknowledgement of it can be detected
+    fn record_lost_packet(
+        recently_lost_packets: &mut IntervalSet<PacketNumber>,
+        packet_number: PacketNumber,
+    ) {
+        if recently_lost_packets.insert_value(packet_number).is_err() {
+            // the limit was reached so forget the oldest losses to make room
+            recently_lost_packets.pop_min();
+            let _ = recently_lost_packets.insert_value(packet_number);
+        }
+    }
+
+    /// Returns the number of packets in the acknowledged range that were previously declared lost
+    ///
+    /// The packets are forgotten afterwards so they are only counted once, even though the peer
+    /// keeps acknowledging them in subsequent ACK frames.
+    fn on_lost_packets_acked(&mut self, acked: PacketNumberRange) -> u64 {
+        let mut count = 0;
+
+        for lost in self.recently_lost_packets.inclusive_ranges() {
+            let start = (*lost.start()).max(acked.start());
+            let end = (*lost.end()).min(acked.end());
+            if let Some(distance) = end.checked_distance(start) {
+                count += distance + 1;
+            }
+        }
+
+        if count > 0
+            && self
+                .recently_lost_packets
+                .remove(acked.start()..=acked.end())
+                .is_err()
+        {
+            // splitting an interval would exceed the limit so forget everything up to the end of
+            // the range instead, which only ever shrinks the set
+            let pn_zero = self.space.new_packet_number(Default::default());
+            let _ = self.recently_lost_packets.remove(pn_zero..=acked.end());
+        }
+
+        count
+    }
+
     //= https://www.rfc-editor.org/rfc/rfc9002#appendix-B.9
     //# When Initial or Handshake keys are discarded, packets sent in that
     //# space no longer count toward bytes in flight.
@@ -948,6 +1026,8 @@ impl<Config: endpoint::Config> Manager<Config> {
 
         // Remove the lost packets and account for the bytes on the proper congestion controller
         for (packet_number, sent_info) in self.sent_packets.remove_range(lost_packets) {
+            self.lost_count += 1;
+            Self::record_lost_packet(&mut self.recently_lost_packets, packet_number);
             let path = context.path_mut_by_id(sent_info.path_id);
 
             //= https://www.rfc-editor.org/rfc/rfc9002#section-7.6.2
@@ -395,6 +395,7 @@ fn on_ack_frame() {
     assert_eq!(context.on_new_packet_ack_count, 1);
     assert_eq!(context.validate_packet_ack_count, 2);
     assert_eq!(context.on_packet_loss_count, 0);
+    assert_eq!(manager.lost_count(), 0);
     assert_eq!(
         context.path().rtt_estimator.latest_rtt(),
         Duration::from_millis(500)
@@ -421,6 +422,7 @@ fn on_ack_frame() {
     assert_eq!(context.on_new_packet_ack_count, 2);
     assert_eq!(context.validate_packet_ack_count, 3);
     assert_eq!(context.on_packet_loss_count, 3);
+    assert_eq!(manager.lost_count(), 3);
     assert_eq!(
         context.path().rtt_estimator.latest_rtt(),
         Duration::from_millis(2500)
@@ -642,6 +644,86 @@ fn on_packet_loss_called_for_all_lost_packets() {
         });
 }
 
+#[test]
+fn late_ack_of_lost_packets_counts_spurious_retransmissions() {
+    let space = PacketNumberSpace::ApplicationData;
+    let mut manager = ServerManager::new(space);
+    let mut path_manager = helper_generate_path_manager(Duration::from_millis(10));
+    let mut context = MockContext::new(&mut path_manager);
+    let mut publisher = Publisher::no_snapshot();
+    let now = now();
+    let outcome = transmission::Outcome {
+        ack_elicitation: AckElicitation::Eliciting,
+        is_congestion_controlled: true,
+        bytes_sent: 100,
+        bytes_progressed: 0,
+    };
+
+    for (packet_number, time_sent) in [
+        (0, now),
+        (1, now),
+        (2, now),
+        (3, now),
+        (4, now + Duration::from_secs(5)),
+    ] {
+        manager.on_packet_sent(
+            space.new_packet_number(VarInt::from_u8(packet_number)),
+            outcome,
+            time_sent,
+            Default::default(),
+            transmission::Mode::Normal,
+            None,
+            &mut context,
+            &mut publisher,
+        );
+    }
+
+    // acknowledging the last packet much later causes the earlier packets to be declared lost
+    let ack_time = now + Duration::from_secs(10);
+    ack_packets(
+        4..=4,
+        ack_time,
+        &mut context,
+        &mut manager,
+        None,
+        &mut publisher,
+    );
+    assert_eq!(manager.lost_count(), 4);
+    assert_eq!(manager.spurious_retransmission_count(), 0);
+
+    // the peer acknowledges some of the packets after they were already declared lost
+    ack_packets(
+        1..=2,
+        ack_time,
+        &mut context,
+        &mut manager,
+        None,
+        &mut publisher,
+    );
+    assert_eq!(manager.spurious_retransmission_count(), 2);
+
+    // subsequent ACK frames repeat the ranges but the packets are only counted once
+    ack_packets(
+        0..=4,
+        ack_time,
+        &mut context,
+        &mut manager,
+        None,
+        &mut publisher,
+    );
+    assert_eq!(manager.spurious_retransmission_count(), 4);
+    ack_packets(
+        0..=4,
+        ack_time,
+        &mut context,
+        &mut manager,
+        None,
+        &mut publisher,
+    );
+    assert_eq!(manager.spurious_retransmission_count(), 4);
+    assert_eq!(manager.lost_count(), 4);
+}
+
 #[test]
 // pto_backoff reset should happen for the path the packet was sent on
 //
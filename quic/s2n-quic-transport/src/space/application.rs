@@ -10,8 +10,8 @@ use crate::{
     recovery,
     recovery::CongestionController,
     space::{
-        datagram, keep_alive::KeepAlive, CryptoStream, HandshakeStatus, PacketSpace,
-        TxPacketNumbers,
+        datagram, keep_alive::KeepAlive, CryptoStream, HandshakeStatus, NewTokenSender,
+        PacketCounts, PacketSpace, TxPacketNumbers,
     },
     stream::Manager as _,
     sync::flag,
@@ -23,7 +23,7 @@ use once_cell::sync::OnceCell;
 use s2n_codec::EncoderBuffer;
 use s2n_quic_core::{
     counter::{Counter, Saturating},
-    crypto::{application::KeySet, limited, tls, CryptoSuite},
+    crypto::{application::KeySet, limited, tls, CryptoSuite, Key as _},
     event::{self, ConnectionPublisher as _, IntoEvent},
     frame::{
         ack::AckRanges, crypto::CryptoRef, datagram::DatagramRef, stream::StreamRef, Ack,
@@ -72,6 +72,7 @@ pub struct ApplicationSpace<Config: endpoint::Config> {
 
     ping: flag::Ping,
     keep_alive: KeepAlive,
+    new_token: NewTokenSender,
     processed_packet_numbers: SlidingWindow,
     recovery_manager: recovery::Manager<Config>,
     pub datagram_manager: datagram::Manager<Config>,
@@ -119,6 +120,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             header_key,
             ping: flag::Ping::default(),
             keep_alive,
+            new_token: NewTokenSender::default(),
             processed_packet_numbers: SlidingWindow::default(),
             recovery_manager: recovery::Manager::new(PacketNumberSpace::ApplicationData),
             datagram_manager,
@@ -127,6 +129,25 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         }
     }
 
+    /// Returns the number of packets sent and lost in the space
+    pub fn packet_counts(&self) -> PacketCounts {
+        PacketCounts::new(&self.tx_packet_numbers, &self.recovery_manager)
+    }
+
+    /// Returns the largest datagram payload that fits in a single packet on a path with the
+    /// given MTU, or `None` if the peer doesn't accept DATAGRAM frames
+    pub fn max_datagram_size(
+        &self,
+        mtu: usize,
+        destination_connection_id_len: usize,
+    ) -> Option<usize> {
+        let tag_len = self.key_set.active_key().key().tag_len();
+        let packet_capacity =
+            datagram::packet_capacity(mtu, destination_connection_id_len, tag_len);
+
+        self.datagram_manager.max_datagram_size(packet_capacity)
+    }
+
     /// Returns true if the packet number has already been processed
     pub fn is_duplicate<Pub: event::ConnectionPublisher>(
         &self,
@@ -219,6 +240,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
                 &mut self.ack_manager,
                 handshake_status,
                 &mut self.ping,
+                &mut self.new_token,
                 &mut self.stream_manager,
                 &mut self.recovery_manager,
                 &mut self.crypto_stream,
@@ -257,6 +279,17 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         outcome.bytes_progressed +=
             (self.stream_manager.outgoing_bytes_progressed() - bytes_progressed).as_u64() as usize;
 
+        // record the budget before the packet is added to the bytes in flight
+        let congestion_controller = &context.path().congestion_controller;
+        let event = event::builder::TransmissionBudget {
+            path_id: context.path_id.into_event(),
+            congestion_window: congestion_controller.congestion_window(),
+            bytes_in_flight: congestion_controller.bytes_in_flight(),
+            send_quantum: congestion_controller.send_quantum(),
+            bytes_written: outcome.bytes_sent,
+        };
+        context.publisher.on_transmission_budget(event);
+
         self.on_packet_sent(
             context,
             packet_number,
@@ -543,6 +576,12 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         self.ping.send()
     }
 
+    /// Sends the address validation token to the client in a NEW_TOKEN frame
+    pub fn send_new_token(&mut self, token: Vec<u8>) {
+        debug_assert!(Config::ENDPOINT_TYPE.is_server());
+        self.new_token.send(token)
+    }
+
     pub fn keep_alive(&mut self, enabled: bool) {
         self.keep_alive.update(enabled);
     }
@@ -569,6 +608,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
                 crypto_stream: &mut self.crypto_stream,
                 handshake_status,
                 ping: &mut self.ping,
+                new_token: &mut self.new_token,
                 stream_manager: &mut self.stream_manager,
                 local_id_registry,
                 path_id,
@@ -720,6 +760,7 @@ impl<Config: endpoint::Config> transmission::interest::Provider for ApplicationS
     ) -> transmission::interest::Result {
         self.ack_manager.transmission_interest(query)?;
         self.ping.transmission_interest(query)?;
+        self.new_token.transmission_interest(query)?;
         self.crypto_stream.transmission_interest(query)?;
         self.recovery_manager.transmission_interest(query)?;
         self.stream_manager.transmission_interest(query)?;
@@ -739,6 +780,7 @@ struct RecoveryContext<'a, Config: endpoint::Config> {
     handshake_status: &'a mut HandshakeStatus,
     crypto_stream: &'a mut CryptoStream,
     ping: &'a mut flag::Ping,
+    new_token: &'a mut NewTokenSender,
     stream_manager: &'a mut Config::StreamManager,
     local_id_registry: &'a mut connection::LocalIdRegistry,
     path_id: path::Id,
@@ -803,6 +845,7 @@ impl<'a, Config: endpoint::Config> recovery::Context<Config> for RecoveryContext
             .on_packet_ack(packet_number_range, publisher);
         self.crypto_stream.on_packet_ack(packet_number_range);
         self.ping.on_packet_ack(packet_number_range);
+        self.new_token.on_packet_ack(packet_number_range);
         self.stream_manager.on_packet_ack(packet_number_range);
         self.local_id_registry.on_packet_ack(packet_number_range);
         self.path_manager.on_packet_ack(packet_number_range);
@@ -823,6 +866,7 @@ impl<'a, Config: endpoint::Config> recovery::Context<Config> for RecoveryContext
         self.handshake_status
             .on_packet_loss(packet_number_range, publisher);
         self.ping.on_packet_loss(packet_number_range);
+        self.new_token.on_packet_loss(packet_number_range);
         self.stream_manager.on_packet_loss(packet_number_range);
         self.local_id_registry.on_packet_loss(packet_number_range);
         self.path_manager.on_packet_loss(packet_number_range);
@@ -1065,7 +1109,7 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
                 path.rtt_estimator.smoothed_rtt(),
                 datagram.timestamp,
             )
-            .map_err(|err| transport::Error::PROTOCOL_VIOLATION.with_reason(err.message()))
+            .map_err(transport::Error::from)
     }
 
     fn handle_path_challenge_frame(
@@ -1086,7 +1130,7 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
         handshake_status: &mut HandshakeStatus,
         publisher: &mut Pub,
     ) -> Result<(), transport::Error> {
-        let amplification_outcome = path_manager.on_path_response(&frame, publisher);
+        let amplification_outcome = path_manager.on_path_response(&frame, timestamp, publisher);
         if amplification_outcome.is_active_path_unblocked() {
             self.on_amplification_unblocked(
                 path_manager,
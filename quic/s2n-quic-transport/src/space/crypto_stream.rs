@@ -138,3 +138,91 @@ impl transmission::interest::Provider for CryptoStream {
         self.tx.transmission_interest(query)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        contexts::testing::{MockWriteContext, OutgoingFrameBuffer},
+        transmission::interest::Provider as _,
+    };
+    use bytes::Bytes;
+    use s2n_quic_core::{endpoint, frame::Frame, time::clock::testing as time};
+
+    #[test]
+    fn large_crypto_data_spans_multiple_packets() {
+        const MAX_PACKET_SIZE: usize = 1200;
+        // a large certificate chain is several times larger than a single packet
+        let data: Vec<u8> = (0..TX_MAX_BUFFER_CAPACITY).map(|v| v as u8).collect();
+
+        let mut stream = CryptoStream::new();
+        stream.tx.push(Bytes::copy_from_slice(&data));
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(MAX_PACKET_SIZE));
+        let mut context = MockWriteContext::new(
+            time::now(),
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+
+        let mut packets = 0;
+        while stream.has_transmission_interest() {
+            stream.tx.on_transmit((), &mut context).unwrap();
+            context.frame_buffer.flush();
+            packets += 1;
+            assert!(packets <= data.len(), "crypto data was never fully sent");
+        }
+        assert!(packets > 1);
+
+        let mut received = vec![];
+        let mut packet_numbers = vec![];
+        for frame in &mut frame_buffer.frames {
+            packet_numbers.push(frame.packet_nr);
+            match frame.as_frame() {
+                Frame::Crypto(frame) => {
+                    // each fragment must start where the previous one ended
+                    assert_eq!(frame.offset.as_u64(), received.len() as u64);
+                    received.extend_from_slice(frame.data.as_less_safe_slice());
+                }
+                frame => panic!("unexpected frame {frame:?}"),
+            }
+        }
+
+        assert_eq!(received, data);
+        packet_numbers.dedup();
+        assert_eq!(packet_numbers.len(), packets);
+    }
+
+    #[test]
+    fn out_of_order_crypto_frames_are_reassembled() {
+        const CHUNK_LEN: usize = 500;
+        let data: Vec<u8> = (0..3000).map(|v| v as u8).collect();
+
+        let mut stream = CryptoStream::new();
+
+        // deliver the fragments in reverse offset order
+        for (index, chunk) in data.chunks(CHUNK_LEN).enumerate().rev() {
+            let frame = CryptoRef {
+                offset: VarInt::try_from(index * CHUNK_LEN).unwrap(),
+                data: chunk,
+            };
+            stream.on_crypto_frame(frame).unwrap();
+
+            if index > 0 {
+                // the data is held until the first fragment fills in the gap
+                assert!(stream.rx.is_empty());
+                assert_eq!(stream.rx.len(), 0);
+            }
+        }
+
+        assert_eq!(stream.rx.len(), data.len());
+        assert_eq!(stream.rx.total_received_len(), data.len() as u64);
+
+        let received: Vec<u8> = stream.rx.drain().flatten().collect();
+        assert_eq!(received, data);
+        assert!(stream.rx.is_empty());
+    }
+}
@@ -14,6 +14,7 @@ use s2n_codec::EncoderValue;
 use s2n_quic_core::{
     datagram::{Endpoint, ReceiveContext, Receiver, Sender, WriteError},
     frame::{self, datagram::DatagramRef},
+    packet::number::PacketNumberLen,
     query,
     varint::VarInt,
 };
@@ -41,6 +42,13 @@ impl<Config: endpoint::Config> Manager<Config> {
         }
     }
 
+    /// Returns the largest datagram payload that fits in a packet with the given capacity
+    ///
+    /// `None` is returned if the peer didn't advertise support for DATAGRAM frames.
+    pub fn max_datagram_size(&self, packet_capacity: usize) -> Option<usize> {
+        max_datagram_size(self.max_datagram_payload, packet_capacity)
+    }
+
     /// A callback that allows users to write datagrams directly to the packet.
     pub fn on_transmit<W: WriteContext>(
         &mut self,
@@ -95,6 +103,32 @@ impl<Config: endpoint::Config> interest::Provider for Manager<Config> {
     }
 }
 
+/// Returns the room left for frames in a short header packet on a path with the given MTU
+pub fn packet_capacity(mtu: usize, destination_connection_id_len: usize, tag_len: usize) -> usize {
+    // the short header is made up of the first byte, the destination connection ID and at
+    // most 4 bytes of packet number
+    let header_len = 1 + destination_connection_id_len + PacketNumberLen::MAX_LEN;
+    mtu.saturating_sub(header_len + tag_len)
+}
+
+/// Returns the largest datagram payload that fits in a packet with the given capacity
+///
+/// `None` is returned if the peer didn't advertise support for DATAGRAM frames.
+pub fn max_datagram_size(max_datagram_payload: u64, packet_capacity: usize) -> Option<usize> {
+    if max_datagram_payload == 0 {
+        return None;
+    }
+
+    // Remove the frame type length and the maximum length value
+    let frame_overhead = frame::datagram::DATAGRAM_TAG.encoding_size()
+        + VarInt::new(packet_capacity as u64)
+            .unwrap_or(VarInt::MAX)
+            .encoding_size();
+    let packet_limit = packet_capacity.saturating_sub(frame_overhead);
+
+    Some(packet_limit.min(max_datagram_payload.try_into().unwrap_or(usize::MAX)))
+}
+
 struct Packet<'a, C: WriteContext> {
     context: &'a mut C,
     has_pending_streams: bool,
@@ -151,3 +185,25 @@ impl<'a, C: WriteContext> s2n_quic_core::datagram::Packet for Packet<'a, C> {
         self.datagrams_prioritized
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_datagram_size_test() {
+        // the peer doesn't accept DATAGRAM frames
+        assert_eq!(max_datagram_size(0, 1200), None);
+
+        // the frame type and a 2 byte length are subtracted from the packet capacity
+        assert_eq!(max_datagram_size(u64::MAX, 1200), Some(1197));
+        assert_eq!(max_datagram_size(65535, 1200), Some(1197));
+
+        // the peer's limit is smaller than the packet
+        assert_eq!(max_datagram_size(500, 1200), Some(500));
+
+        // a length that fits in a single byte
+        assert_eq!(max_datagram_size(u64::MAX, 50), Some(48));
+        assert_eq!(max_datagram_size(u64::MAX, 1), Some(0));
+    }
+}
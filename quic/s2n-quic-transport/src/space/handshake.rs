@@ -8,7 +8,7 @@ use crate::{
     path::{path_event, Path},
     processed_packet::ProcessedPacket,
     recovery,
-    space::{CryptoStream, HandshakeStatus, PacketSpace, TxPacketNumbers},
+    space::{CryptoStream, HandshakeStatus, PacketCounts, PacketSpace, TxPacketNumbers},
     transmission,
 };
 use core::{fmt, marker::PhantomData};
@@ -74,6 +74,11 @@ impl<Config: endpoint::Config> HandshakeSpace<Config> {
         }
     }
 
+    /// Returns the number of packets sent and lost in the space
+    pub fn packet_counts(&self) -> PacketCounts {
+        PacketCounts::new(&self.tx_packet_numbers, &self.recovery_manager)
+    }
+
     /// Returns true if the packet number has already been processed
     pub fn is_duplicate<Pub: event::ConnectionPublisher>(
         &self,
@@ -8,7 +8,7 @@ use crate::{
     path::{path_event, Path},
     processed_packet::ProcessedPacket,
     recovery,
-    space::{CryptoStream, HandshakeStatus, PacketSpace, TxPacketNumbers},
+    space::{CryptoStream, HandshakeStatus, PacketCounts, PacketSpace, TxPacketNumbers},
     transmission,
 };
 use core::{fmt, marker::PhantomData};
@@ -83,6 +83,19 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
         }
     }
 
+    /// Returns the number of packets sent and lost in the space
+    pub fn packet_counts(&self) -> PacketCounts {
+        PacketCounts::new(&self.tx_packet_numbers, &self.recovery_manager)
+    }
+
+    /// Sets the address validation token included in the client's Initial packets
+    ///
+    /// The token is replaced by the one in a Retry packet, if the server sends one.
+    pub fn set_token(&mut self, token: Vec<u8>) {
+        debug_assert!(Config::ENDPOINT_TYPE.is_client());
+        self.retry_token = token;
+    }
+
     /// This method gets called when a Retry packet is processed.
     ///
     /// Reset the TLS stack and recover state when the first Retry packet is processed.
@@ -7,19 +7,20 @@ use crate::{
     connection, endpoint, path,
     path::{path_event, Path},
     processed_packet::ProcessedPacket,
+    recovery,
     stream::Manager as _,
     transmission,
 };
 use bytes::Bytes;
 use core::{
     fmt,
-    ops::RangeInclusive,
+    ops::{AddAssign, RangeInclusive},
     task::{Poll, Waker},
 };
 use s2n_codec::DecoderBufferMut;
 use s2n_quic_core::{
     application::ServerName,
-    connection::{limits::Limits, InitialId, PeerId},
+    connection::{limits::Limits, InitialId, PeerId, PeerTransportParameters},
     crypto::{tls, tls::Session, CryptoSuite, Key},
     event::{self, IntoEvent},
     frame::{
@@ -42,6 +43,7 @@ mod handshake;
 mod handshake_status;
 mod initial;
 mod keep_alive;
+mod new_token;
 mod session_context;
 mod tx_packet_numbers;
 
@@ -50,6 +52,7 @@ pub(crate) use crypto_stream::CryptoStream;
 pub(crate) use handshake::HandshakeSpace;
 pub(crate) use handshake_status::HandshakeStatus;
 pub(crate) use initial::InitialSpace;
+pub(crate) use new_token::NewTokenSender;
 pub(crate) use session_context::SessionContext;
 pub(crate) use tx_packet_numbers::TxPacketNumbers;
 
@@ -77,6 +80,12 @@ pub struct PacketSpaceManager<Config: endpoint::Config> {
     //# another mechanism is used for agreeing on an application protocol,
     //# endpoints MUST use ALPN for this purpose.
     pub application_protocol: Bytes,
+    /// The transport parameters received from the peer
+    pub peer_transport_parameters: Option<PeerTransportParameters>,
+    /// The address validation token to send to the client once the handshake is confirmed
+    new_token: Option<Vec<u8>>,
+    /// The packet counts of the spaces that have been discarded
+    discarded_packet_counts: PacketCounts,
 }
 
 impl<Config: endpoint::Config> fmt::Debug for PacketSpaceManager<Config> {
@@ -90,6 +99,36 @@ impl<Config: endpoint::Config> fmt::Debug for PacketSpaceManager<Config> {
     }
 }
 
+/// The number of packets sent and lost in one or more packet spaces
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PacketCounts {
+    pub sent: u64,
+    pub lost: u64,
+    /// The number of lost packets that were later acknowledged by the peer
+    pub spurious_retransmissions: u64,
+}
+
+impl PacketCounts {
+    pub fn new<Config: endpoint::Config>(
+        tx_packet_numbers: &TxPacketNumbers,
+        recovery_manager: &recovery::Manager<Config>,
+    ) -> Self {
+        Self {
+            sent: tx_packet_numbers.sent_count(),
+            lost: recovery_manager.lost_count(),
+            spurious_retransmissions: recovery_manager.spurious_retransmission_count(),
+        }
+    }
+}
+
+impl AddAssign for PacketCounts {
+    fn add_assign(&mut self, rhs: Self) {
+        self.sent += rhs.sent;
+        self.lost += rhs.lost;
+        self.spurious_retransmissions += rhs.spurious_retransmissions;
+    }
+}
+
 macro_rules! packet_space_api {
     ($ty:ty, $field:ident, $get_mut:ident) => {
         #[allow(dead_code)]
@@ -137,15 +176,39 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             handshake_status: HandshakeStatus::default(),
             server_name: None,
             application_protocol: Bytes::new(),
+            peer_transport_parameters: None,
+            new_token: None,
+            discarded_packet_counts: PacketCounts::default(),
         }
     }
 
+    /// Sets the address validation token to send to the client in a NEW_TOKEN frame
+    pub fn set_new_token(&mut self, token: Vec<u8>) {
+        debug_assert!(Config::ENDPOINT_TYPE.is_server());
+        self.new_token = Some(token);
+    }
+
     packet_space_api!(InitialSpace<Config>, initial, initial_mut);
 
     packet_space_api!(HandshakeSpace<Config>, handshake, handshake_mut);
 
     packet_space_api!(ApplicationSpace<Config>, application, application_mut);
 
+    /// Returns the packet counts across all of the packet spaces, including discarded ones
+    pub fn packet_counts(&self) -> PacketCounts {
+        let mut counts = self.discarded_packet_counts;
+        if let Some(space) = self.initial() {
+            counts += space.packet_counts();
+        }
+        if let Some(space) = self.handshake() {
+            counts += space.packet_counts();
+        }
+        if let Some(space) = self.application() {
+            counts += space.packet_counts();
+        }
+        counts
+    }
+
     #[allow(dead_code)] // 0RTT hasn't been started yet
     pub fn zero_rtt_crypto(
         &self,
@@ -169,6 +232,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             path_manager.active_path_mut().reset_pto_backoff();
             let path_id = path_manager.active_path_id();
             space.on_discard(path_manager.active_path_mut(), path_id, publisher);
+            self.discarded_packet_counts += space.packet_counts();
 
             if let Some((handshake, handshake_status)) = self.handshake_mut() {
                 //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
@@ -215,6 +279,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             path_manager.active_path_mut().reset_pto_backoff();
             let path_id = path_manager.active_path_id();
             space.on_discard(path_manager.active_path_mut(), path_id, publisher);
+            self.discarded_packet_counts += space.packet_counts();
             // Dropping handshake will clear the PTO timer for the handshake space.
             // The PTO timer for the application space is reset when the
             // handshake is confirmed.
@@ -264,6 +329,8 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 limits,
                 server_name: &mut self.server_name,
                 application_protocol: &mut self.application_protocol,
+                peer_transport_parameters: &mut self.peer_transport_parameters,
+                new_token: &mut self.new_token,
                 waker,
                 publisher,
                 datagram,
@@ -310,6 +377,8 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 limits,
                 server_name: &mut self.server_name,
                 application_protocol: &mut self.application_protocol,
+                peer_transport_parameters: &mut self.peer_transport_parameters,
+                new_token: &mut self.new_token,
                 waker,
                 publisher,
                 datagram,
@@ -455,6 +524,27 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             .any(|requires_probe| requires_probe)
     }
 
+    /// Returns `true` if any of the packet spaces has run out of packet numbers
+    pub fn is_packet_number_exhausted(&self) -> bool {
+        core::iter::empty()
+            .chain(
+                self.initial
+                    .iter()
+                    .map(|space| space.tx_packet_numbers.is_exhausted()),
+            )
+            .chain(
+                self.handshake
+                    .iter()
+                    .map(|space| space.tx_packet_numbers.is_exhausted()),
+            )
+            .chain(
+                self.application
+                    .iter()
+                    .map(|space| space.tx_packet_numbers.is_exhausted()),
+            )
+            .any(|is_exhausted| is_exhausted)
+    }
+
     pub fn is_handshake_confirmed(&self) -> bool {
         self.handshake_status.is_confirmed()
     }
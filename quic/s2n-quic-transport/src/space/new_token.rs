@@ -0,0 +1,173 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Delivers an address validation token to the client in a NEW_TOKEN frame
+
+use crate::{contexts::WriteContext, transmission};
+use alloc::vec::Vec;
+use s2n_quic_core::{ack, frame::NewToken, packet::number::PacketNumber};
+
+/// Sends a single NEW_TOKEN frame to the peer
+///
+/// Unlike flag frames, the token is only retransmitted when the packet carrying it is declared
+/// lost, since passively including it in every packet would waste a significant amount of space.
+#[derive(Debug, Default)]
+pub struct NewTokenSender {
+    state: State,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum State {
+    /// No token has been requested to be sent
+    #[default]
+    Idle,
+
+    /// The token needs to be transmitted
+    RequiresTransmission(Vec<u8>),
+
+    /// The token was lost and needs to be retransmitted
+    RequiresRetransmission(Vec<u8>),
+
+    /// The token has been transmitted and is pending acknowledgement
+    InFlight {
+        token: Vec<u8>,
+        packet_number: PacketNumber,
+    },
+
+    /// The token has been delivered
+    Delivered,
+}
+
+impl NewTokenSender {
+    /// Starts sending the token to the peer
+    ///
+    /// Only the first token is sent; subsequent calls are ignored.
+    pub fn send(&mut self, token: Vec<u8>) {
+        if matches!(self.state, State::Idle) {
+            self.state = State::RequiresTransmission(token);
+        }
+    }
+
+    /// This method gets called when a packet delivery got acknowledged
+    pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) {
+        if let State::InFlight { packet_number, .. } = &self.state {
+            if ack_set.contains(*packet_number) {
+                self.state = State::Delivered;
+            }
+        }
+    }
+
+    /// This method gets called when a packet loss is reported
+    pub fn on_packet_loss<A: ack::Set>(&mut self, ack_set: &A) {
+        if let State::InFlight {
+            token,
+            packet_number,
+        } = &mut self.state
+        {
+            if ack_set.contains(*packet_number) {
+                let token = core::mem::take(token);
+                self.state = State::RequiresRetransmission(token);
+            }
+        }
+    }
+
+    /// Queries the component for any outgoing frames that need to get sent
+    pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
+        let constraint = context.transmission_constraint();
+        let token = match &mut self.state {
+            State::RequiresTransmission(token) if constraint.can_transmit() => token,
+            State::RequiresRetransmission(token) if constraint.can_retransmit() => token,
+            _ => return,
+        };
+
+        if let Some(packet_number) = context.write_frame(&NewToken { token }) {
+            let token = core::mem::take(token);
+            self.state = State::InFlight {
+                token,
+                packet_number,
+            };
+        }
+    }
+}
+
+impl transmission::interest::Provider for NewTokenSender {
+    #[inline]
+    fn transmission_interest<Q: transmission::interest::Query>(
+        &self,
+        query: &mut Q,
+    ) -> transmission::interest::Result {
+        match &self.state {
+            State::RequiresTransmission(_) => query.on_new_data(),
+            State::RequiresRetransmission(_) => query.on_lost_data(),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{contexts::testing::*, transmission::interest::Provider};
+    use s2n_quic_core::{endpoint, frame::Frame, time::clock::testing as time};
+
+    #[test]
+    fn new_token_test() {
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut context = MockWriteContext::new(
+            time::now(),
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+
+        let mut sender = NewTokenSender::default();
+        assert!(!sender.has_transmission_interest());
+
+        sender.send(vec![1, 2, 3]);
+        assert_eq!(
+            sender.get_transmission_interest(),
+            transmission::Interest::NewData
+        );
+
+        // only the first token is sent
+        sender.send(vec![4, 5, 6]);
+
+        context.transmission_constraint = transmission::Constraint::CongestionLimited;
+        sender.on_transmit(&mut context);
+        assert!(context.frame_buffer.is_empty());
+
+        context.transmission_constraint = transmission::Constraint::None;
+        sender.on_transmit(&mut context);
+
+        let mut written = context.frame_buffer.pop_front().unwrap();
+        let packet_number = written.packet_nr;
+        match written.as_frame() {
+            Frame::NewToken(frame) => assert_eq!(frame.token, &[1, 2, 3]),
+            frame => panic!("unexpected frame {frame:?}"),
+        }
+        assert!(!sender.has_transmission_interest());
+
+        // the token isn't passively retransmitted while in flight
+        sender.on_transmit(&mut context);
+        assert!(context.frame_buffer.is_empty());
+
+        sender.on_packet_loss(&packet_number);
+        assert_eq!(
+            sender.get_transmission_interest(),
+            transmission::Interest::LostData
+        );
+
+        sender.on_transmit(&mut context);
+        let mut written = context.frame_buffer.pop_front().unwrap();
+        let packet_number = written.packet_nr;
+        match written.as_frame() {
+            Frame::NewToken(frame) => assert_eq!(frame.token, &[1, 2, 3]),
+            frame => panic!("unexpected frame {frame:?}"),
+        }
+
+        sender.on_packet_ack(&packet_number);
+        assert_eq!(sender.state, State::Delivered);
+        assert!(!sender.has_transmission_interest());
+    }
+}
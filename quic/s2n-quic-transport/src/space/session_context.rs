@@ -17,7 +17,7 @@ use s2n_codec::{DecoderBuffer, DecoderValue};
 use s2n_quic_core::{
     ack,
     application::ServerName,
-    connection::{InitialId, PeerId},
+    connection::{InitialId, PeerId, PeerTransportParameters},
     crypto,
     crypto::{tls, CryptoSuite, Key},
     ct::ConstantTimeEq,
@@ -52,6 +52,8 @@ pub struct SessionContext<'a, Config: endpoint::Config, Pub: event::ConnectionPu
     pub limits: &'a mut Limits,
     pub server_name: &'a mut Option<ServerName>,
     pub application_protocol: &'a mut Bytes,
+    pub peer_transport_parameters: &'a mut Option<PeerTransportParameters>,
+    pub new_token: &'a mut Option<Vec<u8>>,
     pub waker: &'a Waker,
     pub publisher: &'a mut Pub,
     pub datagram: &'a mut Config::DatagramEndpoint,
@@ -178,6 +180,9 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
 
         // Load the peer's transport parameters into the connection's limits
         self.limits.load_peer(&peer_parameters);
+        *self.peer_transport_parameters = Some((&peer_parameters).into());
+        self.path_manager
+            .on_peer_max_udp_payload_size(peer_parameters.max_udp_payload_size);
 
         let initial_flow_control_limits = peer_parameters.flow_control_limits();
         let active_connection_id_limit = peer_parameters.active_connection_id_limit;
@@ -236,6 +241,9 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
 
         // Load the peer's transport parameters into the connection's limits
         self.limits.load_peer(&peer_parameters);
+        *self.peer_transport_parameters = Some((&peer_parameters).into());
+        self.path_manager
+            .on_peer_max_udp_payload_size(peer_parameters.max_udp_payload_size);
 
         let initial_flow_control_limits = peer_parameters.flow_control_limits();
         let active_connection_id_limit = peer_parameters.active_connection_id_limit;
@@ -400,14 +408,22 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             self.limits.max_keep_alive_period(),
         );
 
-        let conn_info =
-            ConnectionInfo::new(datagram_limits.max_datagram_payload, self.waker.clone());
-        let (datagram_sender, datagram_receiver) = self.datagram.create_connection(&conn_info);
-        let datagram_manager = datagram::Manager::new(
-            datagram_sender,
-            datagram_receiver,
-            datagram_limits.max_datagram_payload,
+        // Datagrams that don't fit in a packet at the largest UDP payload the path will use can
+        // never be sent, so the sender is given the smaller of that and the peer's limit
+        let path = self.path_manager.active_path();
+        let packet_capacity = datagram::packet_capacity(
+            path.mtu_controller.max_udp_payload() as usize,
+            path.peer_connection_id.len(),
+            key.tag_len(),
         );
+        let max_datagram_payload =
+            datagram::max_datagram_size(datagram_limits.max_datagram_payload, packet_capacity)
+                .map_or(0, |size| size as u64);
+
+        let conn_info = ConnectionInfo::new(max_datagram_payload, self.waker.clone());
+        let (datagram_sender, datagram_receiver) = self.datagram.create_connection(&conn_info);
+        let datagram_manager =
+            datagram::Manager::new(datagram_sender, datagram_receiver, max_datagram_payload);
 
         self.path_manager
             .active_path_mut()
@@ -509,6 +525,10 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
                     self.local_id_registry,
                     self.now,
                 );
+
+                if let Some(token) = self.new_token.take() {
+                    application.send_new_token(token);
+                }
             }
             Ok(())
         } else {
@@ -16,6 +16,10 @@ pub struct TxPacketNumbers {
     next: PacketNumber,
     // skipped packet number used for detecting an Optimistic Ack attack
     skip_packet_number: Option<PacketNumber>,
+    // the number of packets transmitted in the packet number space
+    sent_count: u64,
+    // the largest packet number transmitted in the packet number space
+    largest_sent: Option<PacketNumber>,
 }
 
 impl TxPacketNumbers {
@@ -25,6 +29,8 @@ impl TxPacketNumbers {
             largest_sent_acked: (initial_packet_number, now),
             next: initial_packet_number,
             skip_packet_number: None,
+            sent_count: 0,
+            largest_sent: None,
         }
     }
 
@@ -123,9 +129,20 @@ impl TxPacketNumbers {
 
         // Assuming a constant TX rate of 10,000 packets/second, it would take
         // (2^62 - 1)packets / 10,000packets-per-second  ~= 4.6e14seconds = ~14,000,000years to overflow.
-        // It's probably OK to just panic as there will be other things to worry about at that point.
+        // Regardless, `Transmission` refuses to write a packet with the largest packet number and
+        // the connection is closed once the space is exhausted so this can't overflow.
 
         self.next = packet_number.next().expect("packet number overflowed");
+
+        self.sent_count += 1;
+        self.largest_sent = self.largest_sent.max(Some(packet_number));
+    }
+
+    /// Called when a packet is dropped before it is transmitted with a given packet number
+    ///
+    /// The packet number is skipped without being counted as sent.
+    pub fn on_drop(&mut self, packet_number: PacketNumber) {
+        self.next = packet_number.next().expect("packet number overflowed");
     }
 
     /// Returns the next packet number in the sequence
@@ -133,6 +150,25 @@ impl TxPacketNumbers {
         self.next
     }
 
+    /// Returns `true` if no more packets can be sent in the packet number space
+    ///
+    /// The next packet number is the largest one, which can't be followed by another.
+    pub fn is_exhausted(&self) -> bool {
+        self.next.next().is_none()
+    }
+
+    /// Returns the number of packets transmitted in the packet number space
+    pub fn sent_count(&self) -> u64 {
+        self.sent_count
+    }
+
+    /// Returns the largest packet number transmitted in the packet number space,
+    /// or `None` if nothing has been sent yet
+    #[allow(dead_code)] // not read by the transport yet; the value is included in `Debug` output
+    pub fn largest_sent(&self) -> Option<PacketNumber> {
+        self.largest_sent
+    }
+
     /// Returns the largest packet number that the endpoint sent that
     /// was ACKed by the peer
     pub fn largest_sent_packet_number_acked(&self) -> PacketNumber {
@@ -203,4 +239,72 @@ mod tests {
         assert!(tx.on_packet_ack(timestamp, &ack_set, skip_plus_one).is_ok());
         assert!(tx.skip_packet_number.is_none());
     }
+
+    #[test]
+    fn test_sent_accounting() {
+        let timestamp = unsafe { Timestamp::from_duration(Duration::from_millis(10)) };
+        let mut tx = TxPacketNumbers::new(PacketNumberSpace::Handshake, timestamp);
+
+        assert_eq!(tx.sent_count(), 0);
+        assert_eq!(tx.largest_sent(), None);
+
+        for (count, pn) in [0u8, 1, 3, 4].into_iter().enumerate() {
+            let packet_number = PacketNumberSpace::Handshake.new_packet_number(VarInt::from_u8(pn));
+            tx.on_transmit(packet_number);
+
+            assert_eq!(tx.sent_count(), count as u64 + 1);
+            assert_eq!(tx.largest_sent(), Some(packet_number));
+        }
+    }
+
+    #[test]
+    fn test_dropped_packets() {
+        use s2n_quic_core::{
+            event::{testing::Publisher, ConnectionPublisher},
+            packet::interceptor::{DropEveryN, Interceptor, Packet},
+        };
+
+        let timestamp = unsafe { Timestamp::from_duration(Duration::from_millis(10)) };
+        let mut tx = TxPacketNumbers::new(PacketNumberSpace::ApplicationData, timestamp);
+        let mut interceptor = DropEveryN::new(2);
+        let subject = Publisher::no_snapshot().subject();
+
+        for _ in 0..6 {
+            let packet = Packet {
+                number: tx.next(),
+                timestamp,
+            };
+            if interceptor.intercept_tx_packet(&subject, &packet) {
+                tx.on_transmit(packet.number);
+            } else {
+                tx.on_drop(packet.number);
+            }
+        }
+
+        let pn = |pn| PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(pn));
+
+        // packets 1, 3 and 5 were dropped and are not counted as sent
+        assert_eq!(tx.sent_count(), 3);
+        assert_eq!(tx.largest_sent(), Some(pn(4)));
+        // the dropped packet numbers are never reused
+        assert_eq!(tx.next(), pn(6));
+    }
+
+    #[test]
+    fn test_exhausted() {
+        let timestamp = unsafe { Timestamp::from_duration(Duration::from_millis(10)) };
+        let mut tx = TxPacketNumbers::new(PacketNumberSpace::ApplicationData, timestamp);
+        assert!(!tx.is_exhausted());
+
+        let pn = |pn| PacketNumberSpace::ApplicationData.new_packet_number(pn);
+
+        // the largest packet number can still be the next one
+        tx.on_transmit(pn(VarInt::MAX - VarInt::from_u8(2)));
+        assert!(!tx.is_exhausted());
+
+        // sending the packet before the largest packet number exhausts the space
+        tx.on_transmit(tx.next());
+        assert_eq!(tx.next(), pn(VarInt::MAX));
+        assert!(tx.is_exhausted());
+    }
 }
@@ -13,7 +13,7 @@ use core::{
 };
 pub use s2n_quic_core::{
     application,
-    stream::{ops, StreamError, StreamId, StreamType},
+    stream::{ops, Priority, StreamError, StreamId, StreamType},
 };
 
 #[derive(Clone)]
@@ -9,10 +9,10 @@ use crate::{
     transmission,
 };
 use alloc::rc::Rc;
-use core::cell::RefCell;
+use core::{cell::RefCell, time::Duration};
 use s2n_quic_core::{
-    ack, frame::max_data::MaxData, packet::number::PacketNumber, stream::StreamId, transport,
-    varint::VarInt,
+    ack, frame::max_data::MaxData, packet::number::PacketNumber, stream::StreamId, time::Timestamp,
+    transport, varint::VarInt,
 };
 
 /// Writes `MAX_DATA` frames based on the connections flow control window.
@@ -46,10 +46,74 @@ struct IncomingConnectionFlowControllerImpl {
     /// The amount of flow control credits which had been acquired and where the
     /// data had already been consumed by the application
     pub(super) consumed_window: VarInt,
+    /// Grows the desired flow control window based on the consumption rate
+    pub(super) tuner: WindowTuner,
+}
+
+/// Auto-tunes the connection receive window
+///
+/// The consumption rate is measured over epochs which end once the application has
+/// consumed a full window of data. If that happens in less than two round trips, the
+/// window is limiting the throughput of the connection and is grown by the configured
+/// factor, up to the maximum window.
+#[derive(Debug)]
+pub(super) struct WindowTuner {
+    /// The largest window the tuner will grow the desired window to
+    max_window: u32,
+    /// The factor by which the window is multiplied when it is grown
+    growth_factor: u8,
+    /// The latest smoothed RTT of the connection
+    rtt: Duration,
+    /// The time and consumed window at the start of the current measurement epoch
+    epoch: Option<(Timestamp, VarInt)>,
+}
+
+impl WindowTuner {
+    /// Returns the new desired window if it should be grown
+    fn on_transmit(
+        &mut self,
+        now: Timestamp,
+        consumed_window: VarInt,
+        desired_window: u32,
+    ) -> Option<u32> {
+        if desired_window >= self.max_window {
+            return None;
+        }
+
+        let (start, start_consumed) = match self.epoch {
+            Some(epoch) => epoch,
+            None => {
+                self.epoch = Some((now, consumed_window));
+                return None;
+            }
+        };
+
+        // wait until a full window has been consumed before making a decision
+        if consumed_window - start_consumed < VarInt::from_u32(desired_window) {
+            return None;
+        }
+
+        self.epoch = Some((now, consumed_window));
+
+        if now.saturating_duration_since(start) >= self.rtt * 2 {
+            return None;
+        }
+
+        let window =
+            (desired_window as u64 * self.growth_factor as u64).min(self.max_window as u64) as u32;
+
+        Some(window)
+    }
 }
 
 impl IncomingConnectionFlowControllerImpl {
-    pub fn new(initial_window_size: VarInt, desired_flow_control_window: u32) -> Self {
+    pub fn new(
+        initial_window_size: VarInt,
+        desired_flow_control_window: u32,
+        max_flow_control_window: u32,
+        growth_factor: u8,
+        rtt: Duration,
+    ) -> Self {
         Self {
             read_window_sync: IncrementalValueSync::new(
                 VarInt::from_u32(desired_flow_control_window),
@@ -59,6 +123,12 @@ impl IncomingConnectionFlowControllerImpl {
             desired_flow_control_window,
             acquired_window: VarInt::from_u32(0),
             consumed_window: VarInt::from_u32(0),
+            tuner: WindowTuner {
+                max_window: max_flow_control_window,
+                growth_factor,
+                rtt,
+                epoch: None,
+            },
         }
     }
 
@@ -105,8 +175,24 @@ impl IncomingConnectionFlowControllerImpl {
         self.read_window_sync.on_packet_loss(ack_set)
     }
 
+    pub fn on_rtt_update(&mut self, rtt: Duration) {
+        self.tuner.rtt = rtt;
+    }
+
     #[inline]
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
+        if let Some(window) = self.tuner.on_transmit(
+            context.current_time(),
+            self.consumed_window,
+            self.desired_flow_control_window,
+        ) {
+            self.desired_flow_control_window = window;
+            self.read_window_sync.update_latest_value(
+                self.consumed_window
+                    .saturating_add(VarInt::from_u32(self.desired_flow_control_window)),
+            );
+        }
+
         // Stream ID does not matter here, since it does not get transmitted
         self.read_window_sync
             .on_transmit(StreamId::from_varint(VarInt::from_u32(0)), context)
@@ -134,11 +220,24 @@ impl IncomingConnectionFlowController {
     /// `desired_flow_control_window`. This means if the window which is indicated
     /// to the peer is lower than this value the new value will be communicated
     /// to the peer.
-    pub fn new(initial_window_size: VarInt, desired_flow_control_window: u32) -> Self {
+    ///
+    /// If the application consumes a full window in less than two round trips,
+    /// the desired window is multiplied by `growth_factor`, up to
+    /// `max_flow_control_window`.
+    pub fn new(
+        initial_window_size: VarInt,
+        desired_flow_control_window: u32,
+        max_flow_control_window: u32,
+        growth_factor: u8,
+        rtt: Duration,
+    ) -> Self {
         Self {
             inner: Rc::new(RefCell::new(IncomingConnectionFlowControllerImpl::new(
                 initial_window_size,
                 desired_flow_control_window,
+                max_flow_control_window,
+                growth_factor,
+                rtt,
             ))),
         }
     }
@@ -157,6 +256,11 @@ impl IncomingConnectionFlowController {
         self.inner.borrow_mut().release_window(amount)
     }
 
+    /// Updates the RTT used to measure the consumption rate of the window
+    pub fn on_rtt_update(&mut self, rtt: Duration) {
+        self.inner.borrow_mut().on_rtt_update(rtt)
+    }
+
     /// This method gets called when a packet delivery got acknowledged
     pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) {
         self.inner.borrow_mut().on_packet_ack(ack_set)
@@ -211,3 +315,113 @@ impl transmission::interest::Provider for IncomingConnectionFlowController {
             .transmission_interest(query)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contexts::testing::{MockWriteContext, OutgoingFrameBuffer};
+    use s2n_quic_core::{endpoint, frame::Frame, time::clock::testing as time};
+
+    const INITIAL_WINDOW: u32 = 1000;
+    const MAX_WINDOW: u32 = 6000;
+    const RTT: Duration = Duration::from_millis(100);
+
+    /// Consumes a window worth of data and transmits the resulting MAX_DATA frame,
+    /// returning the advertised value
+    fn consume_window(
+        controller: &mut IncomingConnectionFlowController,
+        now: Timestamp,
+        amount: u32,
+    ) -> VarInt {
+        let amount = VarInt::from_u32(amount);
+        controller.acquire_window(amount).unwrap();
+        controller.release_window(amount);
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut context = MockWriteContext::new(
+            now,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+        let packet_number = context.packet_number();
+        controller.on_transmit(&mut context).unwrap();
+        controller.on_packet_ack(&packet_number);
+
+        let mut frame = frame_buffer.pop_front().expect("MAX_DATA should be sent");
+        match frame.as_frame() {
+            Frame::MaxData(frame) => frame.maximum_data,
+            frame => panic!("unexpected frame {frame:?}"),
+        }
+    }
+
+    #[test]
+    fn fast_consumption_grows_window_test() {
+        let mut controller = IncomingConnectionFlowController::new(
+            VarInt::from_u32(INITIAL_WINDOW),
+            INITIAL_WINDOW,
+            MAX_WINDOW,
+            2,
+            RTT,
+        );
+        let mut now = time::now();
+        let mut consumed = 0;
+        let mut windows = vec![];
+
+        // the first transmission starts the measurement epoch
+        for _ in 0..6 {
+            let window = controller.desired_flow_control_window();
+            consumed += window;
+            let max_data = consume_window(&mut controller, now, window);
+            windows.push(controller.desired_flow_control_window());
+            assert_eq!(
+                max_data,
+                VarInt::from_u32(consumed + controller.desired_flow_control_window())
+            );
+            // consume each window well within 2 RTTs
+            now += RTT / 2;
+        }
+
+        // the window should double on each epoch until it's capped
+        assert_eq!(windows, [1000, 2000, 4000, 6000, 6000, 6000]);
+    }
+
+    #[test]
+    fn slow_consumption_keeps_window_test() {
+        let mut controller = IncomingConnectionFlowController::new(
+            VarInt::from_u32(INITIAL_WINDOW),
+            INITIAL_WINDOW,
+            MAX_WINDOW,
+            2,
+            RTT,
+        );
+        let mut now = time::now();
+
+        for _ in 0..6 {
+            consume_window(&mut controller, now, INITIAL_WINDOW);
+            // the application is reading slower than the window allows
+            now += RTT * 3;
+        }
+
+        assert_eq!(controller.desired_flow_control_window(), INITIAL_WINDOW);
+    }
+
+    #[test]
+    fn tuning_disabled_test() {
+        let mut controller = IncomingConnectionFlowController::new(
+            VarInt::from_u32(INITIAL_WINDOW),
+            INITIAL_WINDOW,
+            INITIAL_WINDOW,
+            2,
+            RTT,
+        );
+        let now = time::now();
+
+        for _ in 0..6 {
+            consume_window(&mut controller, now, INITIAL_WINDOW);
+        }
+
+        assert_eq!(controller.desired_flow_control_window(), INITIAL_WINDOW);
+    }
+}
@@ -31,7 +31,7 @@ use s2n_quic_core::{
         StopSending, StreamDataBlocked, StreamsBlocked,
     },
     packet::number::PacketNumberSpace,
-    stream::{iter::StreamIter, ops, StreamId, StreamType},
+    stream::{iter::StreamIter, ops, Priority, StreamId, StreamType},
     time::{timer, Timestamp},
     transport::{self, parameters::InitialFlowControlLimits},
     varint::VarInt,
@@ -207,7 +207,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
     ///
     /// This method does not perform any validation whether it is allowed to
     /// open the `Stream`.
-    fn insert_stream(&mut self, stream_id: StreamId) {
+    fn insert_stream(&mut self, stream_id: StreamId, priority: Priority) {
         // The receive window is announced by us towards to the peer
         let initial_receive_window = self
             .initial_local_limits
@@ -238,16 +238,24 @@ impl<S: StreamTrait> StreamManagerState<S> {
             "Receive window must not exceed 32bit range"
         );
 
-        self.streams.insert_stream(S::new(StreamConfig {
-            incoming_connection_flow_controller: self.incoming_connection_flow_controller.clone(),
-            outgoing_connection_flow_controller: self.outgoing_connection_flow_controller.clone(),
-            local_endpoint_type: self.local_endpoint_type,
-            stream_id,
-            initial_receive_window,
-            desired_flow_control_window: initial_receive_window.as_u64() as u32,
-            initial_send_window,
-            max_send_buffer_size: self.stream_limits.max_send_buffer_size.as_u32(),
-        }));
+        self.streams.insert_stream(
+            S::new(StreamConfig {
+                incoming_connection_flow_controller: self
+                    .incoming_connection_flow_controller
+                    .clone(),
+                outgoing_connection_flow_controller: self
+                    .outgoing_connection_flow_controller
+                    .clone(),
+                local_endpoint_type: self.local_endpoint_type,
+                stream_id,
+                initial_receive_window,
+                desired_flow_control_window: initial_receive_window.as_u64() as u32,
+                initial_send_window,
+                max_send_buffer_size: self.stream_limits.max_send_buffer_size.as_u32(),
+                send_buffer_low_watermark: self.stream_limits.send_buffer_low_watermark.as_u32(),
+            }),
+            priority,
+        );
     }
 
     /// Opens a Stream which is referenced in a frame if it has not yet been
@@ -297,7 +305,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
                 //# numbered stream IDs MUST be created.  This ensures that the creation
                 //# order for streams is consistent on both endpoints.
                 for stream_id in stream_iter {
-                    self.insert_stream(stream_id);
+                    self.insert_stream(stream_id, Priority::default());
                 }
 
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-2.1
@@ -336,6 +344,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
     fn poll_open_local_stream(
         &mut self,
         stream_type: StreamType,
+        priority: Priority,
         open_token: &mut connection::OpenToken,
         context: &Context,
     ) -> Poll<Result<StreamId, connection::Error>> {
@@ -357,7 +366,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
         // returns Pending if there is no capacity available
         ready!(poll_open);
 
-        self.insert_stream(first_unopened_id);
+        self.insert_stream(first_unopened_id, priority);
         Poll::Ready(Ok(first_unopened_id))
     }
 
@@ -582,6 +591,12 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
                 incoming_connection_flow_controller: IncomingConnectionFlowController::new(
                     initial_local_limits.max_data,
                     initial_local_limits.max_data.as_u64() as u32,
+                    connection_limits
+                        .max_data_window()
+                        .max(initial_local_limits.max_data)
+                        .as_u64() as u32,
+                    connection_limits.data_window_growth_factor(),
+                    min_rtt,
                 ),
                 outgoing_connection_flow_controller: OutgoingConnectionFlowController::new(
                     initial_peer_limits.max_data,
@@ -619,6 +634,28 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
             .acquired_window()
     }
 
+    fn connection_send_window(&self) -> VarInt {
+        self.inner
+            .outgoing_connection_flow_controller
+            .total_window()
+    }
+
+    fn stream_send_window(&self, stream_id: StreamId) -> Option<VarInt> {
+        self.inner
+            .streams
+            .with_stream_ref(stream_id, |stream| stream.send_window())?
+    }
+
+    fn stream_receive_window(&self, stream_id: StreamId) -> Option<VarInt> {
+        self.inner
+            .streams
+            .with_stream_ref(stream_id, |stream| stream.receive_window())?
+    }
+
+    fn blocked_streams(&self) -> Vec<StreamId> {
+        self.inner.streams.stream_flow_credits_list_ids().collect()
+    }
+
     fn poll_accept(
         &mut self,
         stream_type: Option<StreamType>,
@@ -675,6 +712,7 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
     fn poll_open_local_stream(
         &mut self,
         stream_type: StreamType,
+        priority: Priority,
         open_token: &mut connection::OpenToken,
         api_call_context: &mut ConnectionApiCallContext,
         context: &Context,
@@ -689,7 +727,7 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
         let first_unopened_id =
             ready!(self
                 .inner
-                .poll_open_local_stream(stream_type, open_token, context))?;
+                .poll_open_local_stream(stream_type, priority, open_token, context))?;
 
         // Increase the next utilized Stream ID
         *self
@@ -752,6 +790,10 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
     }
 
     fn on_rtt_update(&mut self, rtt_estimator: &RttEstimator, now: Timestamp) {
+        self.inner
+            .incoming_connection_flow_controller
+            .on_rtt_update(rtt_estimator.smoothed_rtt());
+
         {
             let new_min_rtt = rtt_estimator.min_rtt();
             if new_min_rtt != self.last_min_rtt {
@@ -877,6 +919,29 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
                     }
                 },
             );
+
+            transmit_result?;
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.4
+        //# An endpoint SHOULD include new data in packets that are sent on PTO
+        //# expiration.  Previously sent data MAY be sent if no new data can be
+        //# sent.
+        // If none of the streams had new data to send in a probe packet, ask all of the streams
+        // to include their already transmitted, unacknowledged data. Streams which only have data
+        // in flight are not part of the transmission lists, so they need to be queried directly.
+        if context.transmission_mode().is_loss_recovery_probing()
+            && !context.ack_elicitation().is_ack_eliciting()
+        {
+            self.inner.streams.iterate_streams(
+                &mut self.inner.stream_controller,
+                |stream: &mut S| {
+                    // stop writing once the packet is full
+                    if transmit_result.is_ok() {
+                        transmit_result = stream.on_transmit(context);
+                    }
+                },
+            );
         }
 
         // There is no `finalize_done_streams` here, since we do not expect to
@@ -303,6 +303,18 @@ impl StreamTrait for MockStream {
         self.on_connection_window_available_retrieve_window -= Into::<u64>::into(acquired_window);
     }
 
+    fn send_window(&self) -> Option<VarInt> {
+        let window = self
+            .last_max_stream_data
+            .map(|frame| frame.maximum_stream_data)
+            .unwrap_or(self.config.initial_send_window);
+        Some(window)
+    }
+
+    fn receive_window(&self) -> Option<VarInt> {
+        Some(self.config.initial_receive_window)
+    }
+
     fn poll_request(
         &mut self,
         request: &mut ops::Request,
@@ -413,6 +425,14 @@ fn create_stream_manager(local_ep_type: endpoint::Type) -> AbstractStreamManager
 fn try_open(
     manager: &mut AbstractStreamManager<MockStream>,
     stream_type: StreamType,
+) -> Result<StreamId, connection::Error> {
+    try_open_with_priority(manager, stream_type, Priority::default())
+}
+
+fn try_open_with_priority(
+    manager: &mut AbstractStreamManager<MockStream>,
+    stream_type: StreamType,
+    priority: Priority,
 ) -> Result<StreamId, connection::Error> {
     let (accept_waker, _accept_wake_counter) = new_count_waker();
     let (_wakeup_queue, wakeup_handle) = create_wakeup_queue_and_handle();
@@ -420,6 +440,7 @@ fn try_open(
 
     match manager.poll_open_local_stream(
         stream_type,
+        priority,
         &mut token,
         &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
         &Context::from_waker(&accept_waker),
@@ -652,6 +673,62 @@ fn max_data_replenishes_connection_flow_control_window() {
     }
 }
 
+#[test]
+fn flow_control_windows_reflect_max_data_and_max_stream_data() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let initial_limits = create_default_initial_flow_control_limits();
+
+    assert_eq!(initial_limits.max_data, manager.connection_send_window());
+    let window = initial_limits.max_data + 1000;
+    assert!(manager
+        .on_max_data(MaxData {
+            maximum_data: window,
+        })
+        .is_ok());
+    assert_eq!(window, manager.connection_send_window());
+
+    let stream_id = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let initial_send_window = manager.stream_send_window(stream_id).unwrap();
+    let window = initial_send_window + 1000;
+    assert!(manager
+        .on_max_stream_data(&MaxStreamData {
+            stream_id: stream_id.into(),
+            maximum_stream_data: window,
+        })
+        .is_ok());
+    assert_eq!(Some(window), manager.stream_send_window(stream_id));
+    assert!(manager.stream_receive_window(stream_id).is_some());
+
+    // Streams which are not open have no windows
+    let unknown_stream_id = stream_id.next_of_type().unwrap();
+    assert_eq!(None, manager.stream_send_window(unknown_stream_id));
+    assert_eq!(None, manager.stream_receive_window(unknown_stream_id));
+}
+
+#[test]
+fn blocked_streams_reflect_stream_flow_control_interest() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    assert!(manager.blocked_streams().is_empty());
+
+    let blocked_id = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let ready_id = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+
+    manager.with_asserted_stream(blocked_id, |stream| {
+        stream.interests.stream_flow_control_credits = true;
+    });
+    // streams blocked on the connection window are not blocked on MAX_STREAM_DATA
+    manager.with_asserted_stream(ready_id, |stream| {
+        stream.interests.connection_flow_control_credits = true;
+    });
+    assert_eq!(vec![blocked_id], manager.blocked_streams());
+
+    // the stream is no longer reported once the peer raises the limit
+    manager.with_asserted_stream(blocked_id, |stream| {
+        stream.interests.stream_flow_control_credits = false;
+    });
+    assert!(manager.blocked_streams().is_empty());
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-4.6
 //= type=test
 //# MAX_STREAMS frames that do not increase the stream limit MUST be ignored.
@@ -700,6 +777,7 @@ fn max_streams_replenishes_stream_control_capacity() {
         assert!(manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker)
@@ -727,6 +805,7 @@ fn max_streams_replenishes_stream_control_capacity() {
         assert!(manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker)
@@ -852,6 +931,7 @@ fn send_streams_blocked_frame_when_blocked_by_peer() {
         while manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker),
@@ -955,6 +1035,7 @@ fn send_streams_blocked_frame_when_blocked_by_peer() {
         while manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker),
@@ -999,6 +1080,7 @@ fn streams_blocked_period() {
             while manager
                 .poll_open_local_stream(
                     stream_type,
+                    Priority::default(),
                     &mut token,
                     &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                     &Context::from_waker(&waker),
@@ -1274,6 +1356,7 @@ fn blocked_on_local_concurrent_stream_limit() {
             assert!(manager
                 .poll_open_local_stream(
                     stream_type,
+                    Priority::default(),
                     &mut token,
                     &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                     &Context::from_waker(&waker)
@@ -1287,6 +1370,7 @@ fn blocked_on_local_concurrent_stream_limit() {
         assert!(manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker)
@@ -1351,6 +1435,7 @@ fn blocked_on_local_concurrent_stream_limit() {
         assert!(manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker)
@@ -1360,6 +1445,7 @@ fn blocked_on_local_concurrent_stream_limit() {
         assert!(manager
             .poll_open_local_stream(
                 stream_type,
+                Priority::default(),
                 &mut token,
                 &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                 &Context::from_waker(&waker)
@@ -1489,6 +1575,7 @@ fn asymmetric_stream_limits_local_initiated() {
                 for _ in 0..local_available_limit {
                     let result = manager.poll_open_local_stream(
                         stream_type,
+                        Priority::default(),
                         &mut token,
                         &mut ConnectionApiCallContext::from_wakeup_handle(&wakeup_handle),
                         &Context::from_waker(&accept_waker),
@@ -2647,6 +2734,130 @@ fn on_transmit_queries_streams_for_data() {
     assert!(manager.streams_waiting_for_transmission().is_empty());
 }
 
+#[test]
+fn on_transmit_queries_streams_in_priority_order() {
+    fn assert_stream_write_state(
+        manager: &mut AbstractStreamManager<MockStream>,
+        stream_id: StreamId,
+        expected_on_transmit_count: usize,
+        expected_on_transmit_try_write: usize,
+    ) {
+        manager.with_asserted_stream(stream_id, |stream| {
+            assert_eq!(expected_on_transmit_count, stream.on_transmit_count);
+            assert_eq!(
+                expected_on_transmit_try_write,
+                stream.on_transmit_try_write_frames
+            );
+        });
+    }
+
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+
+    // The low priority stream is opened and has data queued before the others are opened
+    let low =
+        try_open_with_priority(&mut manager, StreamType::Bidirectional, Priority::LOWEST).unwrap();
+    manager.with_asserted_stream(low, |stream| {
+        stream.on_transmit_try_write_frames = 10;
+    });
+
+    let default = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let high =
+        try_open_with_priority(&mut manager, StreamType::Bidirectional, Priority::HIGHEST).unwrap();
+    for stream_id in [default, high] {
+        manager.with_asserted_stream(stream_id, |stream| {
+            stream.on_transmit_try_write_frames = 10;
+        });
+    }
+
+    assert_eq!(
+        [high, default, low],
+        *manager.streams_waiting_for_transmission()
+    );
+
+    // leave room for the empty STREAM frame for the open notify
+    frame_buffer.set_error_write_after_n_frames(16);
+    let mut write_context = MockWriteContext::new(
+        time::now(),
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+
+    assert_eq!(
+        Err(OnTransmitError::CouldNotWriteFrame),
+        manager.on_transmit(&mut write_context)
+    );
+
+    // The high priority stream is scheduled first even though it was opened last
+    assert_stream_write_state(&mut manager, high, 1, 0);
+    assert_stream_write_state(&mut manager, default, 1, 5);
+    assert_stream_write_state(&mut manager, low, 0, 10);
+
+    // The interrupted stream resumes before the lower priority stream
+    frame_buffer.clear();
+    assert_eq!([default, low], *manager.streams_waiting_for_transmission());
+
+    // A stream which is ready again is placed ahead of the lower priority streams
+    manager.with_asserted_stream(high, |stream| {
+        stream.on_transmit_try_write_frames = 10;
+    });
+    assert_eq!(
+        [high, default, low],
+        *manager.streams_waiting_for_transmission()
+    );
+}
+
+#[test]
+fn probe_queries_all_streams_without_new_data() {
+    fn on_transmit_count(
+        manager: &mut AbstractStreamManager<MockStream>,
+        stream_id: StreamId,
+    ) -> usize {
+        let mut count = 0;
+        manager.with_asserted_stream(stream_id, |stream| count = stream.on_transmit_count);
+        count
+    }
+
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let stream_2 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    assert!(manager.streams_waiting_for_transmission().is_empty());
+
+    let mut write_context = MockWriteContext::new(
+        time::now(),
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+
+    // streams without transmission interest are not queried in normal transmissions
+    assert!(manager.on_transmit(&mut write_context).is_ok());
+    assert_eq!(0, on_transmit_count(&mut manager, stream_1));
+    assert_eq!(0, on_transmit_count(&mut manager, stream_2));
+    // clear the empty STREAM frame for the open notify
+    write_context.frame_buffer.clear();
+
+    // if no stream has new data, probes query all of the streams for unacked data
+    write_context.transmission_mode = transmission::Mode::LossRecoveryProbing;
+    assert!(manager.on_transmit(&mut write_context).is_ok());
+    assert_eq!(1, on_transmit_count(&mut manager, stream_1));
+    assert_eq!(1, on_transmit_count(&mut manager, stream_2));
+
+    // if a stream wrote new data, the other streams are not queried
+    manager.with_asserted_stream(stream_1, |stream| {
+        stream.on_transmit_try_write_frames = 1;
+    });
+    assert!(manager.on_transmit(&mut write_context).is_ok());
+    assert_eq!(2, on_transmit_count(&mut manager, stream_1));
+    assert_eq!(1, on_transmit_count(&mut manager, stream_2));
+    assert_eq!(1, frame_buffer.len());
+}
+
 fn invalid_stream_id(local_ep_type: endpoint::Type) -> StreamId {
     StreamId::nth(local_ep_type, StreamType::Bidirectional, 100_000).unwrap()
 }
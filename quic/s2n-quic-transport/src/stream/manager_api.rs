@@ -18,7 +18,7 @@ use s2n_quic_core::{
         stream::StreamRef, DataBlocked, MaxData, MaxStreamData, MaxStreams, ResetStream,
         StopSending, StreamDataBlocked, StreamsBlocked,
     },
-    stream::{ops, StreamId, StreamType},
+    stream::{ops, Priority, StreamId, StreamType},
     time::{timer, Timestamp},
     transport::{self, parameters::InitialFlowControlLimits},
     varint::VarInt,
@@ -47,6 +47,23 @@ pub trait Manager:
     /// The number of bytes of forward progress the local endpoint has made on outgoing streams
     fn outgoing_bytes_progressed(&self) -> VarInt;
 
+    /// The connection flow control limit the peer has granted for outgoing data
+    fn connection_send_window(&self) -> VarInt;
+
+    /// The flow control limit the peer has granted for outgoing data on the given stream
+    ///
+    /// Returns `None` if the stream is not open or has no sending side.
+    fn stream_send_window(&self, stream_id: StreamId) -> Option<VarInt>;
+
+    /// The flow control limit advertised to the peer for incoming data on the given stream
+    ///
+    /// Returns `None` if the stream is not open or has no receiving side.
+    fn stream_receive_window(&self, stream_id: StreamId) -> Option<VarInt>;
+
+    /// The streams which are blocked from sending by the flow control limit the peer has
+    /// granted via `MAX_STREAM_DATA`
+    fn blocked_streams(&self) -> Vec<StreamId>;
+
     /// Accepts the next incoming stream of a given type
     fn poll_accept(
         &mut self,
@@ -55,9 +72,12 @@ pub trait Manager:
     ) -> Poll<Result<Option<StreamId>, connection::Error>>;
 
     /// Opens the next local initiated stream of a certain type
+    ///
+    /// The stream is assigned the given priority before it can be scheduled for transmission.
     fn poll_open_local_stream(
         &mut self,
         stream_type: StreamType,
+        priority: Priority,
         open_token: &mut connection::OpenToken,
         api_call_context: &mut ConnectionApiCallContext,
         context: &Context,
@@ -318,7 +318,6 @@ impl ReceiveStreamFlowController {
 
     /// Returns the MAX_STREAM_DATA window that is currently synchronized
     /// towards the peer.
-    #[cfg(test)]
     pub(super) fn current_stream_receive_window(&self) -> VarInt {
         self.read_window_sync.latest_value()
     }
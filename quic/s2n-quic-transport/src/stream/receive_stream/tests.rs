@@ -775,6 +775,44 @@ fn receiving_data_will_lead_to_a_stream_flow_control_window_update() {
     );
 }
 
+#[test]
+fn receive_window_reflects_max_stream_data_updates() {
+    let mut test_env = setup_receive_only_test_env();
+    let initial_window = VarInt::new(TestEnvironment::DEFAULT_INITIAL_RECEIVE_WINDOW).unwrap();
+    assert_eq!(Some(initial_window), test_env.stream.receive_window());
+
+    // Draining the window leads to a MAX_STREAM_DATA update, which is
+    // reflected in the advertised window
+    test_env.feed_data(VarInt::from_u32(0), initial_window.as_u64() as usize);
+    test_env.consume_all_data();
+    let expected_window = initial_window
+        + VarInt::from_u32(
+            test_env
+                .stream
+                .receive_stream
+                .flow_controller
+                .desired_flow_control_window,
+        );
+    assert_eq!(Some(expected_window), test_env.stream.receive_window());
+
+    test_env.assert_write_frames(1);
+    let mut sent_frame = test_env.sent_frames.pop_front().expect("Frame is written");
+    assert_eq!(
+        Frame::MaxStreamData(MaxStreamData {
+            stream_id: test_env.stream.stream_id.into(),
+            maximum_stream_data: expected_window,
+        }),
+        sent_frame.as_frame()
+    );
+
+    // Streams without a receiving side do not have a receive window
+    let mut test_env_config = TestEnvironmentConfig::new(endpoint::Type::Server);
+    test_env_config.stream_id =
+        StreamId::initial(endpoint::Type::Server, StreamType::Unidirectional);
+    let test_env = setup_stream_test_env_with_config(test_env_config);
+    assert_eq!(None, test_env.stream.receive_window());
+}
+
 #[test]
 fn receiving_data_will_lead_to_a_connection_flow_control_window_update() {
     let test_env_config = conn_flow_control_test_env_config();
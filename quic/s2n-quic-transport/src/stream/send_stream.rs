@@ -215,6 +215,11 @@ impl StreamFlowController {
         }
     }
 
+    /// Returns the `MAXIMUM_STREAM_DATA` value which was communicated by a peer
+    pub fn max_stream_data(&self) -> VarInt {
+        self.max_stream_data
+    }
+
     /// Updates the `MAXIMUM_STREAM_DATA` value which was communicated by a peer
     pub fn set_max_stream_data(&mut self, max_stream_data: VarInt) {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-4.1
@@ -457,6 +462,11 @@ pub struct SendStream {
     final_state_observed: bool,
     /// Marks the stream as detached from the application
     detached: bool,
+    /// The amount of buffered data at which the application may resume writing after the
+    /// send buffer filled up
+    low_watermark: usize,
+    /// Set when the send buffer filled up and cleared once it drains to the low watermark
+    is_write_paused: bool,
 }
 
 impl SendStream {
@@ -465,6 +475,7 @@ impl SendStream {
         is_closed: bool,
         initial_window: VarInt,
         max_buffer_capacity: u32,
+        low_watermark: u32,
     ) -> SendStream {
         // If the stream is created in closed state directly move into the
         // terminal state.
@@ -485,6 +496,9 @@ impl SendStream {
             write_waiter: None,
             final_state_observed: is_closed,
             detached: is_closed,
+            // a watermark above the capacity would mean the buffer is never considered full
+            low_watermark: low_watermark.min(max_buffer_capacity) as usize,
+            is_write_paused: false,
         };
 
         if is_closed {
@@ -922,10 +936,18 @@ impl SendStream {
     }
 
     /// Returns true if the caller can push additional data
-    fn can_push(&self) -> bool {
-        // We accept the data if there is at least 1 byte of space
+    fn can_push(&mut self) -> bool {
+        if self.data_sender.available_buffer_space() == 0 {
+            // The buffer reached its capacity so the application needs to wait until the
+            // buffer drains to the low watermark.
+            self.is_write_paused = true;
+        } else if self.is_write_paused {
+            self.is_write_paused = self.data_sender.enqueued_len() > self.low_watermark;
+        }
+
+        // Otherwise we accept the data if there is at least 1 byte of space
         // available in the flow control window.
-        self.data_sender.available_buffer_space() > 0
+        !self.is_write_paused
     }
 
     /// Ensures a potential push operation would be valid
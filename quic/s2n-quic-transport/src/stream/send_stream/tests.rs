@@ -30,6 +30,40 @@ fn setup_send_only_test_env() -> TestEnvironment {
     setup_stream_test_env_with_config(test_env_config)
 }
 
+#[test]
+fn send_window_reflects_max_stream_data() {
+    let mut test_env = setup_stream_test_env();
+    let initial_window = VarInt::new(TestEnvironment::DEFAULT_INITIAL_SEND_WINDOW).unwrap();
+    assert_eq!(Some(initial_window), test_env.stream.send_window());
+
+    for (max_stream_data, expected_window) in [
+        (initial_window + 1000, initial_window + 1000),
+        // MAX_STREAM_DATA frames which do not increase the limit are ignored
+        (initial_window, initial_window + 1000),
+    ] {
+        let mut events = StreamEvents::new();
+        assert!(test_env
+            .stream
+            .on_max_stream_data(
+                &MaxStreamData {
+                    stream_id: test_env.stream.stream_id.into(),
+                    maximum_stream_data: max_stream_data,
+                },
+                &mut events
+            )
+            .is_ok());
+        assert_eq!(Some(expected_window), test_env.stream.send_window());
+    }
+
+    // Streams without a sending side do not have a send window
+    let test_env_config = TestEnvironmentConfig {
+        stream_id: StreamId::initial(endpoint::Type::Client, StreamType::Unidirectional),
+        ..Default::default()
+    };
+    let test_env = setup_stream_test_env_with_config(test_env_config);
+    assert_eq!(None, test_env.stream.send_window());
+}
+
 #[test]
 fn remotely_initiated_unidirectional_stream_can_not_be_sent_to() {
     for local_endpoint_type in &[endpoint::Type::Client, endpoint::Type::Server] {
@@ -413,6 +447,42 @@ fn can_not_enqueue_data_if_max_buffer_size_has_been_reached() {
     }
 }
 
+#[test]
+fn writes_resume_after_buffer_drains_below_low_watermark() {
+    const MAX_BUFFER_SIZE: usize = 1024;
+    const LOW_WATERMARK: usize = 256;
+    const RECEIVE_WINDOW: u64 = 8 * 1024;
+
+    let instructions = &[
+        // Fill the buffer up to the high watermark
+        Instruction::EnqueueData(VarInt::from_u32(0), MAX_BUFFER_SIZE, true),
+        Instruction::EnqueueData(VarInt::from_u32(MAX_BUFFER_SIZE as u32), 1, false),
+        Instruction::CheckDataTx(VarInt::from_u32(0), 498, false, true, pn(0)),
+        Instruction::CheckDataTx(VarInt::from_u32(498), 496, false, true, pn(1)),
+        Instruction::CheckDataTx(VarInt::from_u32(994), 30, false, false, pn(2)),
+        // Space is available again but the buffer is still above the low watermark
+        Instruction::AckPacket(pn(0), ExpectWakeup(Some(false))),
+        Instruction::EnqueueData(VarInt::from_u32(MAX_BUFFER_SIZE as u32), 1, false),
+        // Draining to the low watermark wakes up the application
+        Instruction::AckPacket(pn(1), ExpectWakeup(Some(true))),
+        Instruction::EnqueueData(VarInt::from_u32(MAX_BUFFER_SIZE as u32), 512, true),
+        Instruction::EnqueueData(VarInt::from_u32(MAX_BUFFER_SIZE as u32 + 512), 512, true),
+        // The buffer holds 30 + 1024 bytes, which pauses writing again
+        Instruction::EnqueueData(VarInt::from_u32(2 * MAX_BUFFER_SIZE as u32), 1, false),
+    ];
+
+    let test_env_config = TestEnvironmentConfig {
+        max_send_buffer_size: MAX_BUFFER_SIZE,
+        send_buffer_low_watermark: LOW_WATERMARK,
+        initial_send_window: RECEIVE_WINDOW,
+        max_packet_size: Some(500),
+        ..Default::default()
+    };
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    execute_instructions(&mut test_env, instructions);
+}
+
 #[test]
 fn zero_sized_buffers_can_always_be_enqueued() {
     const MAX_BUFFER_SIZE: usize = 1024;
@@ -791,6 +861,34 @@ fn only_lost_reset_is_sent_when_constrained_to_retransmission_only() {
     execute_instructions(&mut test_env, test_config);
 }
 
+#[test]
+fn reset_without_enqueued_data_transmits_reset_frame() {
+    let error_code = ApplicationErrorCode::new(1).unwrap();
+
+    let test_env_config = TestEnvironmentConfig {
+        stream_id: StreamId::initial(endpoint::Type::Client, StreamType::Unidirectional),
+        local_endpoint_type: endpoint::Type::Client,
+        ..Default::default()
+    };
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    execute_instructions(
+        &mut test_env,
+        &[
+            Instruction::CheckInterests(stream_interests(&[])),
+            // The reset alone should express interest in transmission
+            Instruction::Reset(error_code, true),
+            Instruction::CheckInterests(stream_interests(&["tx"])),
+            // The RESET_STREAM frame carries a final size of 0
+            Instruction::CheckResetTx(error_code, pn(0), VarInt::from_u32(0)),
+            Instruction::CheckNoTx,
+            Instruction::CheckInterests(stream_interests(&["ack"])),
+            Instruction::AckPacket(pn(0), ExpectWakeup(Some(false))),
+            Instruction::CheckInterests(stream_interests(&["fin"])),
+        ],
+    );
+}
+
 #[test]
 fn retransmitted_data_is_sent_in_same_packets_as_new_data() {
     const MAX_PACKET_SIZE: usize = 1000;
@@ -1779,6 +1877,49 @@ fn transmit_fin_while_outstanding_data_exceeds_connection_flow_control_window()
     }
 }
 
+/// A stream which has all of its data transmitted and acknowledged, and has
+/// exhausted both flow control windows, still needs to transmit an empty
+/// STREAM frame carrying the FIN bit once `finish` is called. The FIN does not
+/// consume any flow control credits.
+#[test]
+fn transmit_fin_only_frame_after_stream_is_drained() {
+    let test_config = &[
+        Instruction::EnqueueData(VarInt::from_u32(0), 2000, true),
+        Instruction::CheckInterests(stream_interests(&["tx"])),
+        Instruction::CheckDataTx(VarInt::from_u32(0), 998, false, true, pn(0)),
+        Instruction::CheckDataTx(VarInt::from_u32(998), 996, false, true, pn(1)),
+        Instruction::CheckDataTx(VarInt::from_u32(1994), 6, false, false, pn(2)),
+        Instruction::CheckNoTx,
+        Instruction::AckPacket(pn(0), ExpectWakeup(Some(false))),
+        Instruction::AckPacket(pn(1), ExpectWakeup(Some(false))),
+        Instruction::AckPacket(pn(2), ExpectWakeup(Some(false))),
+        // The stream has nothing left to send
+        Instruction::CheckInterests(stream_interests(&[])),
+        Instruction::CheckNoTx,
+        // Finishing the stream should make it interested in transmission again
+        Instruction::Finish(false),
+        Instruction::CheckInterests(stream_interests(&["tx"])),
+        Instruction::CheckDataTx(VarInt::from_u32(2000), 0, true, false, pn(3)),
+        Instruction::CheckInterests(stream_interests(&["ack"])),
+        Instruction::CheckNoTx,
+        Instruction::AckPacket(pn(3), ExpectWakeup(Some(true))),
+        Instruction::CheckInterests(stream_interests(&[])),
+        Instruction::Finish(true),
+        Instruction::CheckInterests(stream_interests(&["fin"])),
+    ];
+
+    let test_env_config = TestEnvironmentConfig {
+        initial_send_window: 2000,
+        initial_connection_send_window_size: 2000,
+        stream_id: StreamId::initial(endpoint::Type::Server, StreamType::Unidirectional),
+        local_endpoint_type: endpoint::Type::Server,
+        ..Default::default()
+    };
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+    test_env.sent_frames.set_max_packet_size(Some(1000));
+    execute_instructions(&mut test_env, &test_config[..]);
+}
+
 #[test]
 fn finish_after_stream_is_reset_locally() {
     for acknowledge_reset_early in &[true, false] {
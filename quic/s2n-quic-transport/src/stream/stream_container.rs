@@ -15,9 +15,13 @@ use crate::{
 use alloc::rc::Rc;
 use core::{cell::RefCell, ops::Deref};
 use intrusive_collections::{
-    intrusive_adapter, KeyAdapter, LinkedList, LinkedListLink, RBTree, RBTreeLink,
+    intrusive_adapter, linked_list::LinkedListOps, Adapter, DefaultPointerOps, KeyAdapter,
+    LinkedList, LinkedListLink, RBTree, RBTreeLink,
+};
+use s2n_quic_core::{
+    stream::{Priority, StreamId},
+    time::timer,
 };
-use s2n_quic_core::{stream::StreamId, time::timer};
 
 // Intrusive list adapter for managing the list of `done` streams
 intrusive_adapter!(DoneStreamsAdapter<S> = Rc<StreamNode<S>>: StreamNode<S> {
@@ -65,6 +69,8 @@ intrusive_adapter!(StreamTreeAdapter<S> = Rc<StreamNode<S>>: StreamNode<S> {
 struct StreamNode<S> {
     /// This contains the actual implementation of the `Stream`
     inner: RefCell<S>,
+    /// The order in which the Stream is given the chance to transmit relative to other Streams
+    priority: Priority,
     /// Allows the Stream to be part of the `stream_map` collection
     tree_link: RBTreeLink,
     /// Allows the Stream to be part of the `done_streams` collection
@@ -83,9 +89,10 @@ struct StreamNode<S> {
 
 impl<S> StreamNode<S> {
     /// Creates a new `StreamNode` which wraps the given Stream implementation of type `S`
-    pub fn new(stream_impl: S) -> StreamNode<S> {
+    pub fn new(stream_impl: S, priority: Priority) -> StreamNode<S> {
         StreamNode {
             inner: RefCell::new(stream_impl),
+            priority,
             tree_link: RBTreeLink::new(),
             done_streams_link: LinkedListLink::new(),
             waiting_for_frame_delivery_link: LinkedListLink::new(),
@@ -179,13 +186,12 @@ impl<S: StreamTrait> InterestLists<S> {
 
         macro_rules! sync_interests {
             ($interest:expr, $link_name:ident, $list_name:ident) => {
+                sync_interests!($interest, $link_name, $list_name, push_unordered)
+            };
+            ($interest:expr, $link_name:ident, $list_name:ident, $push:ident) => {
                 if $interest != node.$link_name.is_linked() {
                     if $interest {
-                        if matches!(result, StreamContainerIterationResult::Continue) {
-                            self.$list_name.push_back(node.clone());
-                        } else {
-                            self.$list_name.push_front(node.clone());
-                        }
+                        $push(&mut self.$list_name, node.clone(), result);
                     } else {
                         // Safety: We know that the node is only ever part of this list.
                         // While elements are in temporary lists, they always get unlinked
@@ -209,12 +215,14 @@ impl<S: StreamTrait> InterestLists<S> {
         sync_interests!(
             matches!(interests.transmission, transmission::Interest::NewData),
             waiting_for_transmission_link,
-            waiting_for_transmission
+            waiting_for_transmission,
+            push_prioritized
         );
         sync_interests!(
             matches!(interests.transmission, transmission::Interest::LostData),
             waiting_for_retransmission_link,
-            waiting_for_retransmission
+            waiting_for_retransmission,
+            push_prioritized
         );
         sync_interests!(
             interests.connection_flow_control_credits,
@@ -240,6 +248,77 @@ impl<S: StreamTrait> InterestLists<S> {
     }
 }
 
+/// Inserts a Stream into an interest list which is served in insertion order
+fn push_unordered<S, A>(
+    list: &mut LinkedList<A>,
+    node: Rc<StreamNode<S>>,
+    result: StreamContainerIterationResult,
+) where
+    A: Adapter<PointerOps = DefaultPointerOps<Rc<StreamNode<S>>>>,
+    A::LinkOps: LinkedListOps,
+{
+    if matches!(result, StreamContainerIterationResult::Continue) {
+        list.push_back(node);
+    } else {
+        list.push_front(node);
+    }
+}
+
+/// Inserts a Stream into an interest list which is ordered by priority
+///
+/// The list is kept sorted from the highest to the lowest priority. Streams of equal priority are
+/// served in insertion order, except for a Stream which was interrupted while transmitting, which
+/// is placed in front of the others so it can resume first.
+fn push_prioritized<S, A>(
+    list: &mut LinkedList<A>,
+    node: Rc<StreamNode<S>>,
+    result: StreamContainerIterationResult,
+) where
+    A: Adapter<PointerOps = DefaultPointerOps<Rc<StreamNode<S>>>>,
+    A::LinkOps: LinkedListOps,
+{
+    let priority = node.priority;
+
+    if matches!(result, StreamContainerIterationResult::Continue) {
+        // walk from the back since most streams share the same priority
+        let mut cursor = list.back_mut();
+        while cursor.get().is_some_and(|other| other.priority < priority) {
+            cursor.move_prev();
+        }
+        // inserting after the null object places the node at the front
+        cursor.insert_after(node);
+    } else {
+        let mut cursor = list.front_mut();
+        while cursor.get().is_some_and(|other| other.priority > priority) {
+            cursor.move_next();
+        }
+        // inserting before the null object places the node at the back
+        cursor.insert_before(node);
+    }
+}
+
+/// Merges the Streams which were not visited by an interrupted iteration back into a list which
+/// is ordered by priority
+///
+/// Both lists are sorted by priority. The unvisited Streams are placed in front of the Streams of
+/// equal priority which already had the chance to transmit.
+fn merge_prioritized<S, A>(list: &mut LinkedList<A>, mut unvisited: LinkedList<A>)
+where
+    A: Adapter<PointerOps = DefaultPointerOps<Rc<StreamNode<S>>>>,
+    A::LinkOps: LinkedListOps,
+{
+    let mut cursor = list.front_mut();
+    while let Some(node) = unvisited.pop_front() {
+        while cursor
+            .get()
+            .is_some_and(|other| other.priority > node.priority)
+        {
+            cursor.move_next();
+        }
+        cursor.insert_before(node);
+    }
+}
+
 /// A collection of all intrusive lists Streams are part of.
 ///
 /// The container will automatically update the membership of a `Stream` in a
@@ -293,6 +372,36 @@ macro_rules! iterate_uninterruptible {
     };
 }
 
+macro_rules! iterate_prioritized {
+    ($sel:ident, $list_name:tt, $link_name:ident, $controller:ident, $func:ident) => {
+        let mut extracted_list = $sel.interest_lists.$list_name.take();
+        let mut cursor = extracted_list.front_mut();
+
+        while let Some(stream) = cursor.remove() {
+            debug_assert!(!stream.$link_name.is_linked());
+            let mut mut_stream = stream.inner.borrow_mut();
+            let result = $func(&mut *mut_stream);
+            let interests = mut_stream.get_stream_interests();
+
+            if let StreamContainerIterationResult::BreakAndInsertAtBack = result {
+                // The remaining streams go back in before updating the interrupted stream so it
+                // is placed ahead of all of the other streams with the same priority.
+                merge_prioritized(&mut $sel.interest_lists.$list_name, extracted_list);
+                $sel.interest_lists
+                    .update_interests(&stream, interests, result);
+                break;
+            }
+
+            $sel.interest_lists
+                .update_interests(&stream, interests, result);
+        }
+
+        if !$sel.interest_lists.done_streams.is_empty() {
+            $sel.finalize_done_streams($controller);
+        }
+    };
+}
+
 macro_rules! iterate_interruptible {
     ($sel:ident, $list_name:tt, $link_name:ident, $controller:ident, $func:ident) => {
         let mut extracted_list = $sel.interest_lists.$list_name.take();
@@ -340,12 +449,16 @@ impl<S: StreamTrait> StreamContainer<S> {
     }
 
     /// Insert a new Stream into the container
-    pub fn insert_stream(&mut self, stream: S) {
+    ///
+    /// The priority determines the order in which the Stream is given the chance to transmit
+    /// relative to the other Streams. It is assigned before the Stream is added to any of the
+    /// transmission lists.
+    pub fn insert_stream(&mut self, stream: S, priority: Priority) {
         // Even though it likely might have none, it seems like it
         // would be better to avoid future bugs
         let interests = stream.get_stream_interests();
 
-        let new_stream = Rc::new(StreamNode::new(stream));
+        let new_stream = Rc::new(StreamNode::new(stream, priority));
 
         self.interest_lists.update_interests(
             &new_stream,
@@ -425,6 +538,30 @@ impl<S: StreamTrait> StreamContainer<S> {
         Some(result)
     }
 
+    /// Looks up the `Stream` with the given ID and executes the provided
+    /// read-only function on it.
+    ///
+    /// Since the `Stream` can not be modified, its interests remain unchanged.
+    pub fn with_stream_ref<F, R>(&self, stream_id: StreamId, func: F) -> Option<R>
+    where
+        F: FnOnce(&S) -> R,
+    {
+        let node = self.stream_map.find(&stream_id).get()?;
+        let stream: &S = &node.inner.borrow();
+        Some(func(stream))
+    }
+
+    /// Returns the IDs of all `Stream`s which are waiting for stream flow control
+    /// credits
+    ///
+    /// Since the `Stream`s are not modified, their interests remain unchanged.
+    pub fn stream_flow_credits_list_ids(&self) -> impl Iterator<Item = StreamId> + '_ {
+        self.interest_lists
+            .waiting_for_stream_flow_control_credits
+            .iter()
+            .map(|node| node.inner.borrow().stream_id())
+    }
+
     /// Removes all Streams in the `done` state from the `StreamManager`.
     ///
     /// The `stream::Controller` will be notified of streams that have been
@@ -546,7 +683,7 @@ impl<S: StreamTrait> StreamContainer<S> {
     where
         F: FnMut(&mut S) -> StreamContainerIterationResult,
     {
-        iterate_interruptible!(
+        iterate_prioritized!(
             self,
             waiting_for_transmission,
             waiting_for_transmission_link,
@@ -567,7 +704,7 @@ impl<S: StreamTrait> StreamContainer<S> {
     ) where
         F: FnMut(&mut S) -> StreamContainerIterationResult,
     {
-        iterate_interruptible!(
+        iterate_prioritized!(
             self,
             waiting_for_retransmission,
             waiting_for_retransmission_link,
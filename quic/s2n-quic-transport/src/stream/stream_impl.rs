@@ -42,6 +42,9 @@ pub struct StreamConfig {
     pub initial_send_window: VarInt,
     /// The maximum buffered amount of data on the sending side
     pub max_send_buffer_size: u32,
+    /// The amount of buffered data on the sending side at which writing resumes after the
+    /// buffer filled up
+    pub send_buffer_low_watermark: u32,
 }
 
 /// A trait which represents an internally used `Stream`
@@ -122,6 +125,14 @@ pub trait StreamTrait: StreamInterestProvider + timer::Provider + core::fmt::Deb
     /// This method is called when a connection window is available
     fn on_connection_window_available(&mut self);
 
+    /// Returns the flow control limit the peer has granted for sending data,
+    /// or `None` if the Stream has no sending side
+    fn send_window(&self) -> Option<VarInt>;
+
+    /// Returns the flow control limit advertised to the peer for receiving data,
+    /// or `None` if the Stream has no receiving side
+    fn receive_window(&self) -> Option<VarInt>;
+
     // These functions are called from the client API
 
     fn poll_request(
@@ -139,6 +150,8 @@ pub struct StreamImpl {
     pub(super) stream_id: StreamId,
     /// Manages the receiving side of the stream
     pub(super) receive_stream: ReceiveStream,
+    /// Set to `true` when this stream has a receiving side
+    has_receive: bool,
     /// Set to `true` when this stream has a sending side
     has_send: bool,
     /// Manages the sending side of the stream
@@ -205,12 +218,14 @@ impl StreamTrait for StreamImpl {
                 config.initial_receive_window,
                 config.desired_flow_control_window,
             ),
+            has_receive: !receive_is_closed,
             has_send: !send_is_closed,
             send_stream: SendStream::new(
                 config.outgoing_connection_flow_controller,
                 send_is_closed,
                 config.initial_send_window,
                 config.max_send_buffer_size,
+                config.send_buffer_low_watermark,
             ),
         }
     }
@@ -327,6 +342,33 @@ impl StreamTrait for StreamImpl {
         self.send_stream.on_connection_window_available()
     }
 
+    #[inline]
+    fn send_window(&self) -> Option<VarInt> {
+        if !self.has_send {
+            return None;
+        }
+
+        Some(
+            self.send_stream
+                .data_sender
+                .flow_controller()
+                .max_stream_data(),
+        )
+    }
+
+    #[inline]
+    fn receive_window(&self) -> Option<VarInt> {
+        if !self.has_receive {
+            return None;
+        }
+
+        Some(
+            self.receive_stream
+                .flow_controller
+                .current_stream_receive_window(),
+        )
+    }
+
     // These functions are called from the client API
 
     fn poll_request(
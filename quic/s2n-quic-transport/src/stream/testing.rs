@@ -12,7 +12,10 @@ use crate::{
     transmission,
 };
 use bytes::Bytes;
-use core::task::{Context, Poll, Waker};
+use core::{
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 use futures_test::task::{new_count_waker, AwokenCount};
 use s2n_quic_core::{
     application::Error as ApplicationErrorCode,
@@ -483,6 +486,7 @@ pub struct TestEnvironmentConfig {
     pub initial_connection_receive_window_size: u64,
     pub desired_connection_flow_control_window: u32,
     pub max_send_buffer_size: usize,
+    pub send_buffer_low_watermark: usize,
     pub transmission_constraint: transmission::Constraint,
     pub local_endpoint_type: endpoint::Type,
     pub max_packet_size: Option<usize>,
@@ -512,6 +516,7 @@ impl TestEnvironmentConfig {
             desired_connection_flow_control_window:
                 TestEnvironment::DEFAULT_INITIAL_CONNECTION_RECEIVE_WINDOW as u32,
             max_send_buffer_size: TestEnvironment::DEFAULT_MAX_SEND_BUFFER_SIZE,
+            send_buffer_low_watermark: TestEnvironment::DEFAULT_MAX_SEND_BUFFER_SIZE,
             transmission_constraint: transmission::Constraint::None,
             max_packet_size: None,
         }
@@ -529,6 +534,9 @@ pub fn setup_stream_test_env_with_config(config: TestEnvironmentConfig) -> TestE
     let rx_connection_flow_controller = IncomingConnectionFlowController::new(
         VarInt::new(config.initial_connection_receive_window_size).unwrap(),
         config.desired_connection_flow_control_window,
+        config.desired_connection_flow_control_window,
+        2,
+        Duration::from_millis(100),
     );
 
     let tx_connection_flow_controller = OutgoingConnectionFlowController::new(
@@ -544,6 +552,7 @@ pub fn setup_stream_test_env_with_config(config: TestEnvironmentConfig) -> TestE
         desired_flow_control_window: config.desired_flow_control_window,
         initial_send_window: VarInt::new(config.initial_send_window).unwrap(),
         max_send_buffer_size: config.max_send_buffer_size as u32,
+        send_buffer_low_watermark: config.send_buffer_low_watermark as u32,
     });
 
     let (waker, wake_counter) = new_count_waker();
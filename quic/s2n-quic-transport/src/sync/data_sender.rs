@@ -196,6 +196,12 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         self.buffer.total_len()
     }
 
+    /// Returns the amount of data that is currently buffered, including data that has been
+    /// transmitted but not yet acknowledged
+    pub fn enqueued_len(&self) -> usize {
+        self.buffer.enqueued_len().try_into().unwrap_or(usize::MAX)
+    }
+
     /// Returns true if the data sender doesn't have any data enqueued for sending
     pub fn is_empty(&self) -> bool {
         self.buffer.is_empty()
@@ -758,4 +764,64 @@ mod tests {
                 check_model(events, id);
             });
     }
+
+    #[test]
+    fn probe_retransmits_unacked_data() {
+        // returns the offset and length of each STREAM frame written since the last call
+        fn written(frame_buffer: &mut OutgoingFrameBuffer) -> Vec<(u64, usize)> {
+            frame_buffer.flush();
+            let frames = frame_buffer
+                .frames
+                .iter_mut()
+                .map(|frame| match frame.as_frame() {
+                    frame::Frame::Stream(frame) => (frame.offset.as_u64(), frame.data.len()),
+                    frame => panic!("invalid frame {frame:?}"),
+                })
+                .collect();
+            frame_buffer.clear();
+            frames
+        }
+
+        let mut sender: DataSender<_, writer::Stream> = DataSender::new(
+            TestFlowController {
+                max_offset: VarInt::MAX,
+                is_blocked: false,
+            },
+            u32::MAX,
+        );
+        let id = VarInt::from_u8(4);
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut context = MockWriteContext {
+            current_time: time::now(),
+            frame_buffer: &mut frame_buffer,
+            transmission_constraint: transmission::Constraint::None,
+            transmission_mode: transmission::Mode::Normal,
+            endpoint: endpoint::Type::Server,
+        };
+
+        sender.push(Bytes::from_static(&[1; 100]));
+        sender.on_transmit(id, &mut context).unwrap();
+        let first_packet = context.frame_buffer.frames[0].packet_nr;
+        assert_eq!(written(context.frame_buffer), [(0, 100)]);
+
+        // unacked data is not retransmitted in normal transmissions
+        sender.on_transmit(id, &mut context).unwrap();
+        assert!(written(context.frame_buffer).is_empty());
+
+        context.transmission_mode = transmission::Mode::LossRecoveryProbing;
+
+        // without any new data the probe includes the unacked data
+        sender.on_transmit(id, &mut context).unwrap();
+        assert_eq!(written(context.frame_buffer), [(0, 100)]);
+
+        // new data is written ahead of the unacked data
+        sender.push(Bytes::from_static(&[2; 50]));
+        sender.on_transmit(id, &mut context).unwrap();
+        assert_eq!(written(context.frame_buffer), [(100, 50), (0, 100)]);
+
+        // only the remaining unacked data is included once the first packet is acked
+        sender.on_packet_ack(&first_packet);
+        sender.on_transmit(id, &mut context).unwrap();
+        assert_eq!(written(context.frame_buffer), [(100, 50)]);
+    }
 }
@@ -11,6 +11,14 @@ pub struct Stream;
 impl FrameWriter for Stream {
     type Context = VarInt;
 
+    //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.4
+    //# An endpoint SHOULD include new data in packets that are sent on PTO
+    //# expiration.  Previously sent data MAY be sent if no new data can be
+    //# sent.
+    // Include already transmitted, unacked stream data in probe packets, in case
+    // the original packets carrying it were lost.
+    const RETRANSMIT_IN_PROBE: bool = true;
+
     #[inline]
     fn write_chunk<W: WriteContext>(
         &self,
@@ -8,7 +8,7 @@ use crate::{
     endpoint, path,
     path::mtu,
     recovery,
-    space::{datagram, CryptoStream, HandshakeStatus},
+    space::{datagram, CryptoStream, HandshakeStatus, NewTokenSender},
     stream::Manager as _,
     sync::{flag, flag::Ping},
     transmission::{self, Mode, Provider as _},
@@ -35,6 +35,7 @@ impl<'a, Config: endpoint::Config> Payload<'a, Config> {
         ack_manager: &'a mut AckManager,
         handshake_status: &'a mut HandshakeStatus,
         ping: &'a mut flag::Ping,
+        new_token: &'a mut NewTokenSender,
         stream_manager: &'a mut Config::StreamManager,
         recovery_manager: &'a mut recovery::Manager<Config>,
         crypto_stream: &'a mut CryptoStream,
@@ -50,6 +51,7 @@ impl<'a, Config: endpoint::Config> Payload<'a, Config> {
                     ack_manager,
                     handshake_status,
                     ping,
+                    new_token,
                     stream_manager,
                     local_id_registry,
                     path_manager,
@@ -107,6 +109,7 @@ pub struct Normal<'a, Config: endpoint::Config> {
     ack_manager: &'a mut AckManager,
     handshake_status: &'a mut HandshakeStatus,
     ping: &'a mut Ping,
+    new_token: &'a mut NewTokenSender,
     stream_manager: &'a mut Config::StreamManager,
     local_id_registry: &'a mut connection::LocalIdRegistry,
     path_manager: &'a mut path::Manager<Config>,
@@ -188,6 +191,8 @@ impl<'a, Config: endpoint::Config> Normal<'a, Config> {
         self.local_id_registry.on_transmit(context);
 
         self.path_manager.on_transmit(context);
+
+        self.new_token.on_transmit(context);
     }
 }
 
@@ -208,6 +213,7 @@ impl<'a, Config: endpoint::Config> transmission::interest::Provider for Normal<'
             .active_path()
             .transmission_interest(query)?;
         self.ping.transmission_interest(query)?;
+        self.new_token.transmission_interest(query)?;
         Ok(())
     }
 }
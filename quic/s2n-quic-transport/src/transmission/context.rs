@@ -284,3 +284,73 @@ impl<'a, C: WriteContext> WriteContext for RetransmissionContext<'a, C> {
         self.context.tag_len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoint::testing::Server as Config;
+    use s2n_codec::EncoderBuffer;
+    use s2n_quic_core::{
+        event::testing::Subscriber,
+        frame::{Padding, Ping},
+        time::clock::testing as time,
+    };
+
+    #[test]
+    fn write_frame_at_capacity_test() {
+        let mut outcome = transmission::Outcome::default();
+        let mut bytes = [0u8; 16];
+        let mut buffer = scatter::Buffer::new(EncoderBuffer::new(&mut bytes));
+        let mut subscriber = Subscriber::no_snapshot();
+        let mut connection_context = ();
+        let timestamp = time::now();
+        let mut publisher = event::ConnectionPublisherSubscriber::new(
+            event::builder::ConnectionMeta {
+                endpoint_type: s2n_quic_core::endpoint::Type::Server,
+                id: 0,
+                timestamp,
+            },
+            0x00000001,
+            &mut subscriber,
+            &mut connection_context,
+        );
+
+        let mut context: Context<Config> = Context {
+            outcome: &mut outcome,
+            buffer: &mut buffer,
+            packet_number: PacketNumber::default(),
+            transmission_constraint: transmission::Constraint::None,
+            transmission_mode: Mode::Normal,
+            timestamp,
+            header_len: 0,
+            tag_len: 0,
+            config: PhantomData,
+            path_id: path::Id::test_id(),
+            publisher: &mut publisher,
+        };
+
+        // a frame which is one byte larger than the buffer is rejected without writing anything
+        assert!(context.write_frame(&Padding { length: 17 }).is_none());
+        assert_eq!(context.remaining_capacity(), 16);
+
+        // leave exactly enough space for a single PING frame
+        assert!(context.write_frame(&Padding { length: 15 }).is_some());
+        assert_eq!(context.remaining_capacity(), 1);
+
+        // a frame which doesn't fit the remaining space is not partially written
+        assert!(context.write_frame(&Padding { length: 2 }).is_none());
+        assert_eq!(context.remaining_capacity(), 1);
+        assert!(!context.ack_elicitation().is_ack_eliciting());
+
+        // a frame filling the buffer exactly is written
+        assert!(context.write_frame(&Ping).is_some());
+        assert_eq!(context.remaining_capacity(), 0);
+        assert!(context.ack_elicitation().is_ack_eliciting());
+
+        // nothing else fits in a full buffer
+        assert!(context.write_frame(&Ping).is_none());
+        assert!(context.write_frame_forced(&Ping).is_none());
+
+        assert_eq!(buffer.len(), 16);
+    }
+}
@@ -77,6 +77,34 @@ impl<'a, 'sub, Config: endpoint::Config, P: Payload> PacketPayloadEncoder
             "the implementation assumes an empty buffer"
         );
 
+        // The largest packet number can't be followed by another one so the space is exhausted.
+        // Nothing is written and the connection is closed once the current burst completes.
+        if self.packet_number.next().is_none() {
+            return;
+        }
+
+        {
+            use s2n_quic_core::{
+                event::ConnectionPublisher,
+                packet::interceptor::{Interceptor, Packet},
+            };
+
+            let packet = Packet {
+                number: self.packet_number,
+                timestamp: self.timestamp,
+            };
+
+            // drop the packet before any frames are written so the payload is retried in a
+            // later packet
+            if !self
+                .packet_interceptor
+                .intercept_tx_packet(&self.publisher.subject(), &packet)
+            {
+                self.tx_packet_numbers.on_drop(self.packet_number);
+                return;
+            }
+        }
+
         let mut context: Context<Config> = Context {
             outcome: self.outcome,
             buffer,
@@ -94,15 +122,12 @@ impl<'a, 'sub, Config: endpoint::Config, P: Payload> PacketPayloadEncoder
         self.payload.on_transmit(&mut context);
 
         if !context.buffer.is_empty() {
-            // Add padding up to minimum_len
-            let mut length = minimum_len.saturating_sub(context.buffer.len());
-
-            // if we've only got a few bytes left in the buffer may as well pad it to full
-            // capacity
-            let remaining_capacity = context.buffer.remaining_capacity();
-            if remaining_capacity < stateless_reset::min_indistinguishable_packet_len(tag_len) {
-                length = remaining_capacity;
-            }
+            let length = padding_len(
+                context.buffer.len(),
+                context.buffer.remaining_capacity(),
+                minimum_len,
+                tag_len,
+            );
 
             if length > 0 {
                 context.write_frame(&Padding { length });
@@ -131,6 +156,19 @@ impl<'a, 'sub, Config: endpoint::Config, P: Payload> PacketPayloadEncoder
     }
 }
 
+/// Returns the number of PADDING bytes to write after a payload of `len` bytes
+#[inline]
+fn padding_len(len: usize, remaining_capacity: usize, minimum_len: usize, tag_len: usize) -> usize {
+    // if we've only got a few bytes left in the buffer may as well pad it to full
+    // capacity
+    if remaining_capacity < stateless_reset::min_indistinguishable_packet_len(tag_len) {
+        return remaining_capacity;
+    }
+
+    // Add padding up to minimum_len
+    minimum_len.saturating_sub(len)
+}
+
 impl<'a, 'sub, Config: endpoint::Config, P: Payload> transmission::interest::Provider
     for Transmission<'a, 'sub, Config, P>
 {
@@ -141,3 +179,28 @@ impl<'a, 'sub, Config: endpoint::Config, P: Payload> transmission::interest::Pro
         self.payload.transmission_interest(query)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding_len_test() {
+        let tag_len = 16;
+        let min_len = stateless_reset::min_indistinguishable_packet_len(tag_len);
+
+        // the payload is padded up to the minimum length
+        assert_eq!(padding_len(100, 1000, 1200, tag_len), 1100);
+        assert_eq!(padding_len(1, 1000, 50, tag_len), 49);
+
+        // nothing is added when the payload is already at or above the minimum
+        assert_eq!(padding_len(1200, 1000, 1200, tag_len), 0);
+        assert_eq!(padding_len(1300, 1000, 1200, tag_len), 0);
+        assert_eq!(padding_len(100, 1000, 0, tag_len), 0);
+
+        // a small amount of remaining capacity is filled rather than left unused
+        assert_eq!(padding_len(1200, min_len - 1, 0, tag_len), min_len - 1);
+        assert_eq!(padding_len(1200, 0, 0, tag_len), 0);
+        assert_eq!(padding_len(1200, min_len, 0, tag_len), 0);
+    }
+}
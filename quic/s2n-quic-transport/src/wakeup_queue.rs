@@ -7,11 +7,19 @@
 
 use alloc::{collections::VecDeque, sync::Arc};
 use core::{
+    fmt,
+    hash::Hash,
     sync::atomic::{AtomicBool, Ordering},
     task::{Context, Waker},
 };
+#[cfg(debug_assertions)]
+use std::collections::HashMap;
 use std::{sync::Mutex, task::Wake};
 
+/// The number of handle IDs which can be registered before IDs of dropped handles are pruned
+#[cfg(debug_assertions)]
+const MIN_HANDLE_ID_PRUNE_LEN: usize = 64;
+
 /// The shared state of the [`WakeupQueue`].
 #[derive(Debug)]
 struct QueueState<T> {
@@ -19,8 +27,16 @@ struct QueueState<T> {
     woken_connections: VecDeque<T>,
     /// The waker which should be used to wake up the connection
     waker: Option<Waker>,
+    /// The waker which should be notified once a batch of wakeups drains the queue
+    empty_waker: Option<Waker>,
     /// Whether a wakeup is already in progress
     wakeup_in_progress: bool,
+    /// The IDs of live wakeup handles, which are used to detect handles sharing an ID
+    #[cfg(debug_assertions)]
+    handle_ids: HashMap<T, alloc::sync::Weak<()>>,
+    /// The number of registered handle IDs at which the IDs of dropped handles are pruned
+    #[cfg(debug_assertions)]
+    handle_ids_prune_len: usize,
 }
 
 impl<T: Copy + Send + Sync> QueueState<T> {
@@ -28,10 +44,46 @@ impl<T: Copy + Send + Sync> QueueState<T> {
         Self {
             woken_connections: VecDeque::new(),
             waker: None,
+            empty_waker: None,
             wakeup_in_progress: false,
+            #[cfg(debug_assertions)]
+            handle_ids: HashMap::new(),
+            #[cfg(debug_assertions)]
+            handle_ids_prune_len: MIN_HANDLE_ID_PRUNE_LEN,
         }
     }
 
+    /// Registers the ID of a new wakeup handle
+    ///
+    /// Panics if a live handle already uses the same ID. The ID stays registered until the
+    /// returned registration is dropped.
+    #[cfg(debug_assertions)]
+    fn register_handle(&mut self, wakeup_handle_id: T) -> Arc<()>
+    where
+        T: Eq + Hash + fmt::Debug,
+    {
+        if let Some(registration) = self.handle_ids.get(&wakeup_handle_id) {
+            assert!(
+                registration.strong_count() == 0,
+                "wakeup handle id {wakeup_handle_id:?} is already in use"
+            );
+        }
+
+        let registration = Arc::new(());
+        self.handle_ids
+            .insert(wakeup_handle_id, Arc::downgrade(&registration));
+
+        // Remove the IDs of handles which have been dropped. Pruning only once the number of IDs
+        // has doubled since the last pass keeps the cost of each registration constant on average.
+        if self.handle_ids.len() >= self.handle_ids_prune_len {
+            self.handle_ids
+                .retain(|_id, registration| registration.strong_count() > 0);
+            self.handle_ids_prune_len = (self.handle_ids.len() * 2).max(MIN_HANDLE_ID_PRUNE_LEN);
+        }
+
+        registration
+    }
+
     fn queue_wakeup(&mut self, wakeup_handle_id: T) -> Option<Waker> {
         self.woken_connections.push_back(wakeup_handle_id);
         // If pushing another handle already notified the processing thread that it should dequeue
@@ -50,7 +102,14 @@ impl<T: Copy + Send + Sync> QueueState<T> {
     /// It will returns a queue of occurred events.
     /// If no wakeup occurred, the method will store the passed [`Waker`] and notify it as soon as
     /// a wakeup occurred.
-    fn poll_pending_wakeups(&mut self, swap_queue: &mut VecDeque<T>, context: &Context) {
+    ///
+    /// If a batch of wakeups was returned, the registered empty waker is taken and returned so
+    /// the caller can notify it once the lock is released.
+    fn poll_pending_wakeups(
+        &mut self,
+        swap_queue: &mut VecDeque<T>,
+        context: &Context,
+    ) -> Option<Waker> {
         debug_assert!(swap_queue.is_empty());
         self.wakeup_in_progress = false;
 
@@ -65,10 +124,20 @@ impl<T: Copy + Send + Sync> QueueState<T> {
                 None => self.waker = Some(context.waker().clone()),
             }
 
-            return;
+            return None;
         }
 
         core::mem::swap(&mut self.woken_connections, swap_queue);
+
+        // The queue was drained by handing out the batch
+        self.empty_waker.take()
+    }
+
+    fn register_empty_waker(&mut self, context: &Context) {
+        match &self.empty_waker {
+            Some(w) if w.will_wake(context.waker()) => {}
+            _ => self.empty_waker = Some(context.waker().clone()),
+        }
     }
 
     #[cfg(any(feature = "testing", test))]
@@ -100,8 +169,26 @@ impl<T: Copy + Send + Sync> WakeupQueue<T> {
 
     /// Creates a new [`WakeupHandle`] which will wake up this [`WakeupQueue`] if
     /// [`WakeupHandle::wakeup`] is called.
-    pub fn create_wakeup_handle(&self, wakeup_handle_id: T) -> WakeupHandle<T> {
-        WakeupHandle::new(self.state.clone(), wakeup_handle_id)
+    ///
+    /// In debug builds this panics if another live handle was created with the same ID.
+    pub fn create_wakeup_handle(&self, wakeup_handle_id: T) -> WakeupHandle<T>
+    where
+        T: Eq + Hash + fmt::Debug,
+    {
+        #[cfg(debug_assertions)]
+        let registration = self
+            .state
+            .lock()
+            .expect("Locking can only fail if locks are poisoned")
+            .register_handle(wakeup_handle_id);
+
+        WakeupHandle {
+            queue: self.state.clone(),
+            wakeup_handle_id,
+            wakeup_queued: AtomicBool::new(false),
+            #[cfg(debug_assertions)]
+            _registration: registration,
+        }
     }
 
     /// Returns the list of component handles which need to get woken.
@@ -109,12 +196,34 @@ impl<T: Copy + Send + Sync> WakeupQueue<T> {
     /// memory allocations, the caller is expected to pass in a new `VecDequeue` which will
     /// by utilized for further queueing. Thereby a double-buffering approach for wakeups is
     /// enabled.
+    ///
+    /// If the returned batch drained the queue, the waker registered through
+    /// [`Self::register_empty_waker`] is notified.
     pub fn poll_pending_wakeups(&mut self, swap_queue: &mut VecDeque<T>, context: &Context) {
-        let mut guard = self
-            .state
+        let maybe_waker = {
+            let mut guard = self
+                .state
+                .lock()
+                .expect("Locking can only fail if locks are poisoned");
+            guard.poll_pending_wakeups(swap_queue, context)
+        };
+
+        if let Some(waker) = maybe_waker {
+            waker.wake();
+        }
+    }
+
+    /// Registers a [`Waker`] which is notified once a call to
+    /// [`Self::poll_pending_wakeups`] hands out the last pending wakeups and leaves the queue
+    /// empty.
+    ///
+    /// The waker is notified at most once per registration.
+    #[allow(dead_code)] // the endpoint doesn't wait for the queue to drain yet
+    pub fn register_empty_waker(&mut self, context: &Context) {
+        self.state
             .lock()
-            .expect("Locking can only fail if locks are poisoned");
-        guard.poll_pending_wakeups(swap_queue, context)
+            .expect("Locking can only fail if locks are poisoned")
+            .register_empty_waker(context);
     }
 
     #[cfg(any(feature = "testing", test))]
@@ -136,18 +245,12 @@ pub struct WakeupHandle<T> {
     /// Whether a wakeup for this handle had already been queued since the last time
     /// the wakeup handler was called
     wakeup_queued: AtomicBool,
+    /// Keeps the ID of this handle registered with the queue while the handle is alive
+    #[cfg(debug_assertions)]
+    _registration: Arc<()>,
 }
 
 impl<T: Copy + Send + Sync> WakeupHandle<T> {
-    /// Creates a new [`WakeupHandle`] which delegates wakeups to the given `queue`.
-    fn new(queue: Arc<Mutex<QueueState<T>>>, wakeup_handle_id: T) -> Self {
-        Self {
-            queue,
-            wakeup_handle_id,
-            wakeup_queued: AtomicBool::new(false),
-        }
-    }
-
     /// Notifies the queue to wake up. If a `wakeup()` had been issued for the same
     /// [`WakeupHandle`] without having been handled yet, the new [`wakeup()`] request will be
     /// ignored, since the wakeup will already be pending.
@@ -181,6 +284,25 @@ impl<T: Copy + Send + Sync> WakeupHandle<T> {
     }
 }
 
+/// Clones share the ID and queue of the original handle, but track their queued wakeups
+/// independently.
+///
+/// As a result, the queue only deduplicates wakeups per clone: if two clones each call
+/// [`WakeupHandle::wakeup`] before [`WakeupHandle::wakeup_handled`] is called on them, the ID is
+/// enqueued once for each clone and [`WakeupQueue::poll_pending_wakeups`] will return it twice.
+impl<T: Copy + Send + Sync> Clone for WakeupHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            wakeup_handle_id: self.wakeup_handle_id,
+            wakeup_queued: AtomicBool::new(false),
+            // the ID stays registered until every clone has been dropped
+            #[cfg(debug_assertions)]
+            _registration: self._registration.clone(),
+        }
+    }
+}
+
 impl<T: Copy + Send + Sync> Wake for WakeupHandle<T> {
     fn wake(self: Arc<Self>) {
         self.wakeup()
@@ -277,6 +399,89 @@ mod tests {
         check_state!(state, 2, true);
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wakeup handle id 1 is already in use")]
+    fn duplicate_handle_id() {
+        let queue = WakeupQueue::new();
+        let _handle1 = queue.create_wakeup_handle(1u32);
+        let _handle2 = queue.create_wakeup_handle(1u32);
+    }
+
+    #[test]
+    fn reuse_handle_id_after_drop() {
+        let queue = WakeupQueue::new();
+        let handle = queue.create_wakeup_handle(1u32);
+        let _other = queue.create_wakeup_handle(2u32);
+        drop(handle);
+
+        // the ID can be reused once the previous handle is gone
+        let _handle = queue.create_wakeup_handle(1u32);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn prune_dropped_handle_ids() {
+        let queue = WakeupQueue::new();
+        let _live = queue.create_wakeup_handle(0u32);
+
+        // IDs which are never reused are pruned once their handles are dropped
+        for id in 1..10_000u32 {
+            drop(queue.create_wakeup_handle(id));
+        }
+
+        let state = queue.test_state();
+        let state = state.lock().unwrap();
+        assert!(state.handle_ids.len() <= MIN_HANDLE_ID_PRUNE_LEN);
+        assert!(state.handle_ids.contains_key(&0));
+    }
+
+    #[test]
+    fn cloned_handle_wakeups() {
+        let (waker, counter) = new_count_waker();
+        let mut queue = WakeupQueue::new();
+        let mut pending = VecDeque::new();
+
+        let handle = queue.create_wakeup_handle(1u32);
+        let clone = handle.clone();
+
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert!(pending.is_empty());
+
+        // each clone tracks its own queued wakeup, so both are enqueued
+        handle.wakeup();
+        clone.wakeup();
+        assert_eq!(counter, 1);
+
+        // repeated wakeups on either clone are still deduplicated
+        handle.wakeup();
+        clone.wakeup();
+
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert_eq!(vec_deque![1u32, 1u32], pending);
+        pending.clear();
+
+        handle.wakeup_handled();
+        clone.wakeup_handled();
+
+        // the ID stays registered while a clone is still alive
+        drop(handle);
+        clone.wakeup();
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert_eq!(vec_deque![1u32], pending);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wakeup handle id 1 is already in use")]
+    fn cloned_handle_keeps_id_registered() {
+        let queue = WakeupQueue::new();
+        let handle = queue.create_wakeup_handle(1u32);
+        let _clone = handle.clone();
+        drop(handle);
+        let _handle = queue.create_wakeup_handle(1u32);
+    }
+
     #[test]
     fn queue_wakeups() {
         let (waker, counter) = new_count_waker();
@@ -327,4 +532,49 @@ mod tests {
         assert_eq!(vec_deque![2u32], pending);
         pending.clear();
     }
+
+    #[test]
+    fn empty_waker_test() {
+        let (waker, _counter) = new_count_waker();
+        let (empty_waker, empty_counter) = new_count_waker();
+        let mut queue = WakeupQueue::new();
+        let mut pending = VecDeque::new();
+
+        let handle1 = queue.create_wakeup_handle(1u32);
+        let handle2 = queue.create_wakeup_handle(2u32);
+
+        queue.register_empty_waker(&Context::from_waker(&empty_waker));
+
+        // Polling an already empty queue does not notify the empty waker
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert!(pending.is_empty());
+        assert_eq!(empty_counter, 0);
+
+        // Queueing wakeups does not notify the empty waker
+        handle1.wakeup();
+        handle2.wakeup();
+        assert_eq!(empty_counter, 0);
+
+        // Draining the last pending handles notifies the empty waker
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert_eq!(vec_deque![1u32, 2u32], pending);
+        assert_eq!(empty_counter, 1);
+        pending.clear();
+
+        // The empty waker is only notified once per registration
+        handle1.wakeup_handled();
+        handle1.wakeup();
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert_eq!(vec_deque![1u32], pending);
+        assert_eq!(empty_counter, 1);
+        pending.clear();
+
+        // Registering again arms the notification for the next drained batch
+        queue.register_empty_waker(&Context::from_waker(&empty_waker));
+        handle1.wakeup_handled();
+        handle1.wakeup();
+        queue.poll_pending_wakeups(&mut pending, &Context::from_waker(&waker));
+        assert_eq!(vec_deque![1u32], pending);
+        assert_eq!(empty_counter, 2);
+    }
 }
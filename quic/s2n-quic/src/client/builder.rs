@@ -261,7 +261,7 @@ impl<Providers: ClientProviders> Builder<Providers> {
         ClientProviders
     );
 
-    #[cfg(feature = "unstable-provider-datagram")]
+    #[cfg(any(test, feature = "unstable-provider-datagram"))]
     impl_provider_method!(
         /// Sets the datagram provider for the [`Client`]
         with_datagram,
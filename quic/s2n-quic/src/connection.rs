@@ -11,7 +11,11 @@ mod handle;
 
 pub use acceptor::*;
 pub use handle::*;
-pub use s2n_quic_core::connection::Error;
+pub use s2n_quic_core::{
+    connection::{ConnectionCloseReason, ConnectionStats, Error, PeerTransportParameters},
+    path::{PathInfo, ValidationState as PathValidationState},
+    recovery::RttEstimate,
+};
 
 pub mod error {
     pub use s2n_quic_core::transport::error::Code;
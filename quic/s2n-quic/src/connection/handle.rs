@@ -44,10 +44,14 @@ macro_rules! impl_handle_api {
             cx: &mut core::task::Context,
         ) -> core::task::Poll<$crate::connection::Result<$crate::stream::LocalStream>> {
             use s2n_quic_core::stream::StreamType;
-            use $crate::stream::{BidirectionalStream, SendStream};
+            use $crate::stream::{BidirectionalStream, Priority, SendStream};
 
             Ok(
-                match core::task::ready!(self.0.poll_open_stream(stream_type, cx))? {
+                match core::task::ready!(self.0.poll_open_stream(
+                    stream_type,
+                    Priority::default(),
+                    cx
+                ))? {
                     stream if stream_type == StreamType::Unidirectional => {
                         SendStream::new(stream.into()).into()
                     }
@@ -93,12 +97,58 @@ macro_rules! impl_handle_api {
         pub fn poll_open_bidirectional_stream(
             &mut self,
             cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::connection::Result<$crate::stream::BidirectionalStream>> {
+            self.poll_open_bidirectional_stream_with_priority(Default::default(), cx)
+        }
+
+        /// Opens a new [`BidirectionalStream`](`crate::stream::BidirectionalStream`) with the
+        /// given [`Priority`](`crate::stream::Priority`)
+        ///
+        /// The priority is assigned before the stream can be scheduled for transmission, so data
+        /// written to it is sent ahead of data on streams with a lower priority.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   use s2n_quic::stream::Priority;
+        /// #   let mut handle: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// let stream = handle
+        ///     .open_bidirectional_stream_with_priority(Priority::HIGHEST)
+        ///     .await?;
+        /// println!("High priority stream opened with id: {}", stream.id());
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn open_bidirectional_stream_with_priority(
+            &mut self,
+            priority: $crate::stream::Priority,
+        ) -> $crate::connection::Result<$crate::stream::BidirectionalStream> {
+            futures::future::poll_fn(|cx| {
+                self.poll_open_bidirectional_stream_with_priority(priority, cx)
+            })
+            .await
+        }
+
+        /// Polls opening a [`BidirectionalStream`](`crate::stream::BidirectionalStream`) with the
+        /// given [`Priority`](`crate::stream::Priority`)
+        #[inline]
+        pub fn poll_open_bidirectional_stream_with_priority(
+            &mut self,
+            priority: $crate::stream::Priority,
+            cx: &mut core::task::Context,
         ) -> core::task::Poll<$crate::connection::Result<$crate::stream::BidirectionalStream>> {
             use s2n_quic_core::stream::StreamType;
             use $crate::stream::BidirectionalStream;
 
-            let stream =
-                core::task::ready!(self.0.poll_open_stream(StreamType::Bidirectional, cx))?;
+            let stream = core::task::ready!(self.0.poll_open_stream(
+                StreamType::Bidirectional,
+                priority,
+                cx
+            ))?;
 
             Ok(BidirectionalStream::new(stream)).into()
         }
@@ -129,16 +179,99 @@ macro_rules! impl_handle_api {
         pub fn poll_open_send_stream(
             &mut self,
             cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::connection::Result<$crate::stream::SendStream>> {
+            self.poll_open_send_stream_with_priority(Default::default(), cx)
+        }
+
+        /// Opens a [`SendStream`](`crate::stream::SendStream`) with the given
+        /// [`Priority`](`crate::stream::Priority`)
+        ///
+        /// The priority is assigned before the stream can be scheduled for transmission, so data
+        /// written to it is sent ahead of data on streams with a lower priority.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   use s2n_quic::stream::Priority;
+        /// #   let mut connection: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// let stream = connection
+        ///     .open_send_stream_with_priority(Priority::HIGHEST)
+        ///     .await?;
+        /// println!("High priority stream opened with id: {}", stream.id());
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn open_send_stream_with_priority(
+            &mut self,
+            priority: $crate::stream::Priority,
+        ) -> $crate::connection::Result<$crate::stream::SendStream> {
+            futures::future::poll_fn(|cx| self.poll_open_send_stream_with_priority(priority, cx))
+                .await
+        }
+
+        /// Polls opening a [`SendStream`](`crate::stream::SendStream`) with the given
+        /// [`Priority`](`crate::stream::Priority`)
+        #[inline]
+        pub fn poll_open_send_stream_with_priority(
+            &mut self,
+            priority: $crate::stream::Priority,
+            cx: &mut core::task::Context,
         ) -> core::task::Poll<$crate::connection::Result<$crate::stream::SendStream>> {
             use s2n_quic_core::stream::StreamType;
             use $crate::stream::SendStream;
 
-            let stream =
-                core::task::ready!(self.0.poll_open_stream(StreamType::Unidirectional, cx))?;
+            let stream = core::task::ready!(self.0.poll_open_stream(
+                StreamType::Unidirectional,
+                priority,
+                cx
+            ))?;
 
             Ok(SendStream::new(stream.into())).into()
         }
 
+        /// Waits for the handshake to be confirmed
+        ///
+        /// Clients are handed the connection as soon as the handshake completes, but only
+        /// consider it confirmed once the server's HANDSHAKE_DONE frame is received. Servers
+        /// confirm the handshake when it completes, so this resolves immediately for accepted
+        /// connections. An error is returned if the connection closes before the handshake
+        /// is confirmed.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let mut handle: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// handle.handshake_confirmed().await?;
+        /// println!("handshake confirmed with {:?}", handle.remote_addr());
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn handshake_confirmed(&mut self) -> $crate::connection::Result<()> {
+            futures::future::poll_fn(|cx| self.poll_handshake_confirmed(cx)).await
+        }
+
+        /// Polls for the handshake to be confirmed
+        ///
+        /// The method will return
+        /// - `Poll::Ready(Ok(()))` if the handshake has been confirmed
+        /// - `Poll::Ready(Err(error))` if the connection closed before the handshake was confirmed
+        /// - `Poll::Pending` if the handshake has not been confirmed yet
+        #[inline]
+        pub fn poll_handshake_confirmed(
+            &mut self,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::connection::Result<()>> {
+            self.0.poll_handshake_confirmed(cx)
+        }
+
         /// Returns the local address that this connection is bound to.
         #[inline]
         pub fn local_addr(&self) -> $crate::connection::Result<std::net::SocketAddr> {
@@ -151,6 +284,164 @@ macro_rules! impl_handle_api {
             self.0.remote_address().map(std::net::SocketAddr::from)
         }
 
+        /// Returns the size of the congestion window, in bytes, on the active path
+        #[inline]
+        pub fn congestion_window(&self) -> $crate::connection::Result<u64> {
+            self.0.congestion_window()
+        }
+
+        /// Returns the number of bytes in flight on the active path
+        ///
+        /// Bytes are considered in flight from when they are sent until they are
+        /// acknowledged or declared lost.
+        #[inline]
+        pub fn bytes_in_flight(&self) -> $crate::connection::Result<u64> {
+            self.0.bytes_in_flight()
+        }
+
+        /// Returns the round trip time statistics for the active path
+        ///
+        /// The estimate is updated each time an ACK frame provides a new RTT sample.
+        #[inline]
+        pub fn rtt_estimate(&self) -> $crate::connection::Result<$crate::connection::RttEstimate> {
+            self.0.rtt_estimate()
+        }
+
+        /// Returns the maximum transmission unit, in bytes, validated for the active path
+        ///
+        /// The MTU starts at 1200 bytes and is raised as path MTU discovery probes are
+        /// acknowledged by the peer. The value does not include the IP or UDP headers.
+        #[inline]
+        pub fn mtu(&self) -> $crate::connection::Result<usize> {
+            self.0.mtu()
+        }
+
+        /// Returns the largest datagram payload, in bytes, that can currently be sent to the peer
+        ///
+        /// The limit is the smaller of the peer's `max_datagram_frame_size` transport parameter
+        /// and the space left in a single packet on the active path, so it can grow as path MTU
+        /// discovery raises the MTU. `None` is returned if the handshake hasn't completed or the
+        /// peer doesn't accept datagrams.
+        #[inline]
+        pub fn max_datagram_size(&self) -> $crate::connection::Result<Option<usize>> {
+            self.0.max_datagram_size()
+        }
+
+        /// Returns the transport parameters advertised by the peer
+        ///
+        /// `None` is returned until the peer's parameters have been received during the
+        /// handshake.
+        #[inline]
+        pub fn peer_transport_parameters(
+            &self,
+        ) -> $crate::connection::Result<Option<$crate::connection::PeerTransportParameters>> {
+            self.0.peer_transport_parameters()
+        }
+
+        /// Returns the delivery rate, in bytes per second, sampled on the active path
+        ///
+        /// The rate is only sampled by rate-based congestion controllers, such as BBR, which
+        /// use it to pace transmissions. `None` is returned if the congestion controller doesn't
+        /// sample the rate or no packets have been acknowledged yet.
+        #[inline]
+        pub fn delivery_rate(&self) -> $crate::connection::Result<Option<u64>> {
+            self.0.delivery_rate()
+        }
+
+        /// Returns a snapshot of the connection's packet and byte counters
+        ///
+        /// The snapshot also includes the smoothed RTT and congestion window of the active path.
+        /// It can still be read after the connection has closed, which is useful for reporting
+        /// the totals for the lifetime of the connection.
+        #[inline]
+        pub fn stats(&self) -> $crate::connection::Result<$crate::connection::ConnectionStats> {
+            self.0.stats()
+        }
+
+        /// Returns the reason the connection was closed
+        ///
+        /// `None` is returned while the connection is still open. Once it closes, the reason
+        /// distinguishes idle timeouts, application and transport closes, and stateless resets.
+        #[inline]
+        pub fn close_reason(&self) -> Option<$crate::connection::ConnectionCloseReason> {
+            self.0.close_reason()
+        }
+
+        /// Returns the paths the connection is currently maintaining
+        ///
+        /// A connection has a single path unless the peer migrates, in which case the new
+        /// path is probed and becomes the active path once it is used for non-probing packets.
+        #[inline]
+        pub fn paths(
+            &self,
+        ) -> $crate::connection::Result<impl Iterator<Item = $crate::connection::PathInfo>> {
+            Ok(self.0.paths()?.into_iter())
+        }
+
+        /// Returns the QUIC version used by the connection
+        ///
+        /// This is the version carried in the long header packets of the handshake, e.g.
+        /// `0x00000001` for QUIC version 1.
+        #[inline]
+        pub fn version(&self) -> $crate::connection::Result<u32> {
+            self.0.quic_version()
+        }
+
+        /// Returns the connection-level flow control limit, in bytes, the peer has
+        /// granted for sending data
+        ///
+        /// The limit is raised each time the peer sends a `MAX_DATA` frame.
+        #[inline]
+        pub fn connection_flow_control_window(&self) -> $crate::connection::Result<u64> {
+            self.0.connection_flow_control_window()
+        }
+
+        /// Returns the flow control limit, in bytes, the peer has granted for sending
+        /// data on the stream with the given ID
+        ///
+        /// The limit is raised each time the peer sends a `MAX_STREAM_DATA` frame.
+        /// `None` is returned if the stream is not open or can't send data.
+        #[inline]
+        pub fn stream_send_window(
+            &self,
+            stream_id: u64,
+        ) -> $crate::connection::Result<Option<u64>> {
+            use s2n_quic_core::{stream::StreamId, varint::VarInt};
+
+            match VarInt::new(stream_id) {
+                Ok(id) => self.0.stream_send_window(StreamId::from_varint(id)),
+                Err(_) => Ok(None),
+            }
+        }
+
+        /// Returns the flow control limit, in bytes, advertised to the peer for
+        /// receiving data on the stream with the given ID
+        ///
+        /// `None` is returned if the stream is not open or can't receive data.
+        #[inline]
+        pub fn stream_recv_window(
+            &self,
+            stream_id: u64,
+        ) -> $crate::connection::Result<Option<u64>> {
+            use s2n_quic_core::{stream::StreamId, varint::VarInt};
+
+            match VarInt::new(stream_id) {
+                Ok(id) => self.0.stream_recv_window(StreamId::from_varint(id)),
+                Err(_) => Ok(None),
+            }
+        }
+
+        /// Returns the IDs of the streams which are blocked from sending data by the
+        /// flow control limit the peer has granted for the stream
+        ///
+        /// While a stream is blocked, `STREAM_DATA_BLOCKED` frames are periodically sent to
+        /// the peer. The stream is no longer reported once a `MAX_STREAM_DATA` frame raises
+        /// the limit.
+        #[inline]
+        pub fn blocked_streams(&self) -> $crate::connection::Result<impl Iterator<Item = u64>> {
+            Ok(self.0.blocked_streams()?.into_iter().map(u64::from))
+        }
+
         /// Returns the negotiated server name the connection is using.
         #[inline]
         pub fn server_name(&self) -> $crate::connection::Result<Option<$crate::server::Name>> {
@@ -6,9 +6,11 @@
 //! The default provider will randomly generate a 256 bit key. This key will be used to sign and
 //! verify tokens. The key can be rotated at a duration set by the user.
 //!
-//! The default provider does not support tokens delivered in a NEW_TOKEN frame.
+//! Tokens delivered in a NEW_TOKEN frame are bound to the client's IP address and are signed with
+//! a separate set of keys which are rotated at the NEW_TOKEN lifetime, since they are presented on
+//! later connections.
 
-use core::{mem::size_of, time::Duration};
+use core::{convert::Infallible, mem::size_of, time::Duration};
 use hash_hasher::HashHasher;
 use s2n_codec::{DecoderBuffer, DecoderBufferMut};
 use s2n_quic_core::{
@@ -79,8 +81,64 @@ impl BaseKey {
     }
 }
 
+/// A pair of signing keys which take turns signing tokens
+///
+/// Each key is valid for two rotation periods, so a token is accepted for at least one rotation
+/// period after it was signed.
+struct KeyRing {
+    /// Key validity period
+    rotation_period: Duration,
+
+    /// Timestamp to rotate current key
+    current_key_rotates_at: Timestamp,
+
+    /// Which key is used to sign
+    current_key: u8,
+
+    /// Key used to sign keys
+    keys: [BaseKey; 2],
+}
+
+impl KeyRing {
+    fn new(rotation_period: Duration, now: Timestamp) -> Self {
+        // The keys must remain valid for two rotation periods or they will regenerate their
+        // material and validation will fail.
+        let active_duration = rotation_period.saturating_mul(2);
+        Self {
+            rotation_period,
+            current_key_rotates_at: now,
+            current_key: 0,
+            keys: [BaseKey::new(active_duration), BaseKey::new(active_duration)],
+        }
+    }
+
+    fn current_key(&mut self) -> u8 {
+        let now = s2n_quic_platform::time::now();
+        if now > self.current_key_rotates_at {
+            self.current_key ^= 1;
+            self.current_key_rotates_at = now + self.rotation_period;
+
+            // TODO either clear the duplicate filter here, or implement in the BaseKey logic
+            // https://github.com/aws/s2n-quic/issues/173
+        }
+        self.current_key
+    }
+
+    fn key(&mut self, key_id: u8) -> &mut BaseKey {
+        &mut self.keys[key_id as usize]
+    }
+}
+
 const DEFAULT_KEY_ROTATION_PERIOD: Duration = Duration::from_millis(1000);
 
+// Signing keys are valid for two rotation periods, so there is no point in accepting
+// tokens for any longer than that.
+const DEFAULT_RETRY_TOKEN_LIFETIME: Duration = Duration::from_millis(2000);
+
+// Clients present NEW_TOKEN tokens on later connections, which can be hours after the token
+// was issued.
+const DEFAULT_NEW_TOKEN_LIFETIME: Duration = Duration::from_secs(12 * 60 * 60);
+
 #[derive(Debug)]
 pub struct Provider {
     //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
@@ -91,31 +149,87 @@ pub struct Provider {
     /// To fulfill this SHOULD, we rotate the key periodically. This allows
     /// customers to control the token lifetime without adding bytes to the token itself.
     key_rotation_period: Duration,
+
+    /// The amount of time a Retry token is accepted after it was issued
+    retry_token_lifetime: Duration,
+
+    /// The amount of time a NEW_TOKEN token is accepted after it was issued
+    new_token_lifetime: Duration,
 }
 
 impl Default for Provider {
     fn default() -> Self {
         Self {
             key_rotation_period: DEFAULT_KEY_ROTATION_PERIOD,
+            retry_token_lifetime: DEFAULT_RETRY_TOKEN_LIFETIME,
+            new_token_lifetime: DEFAULT_NEW_TOKEN_LIFETIME,
+        }
+    }
+}
+
+impl Provider {
+    /// Creates a builder for the provider
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+}
+
+/// A builder for the default address token [`Provider`]
+///
+/// ```rust
+/// use s2n_quic::provider::address_token;
+/// # use std::{error::Error, time::Duration};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let provider = address_token::Default::builder()
+///     .with_new_token_lifetime(Duration::from_secs(60 * 60))?
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Builder {
+    new_token_lifetime: Duration,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            new_token_lifetime: DEFAULT_NEW_TOKEN_LIFETIME,
         }
     }
 }
 
+impl Builder {
+    /// Sets the amount of time a token delivered in a NEW_TOKEN frame is accepted after it was
+    /// issued (default: 12 hours)
+    pub fn with_new_token_lifetime(mut self, lifetime: Duration) -> Result<Self, Infallible> {
+        self.new_token_lifetime = lifetime;
+        Ok(self)
+    }
+
+    /// Builds the provider
+    pub fn build(self) -> Result<Provider, Infallible> {
+        Ok(Provider {
+            new_token_lifetime: self.new_token_lifetime,
+            ..Default::default()
+        })
+    }
+}
+
 impl super::Provider for Provider {
     type Format = Format;
     type Error = core::convert::Infallible;
 
     fn start(self) -> Result<Self::Format, Self::Error> {
-        // The keys must remain valid for two rotation periods or they will regenerate their
-        // material and validation will fail.
+        let now = s2n_quic_platform::time::now();
         let format = Format {
-            key_rotation_period: self.key_rotation_period,
-            current_key_rotates_at: s2n_quic_platform::time::now(),
-            current_key: 0,
-            keys: [
-                BaseKey::new(self.key_rotation_period * 2),
-                BaseKey::new(self.key_rotation_period * 2),
-            ],
+            retry_keys: KeyRing::new(self.key_rotation_period, now),
+            retry_token_lifetime: self.retry_token_lifetime,
+            // Rotating at the token lifetime keeps every key around for as long as the tokens
+            // it signed are accepted
+            new_token_keys: KeyRing::new(self.new_token_lifetime, now),
+            new_token_lifetime: self.new_token_lifetime,
+            epoch: now,
         };
 
         Ok(format)
@@ -129,35 +243,28 @@ pub struct Format {
     //# Servers are encouraged to allow tokens to be used only
     //# once, if possible; tokens MAY include additional information about
     //# clients to further narrow applicability or reuse.
-    /// Key validity period
-    key_rotation_period: Duration,
-
     //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
     //# Servers SHOULD ensure that
     //# tokens sent in Retry packets are only accepted for a short time.
-    /// Timestamp to rotate current key
-    current_key_rotates_at: s2n_quic_core::time::Timestamp,
+    /// Keys used to sign Retry tokens
+    retry_keys: KeyRing,
 
-    /// Which key is used to sign
-    current_key: u8,
+    /// The amount of time a Retry token is accepted after it was issued
+    retry_token_lifetime: Duration,
 
-    /// Key used to sign keys
-    keys: [BaseKey; 2],
-}
+    /// Keys used to sign NEW_TOKEN tokens
+    new_token_keys: KeyRing,
 
-impl Format {
-    fn current_key(&mut self) -> u8 {
-        let now = s2n_quic_platform::time::now();
-        if now > self.current_key_rotates_at {
-            self.current_key ^= 1;
-            self.current_key_rotates_at = now + self.key_rotation_period;
+    /// The amount of time a NEW_TOKEN token is accepted after it was issued
+    new_token_lifetime: Duration,
 
-            // TODO either clear the duplicate filter here, or implement in the BaseKey logic
-            // https://github.com/aws/s2n-quic/issues/173
-        }
-        self.current_key
-    }
+    /// The time the format was started
+    ///
+    /// Token expiration times are encoded relative to this timestamp.
+    epoch: Timestamp,
+}
 
+impl Format {
     // Retry Tokens need to include the original destination connection id from the transport
     // parameters. This OCID is included in the tag.
     fn tag_retry_token(
@@ -165,7 +272,10 @@ impl Format {
         token: &Token,
         context: &mut super::Context<'_>,
     ) -> Option<hmac::Tag> {
-        let mut ctx = self.keys[token.header.key_id() as usize].hasher(context.random)?;
+        let mut ctx = self
+            .retry_keys
+            .key(token.header.key_id())
+            .hasher(context.random)?;
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
         //# Tokens
@@ -174,6 +284,7 @@ impl Format {
         //# packets remain constant.
         ctx.update(&token.original_destination_connection_id);
         ctx.update(&token.nonce);
+        ctx.update(&token.expires_at);
         ctx.update(context.peer_connection_id);
         match context.remote_address {
             SocketAddress::IpV4 { ip, port, .. } => {
@@ -199,7 +310,9 @@ impl Format {
         context: &mut super::Context<'_>,
         token: &Token,
     ) -> Option<connection::InitialId> {
-        if self.keys[token.header.key_id() as usize]
+        if self
+            .retry_keys
+            .key(token.header.key_id())
             .duplicate_filter
             .contains(token)
         {
@@ -208,67 +321,160 @@ impl Format {
 
         let tag = self.tag_retry_token(token, context)?;
 
-        if constant_time::verify_slices_are_equal(&token.hmac, tag.as_ref()).is_ok() {
-            // Only add the token once it has been validated. This will prevent the filter from
-            // being filled with garbage tokens.
+        if constant_time::verify_slices_are_equal(&token.hmac, tag.as_ref()).is_err() {
+            return None;
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //# Servers SHOULD ensure that
+        //# tokens sent in Retry packets are only accepted for a short time.
+        // The expiration time is only trusted after the HMAC has been verified
+        let expires_at = self.epoch + Duration::from_micros(u64::from_be_bytes(token.expires_at));
+        if s2n_quic_platform::time::now() >= expires_at {
+            return None;
+        }
+
+        // Only add the token once it has been validated. This will prevent the filter from
+        // being filled with garbage tokens.
+
+        // Ignore the outcome of adding a token to the filter because we always want to
+        // continue the connection if the filter fails.
+        let _ = self
+            .retry_keys
+            .key(token.header.key_id())
+            .duplicate_filter
+            .add(token);
+
+        token.original_destination_connection_id()
+    }
+
+    // NEW_TOKEN tokens are presented on a later connection from a different port, so only the
+    // client's IP address is included in the tag.
+    fn tag_new_token(
+        &mut self,
+        token: &Token,
+        context: &mut super::Context<'_>,
+    ) -> Option<hmac::Tag> {
+        let mut ctx = self
+            .new_token_keys
+            .key(token.header.key_id())
+            .hasher(context.random)?;
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //# Tokens sent in NEW_TOKEN frames MUST include information that allows
+        //# the server to verify that the client IP address has not changed from
+        //# when the token was issued.
+        ctx.update(&token.nonce);
+        ctx.update(&token.expires_at);
+        match context.remote_address {
+            SocketAddress::IpV4 { ip, .. } => ctx.update(ip),
+            SocketAddress::IpV6 { ip, .. } => ctx.update(ip),
+            _ => {
+                // we are unable to hash the address so bail
+                return None;
+            }
+        };
+
+        Some(ctx.sign())
+    }
+
+    fn validate_new_token_frame(
+        &mut self,
+        context: &mut super::Context<'_>,
+        token: &[u8],
+    ) -> Option<()> {
+        let token = Self::decode_token(token)?;
+
+        if token.header.token_source() != Source::NewTokenFrame {
+            return None;
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //# Tokens that are provided
+        //# in NEW_TOKEN frames (Section 19.7) need to be valid for longer but
+        //# SHOULD NOT be accepted multiple times.
+        let key_id = token.header.key_id();
+        if self
+            .new_token_keys
+            .key(key_id)
+            .duplicate_filter
+            .contains(token)
+        {
+            return None;
+        }
+
+        let tag = self.tag_new_token(token, context)?;
 
-            // Ignore the outcome of adding a token to the filter because we always want to
-            // continue the connection if the filter fails.
-            let _ = self.keys[token.header.key_id() as usize]
-                .duplicate_filter
-                .add(token);
+        constant_time::verify_slices_are_equal(&token.hmac, tag.as_ref()).ok()?;
 
-            return token.original_destination_connection_id();
+        // The expiration time is only trusted after the HMAC has been verified
+        let expires_at = self.epoch + Duration::from_micros(u64::from_be_bytes(token.expires_at));
+        if s2n_quic_platform::time::now() >= expires_at {
+            return None;
         }
 
-        None
+        // Ignore the outcome of adding a token to the filter because we always want to
+        // continue the connection if the filter fails.
+        let _ = self.new_token_keys.key(key_id).duplicate_filter.add(token);
+
+        Some(())
+    }
+
+    fn decode_token(token: &[u8]) -> Option<&Token> {
+        let buffer = DecoderBuffer::new(token);
+        let (token, remaining) = buffer.decode::<&Token>().ok()?;
+
+        // Verify the provided token doesn't have any additional data
+        remaining.ensure_empty().ok()?;
+
+        if token.header.version() != TOKEN_VERSION {
+            return None;
+        }
+
+        Some(token)
     }
 }
 
 impl super::Format for Format {
     const TOKEN_LEN: usize = size_of::<Token>();
 
-    /// The default provider does not support NEW_TOKEN frame tokens
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
+    //# A server MAY provide clients with an address validation token during
+    //# one connection that can be used on a subsequent connection.
     fn generate_new_token(
         &mut self,
-        _context: &mut super::Context<'_>,
+        context: &mut super::Context<'_>,
         _source_connection_id: &connection::LocalId,
-        _output_buffer: &mut [u8],
+        output_buffer: &mut [u8],
     ) -> Option<()> {
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
-        //= type=TODO
-        //= tracking-issue=418
-        //# A server MAY provide clients with an address validation token during
-        //# one connection that can be used on a subsequent connection.
+        let buffer = DecoderBufferMut::new(output_buffer);
+        let (token, _) = buffer
+            .decode::<&mut Token>()
+            .expect("Provided output buffer did not match TOKEN_LEN");
 
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
-        //= type=TODO
-        //= tracking-issue=346
-        //# Tokens sent in NEW_TOKEN frames MUST include information that allows
-        //# the server to verify that the client IP address has not changed from
-        //# when the token was issued.
+        token.header = Header::new(Source::NewTokenFrame, self.new_token_keys.current_key());
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
-        //= type=TODO
-        //= tracking-issue=345
         //# A token issued with NEW_TOKEN MUST NOT include information that would
         //# allow values to be linked by an observer to the connection on which
         //# it was issued.
+        token.odcid_len = 0;
+        token.original_destination_connection_id = [0; 20];
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
-        //= type=TODO
-        //= tracking-issue=387
         //# A server MUST ensure that every NEW_TOKEN frame it sends
         //# is unique across all clients, with the exception of those sent to
         //# repair losses of previously sent NEW_TOKEN frames.
+        context.random.public_random_fill(&mut token.nonce[..]);
+        let expires_at = s2n_quic_platform::time::now() + self.new_token_lifetime;
+        let expires_at = expires_at.saturating_duration_since(self.epoch).as_micros() as u64;
+        token.expires_at = expires_at.to_be_bytes();
 
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
-        //= type=TODO
-        //= tracking-issue=394
-        //# A server MAY provide clients with an address validation token during
-        //# one connection that can be used on a subsequent connection.
+        let tag = self.tag_new_token(token, context)?;
+
+        token.hmac.copy_from_slice(tag.as_ref());
 
-        None
+        Some(())
     }
 
     //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.2
@@ -289,7 +495,7 @@ impl super::Format for Format {
             .decode::<&mut Token>()
             .expect("Provided output buffer did not match TOKEN_LEN");
 
-        let header = Header::new(Source::RetryPacket, self.current_key());
+        let header = Header::new(Source::RetryPacket, self.retry_keys.current_key());
 
         token.header = header;
         token.original_destination_connection_id[..original_destination_connection_id.len()]
@@ -305,8 +511,11 @@ impl super::Format for Format {
             *b = 0;
         }
 
-        // Populate the nonce before signing
+        // Populate the nonce and expiration time before signing
         context.random.public_random_fill(&mut token.nonce[..]);
+        let expires_at = s2n_quic_platform::time::now() + self.retry_token_lifetime;
+        let expires_at = expires_at.saturating_duration_since(self.epoch).as_micros() as u64;
+        token.expires_at = expires_at.to_be_bytes();
 
         let tag = self.tag_retry_token(token, context)?;
 
@@ -324,35 +533,23 @@ impl super::Format for Format {
         context: &mut super::Context<'_>,
         token: &[u8],
     ) -> Option<connection::InitialId> {
-        let buffer = DecoderBuffer::new(token);
-        let (token, remaining) = buffer.decode::<&Token>().ok()?;
-
-        // Verify the provided token doesn't have any additional data
-        remaining.ensure_empty().ok()?;
+        let token = Self::decode_token(token)?;
 
-        if token.header.version() != TOKEN_VERSION {
-            return None;
-        }
-
-        let source = token.header.token_source();
-
-        match source {
+        match token.header.token_source() {
             Source::RetryPacket => self.validate_retry_token(context, token),
-            Source::NewTokenFrame => None, // Not supported in the default provider
+            // NEW_TOKEN tokens don't carry an original destination connection id
+            Source::NewTokenFrame => None,
         }
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
-        //= type=TODO
-        //= tracking-issue=347
-        //# Tokens that are provided
-        //# in NEW_TOKEN frames (Section 19.7) need to be valid for longer but
-        //# SHOULD NOT be accepted multiple times.
-
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
         //= type=TODO
         //= tracking-issue=388
         //# Clients that want to break continuity of identity with a server can
         //# discard tokens provided using the NEW_TOKEN frame.
     }
+
+    fn validate_new_token(&mut self, context: &mut super::Context<'_>, token: &[u8]) -> bool {
+        self.validate_new_token_frame(context, token).is_some()
+    }
 }
 
 #[derive(Clone, Copy, Debug, FromBytes, FromZeroes, AsBytes, Unaligned)]
@@ -430,6 +627,10 @@ struct Token {
     //# sends to clients.
     nonce: [u8; 32],
 
+    /// The time after which the token is no longer accepted, in microseconds since the
+    /// `Format` epoch
+    expires_at: [u8; 8],
+
     //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
     //# A token-based scheme allows the server to offload any state
     //# associated with validation to the client.  For this design to work,
@@ -462,6 +663,7 @@ impl Token {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::provider::address_token::Provider as _;
     use s2n_quic_core::{
         inet::SocketAddress,
         random,
@@ -474,13 +676,11 @@ mod tests {
 
     fn get_test_format() -> Format {
         Format {
-            key_rotation_period: TEST_KEY_ROTATION_PERIOD,
-            keys: [
-                BaseKey::new(TEST_KEY_ROTATION_PERIOD * 2),
-                BaseKey::new(TEST_KEY_ROTATION_PERIOD * 2),
-            ],
-            current_key_rotates_at: time::now(),
-            current_key: 0,
+            retry_keys: KeyRing::new(TEST_KEY_ROTATION_PERIOD, time::now()),
+            retry_token_lifetime: TEST_KEY_ROTATION_PERIOD * 2,
+            new_token_keys: KeyRing::new(TEST_KEY_ROTATION_PERIOD, time::now()),
+            new_token_lifetime: TEST_KEY_ROTATION_PERIOD * 2,
+            epoch: time::now(),
         }
     }
 
@@ -586,6 +786,164 @@ mod tests {
         assert!(format.validate_token(&mut context, &token).is_some());
     }
 
+    #[test]
+    fn test_new_token_ip_validation() {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //= type=test
+        //# Tokens sent in NEW_TOKEN frames MUST include information that allows
+        //# the server to verify that the client IP address has not changed from
+        //# when the token was issued.
+        let mut format = get_test_format();
+        let local_conn_id = connection::LocalId::try_from_bytes(&[1, 2, 3, 4]).unwrap();
+        let first_conn_id = connection::PeerId::try_from_bytes(&[2, 4, 6, 8, 10]).unwrap();
+        let second_conn_id = connection::PeerId::try_from_bytes(&[1, 3, 5, 7, 9]).unwrap();
+
+        let mut token = [0; Format::TOKEN_LEN];
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let issued_address: SocketAddress = addr.into();
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&issued_address, &first_conn_id, &mut random);
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut token)
+            .unwrap();
+
+        // NEW_TOKEN tokens can't be used in place of a Retry token
+        assert_eq!(format.validate_token(&mut context, &token), None);
+
+        let addr: SocketAddr = "127.0.0.2:443".parse().unwrap();
+        let incorrect_address: SocketAddress = addr.into();
+        context = Context::new(&incorrect_address, &first_conn_id, &mut random);
+        assert!(!format.validate_new_token(&mut context, &token));
+
+        // The token is presented on a new connection so the port and peer connection id change
+        let addr: SocketAddr = "127.0.0.1:444".parse().unwrap();
+        let new_port: SocketAddress = addr.into();
+        context = Context::new(&new_port, &second_conn_id, &mut random);
+        assert!(format.validate_new_token(&mut context, &token));
+    }
+
+    #[test]
+    fn test_new_token_duplicate() {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //= type=test
+        //# Tokens that are provided
+        //# in NEW_TOKEN frames (Section 19.7) need to be valid for longer but
+        //# SHOULD NOT be accepted multiple times.
+        let mut format = get_test_format();
+        let local_conn_id = connection::LocalId::try_from_bytes(&[1, 2, 3, 4]).unwrap();
+        let conn_id = connection::PeerId::try_from_bytes(&[2, 4, 6, 8, 10]).unwrap();
+        let addr = SocketAddress::default();
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+
+        let mut first_token = [0; Format::TOKEN_LEN];
+        let mut second_token = [0; Format::TOKEN_LEN];
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut first_token)
+            .unwrap();
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut second_token)
+            .unwrap();
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
+        //= type=test
+        //# A server MUST ensure that every NEW_TOKEN frame it sends
+        //# is unique across all clients, with the exception of those sent to
+        //# repair losses of previously sent NEW_TOKEN frames.
+        assert_ne!(first_token, second_token);
+
+        assert!(format.validate_new_token(&mut context, &first_token));
+        assert!(!format.validate_new_token(&mut context, &first_token));
+        assert!(format.validate_new_token(&mut context, &second_token));
+    }
+
+    #[test]
+    fn test_expired_new_token() {
+        let clock = Arc::new(time::testing::MockClock::new());
+        time::testing::set_local_clock(clock.clone());
+
+        let mut format = get_test_format();
+        format.new_token_lifetime = TEST_KEY_ROTATION_PERIOD / 2;
+        let local_conn_id = connection::LocalId::try_from_bytes(&[1, 2, 3, 4]).unwrap();
+        let conn_id = connection::PeerId::try_from_bytes(&[2, 4, 6, 8, 10]).unwrap();
+        let addr = SocketAddress::default();
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+
+        let mut token = [0; Format::TOKEN_LEN];
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut token)
+            .unwrap();
+
+        clock.adjust_by(TEST_KEY_ROTATION_PERIOD / 2);
+        assert!(!format.validate_new_token(&mut context, &token));
+    }
+
+    #[test]
+    fn test_new_token_default_lifetime() {
+        let clock = Arc::new(time::testing::MockClock::new());
+        time::testing::set_local_clock(clock.clone());
+
+        let mut format = Provider::default().start().unwrap();
+        let local_conn_id = connection::LocalId::try_from_bytes(&[1, 2, 3, 4]).unwrap();
+        let conn_id = connection::PeerId::try_from_bytes(&[2, 4, 6, 8, 10]).unwrap();
+        let addr = SocketAddress::default();
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+
+        let mut first_token = [0; Format::TOKEN_LEN];
+        let mut second_token = [0; Format::TOKEN_LEN];
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut first_token)
+            .unwrap();
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut second_token)
+            .unwrap();
+
+        // The token outlives many rotations of the Retry signing keys
+        clock.adjust_by(DEFAULT_NEW_TOKEN_LIFETIME - Duration::from_secs(1));
+        assert!(format.validate_new_token(&mut context, &first_token));
+
+        clock.adjust_by(Duration::from_secs(1));
+        assert!(!format.validate_new_token(&mut context, &second_token));
+    }
+
+    #[test]
+    fn test_new_token_lifetime_builder() {
+        let clock = Arc::new(time::testing::MockClock::new());
+        time::testing::set_local_clock(clock.clone());
+
+        let lifetime = Duration::from_secs(60);
+        let mut format = Provider::builder()
+            .with_new_token_lifetime(lifetime)
+            .unwrap()
+            .build()
+            .unwrap()
+            .start()
+            .unwrap();
+        let local_conn_id = connection::LocalId::try_from_bytes(&[1, 2, 3, 4]).unwrap();
+        let conn_id = connection::PeerId::try_from_bytes(&[2, 4, 6, 8, 10]).unwrap();
+        let addr = SocketAddress::default();
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+
+        let mut first_token = [0; Format::TOKEN_LEN];
+        let mut second_token = [0; Format::TOKEN_LEN];
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut first_token)
+            .unwrap();
+
+        // Tokens signed right before the keys rotate are accepted for the whole lifetime
+        clock.adjust_by(lifetime);
+        format
+            .generate_new_token(&mut context, &local_conn_id, &mut second_token)
+            .unwrap();
+        assert!(!format.validate_new_token(&mut context, &first_token));
+
+        clock.adjust_by(lifetime - Duration::from_millis(1));
+        assert!(format.validate_new_token(&mut context, &second_token));
+    }
+
     #[test]
     fn test_key_rotation() {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.3
@@ -647,6 +1005,70 @@ mod tests {
         assert!(format.validate_token(&mut context, &buf).is_none());
     }
 
+    #[test]
+    fn test_retry_token_lifetime() {
+        let clock = Arc::new(time::testing::MockClock::new());
+        time::testing::set_local_clock(clock.clone());
+
+        let mut format = get_test_format();
+        format.retry_token_lifetime = TEST_KEY_ROTATION_PERIOD / 2;
+        let conn_id = connection::PeerId::TEST_ID;
+        let orig_conn_id = connection::InitialId::TEST_ID;
+        let addr = SocketAddress::default();
+        let mut first_token = [0; Format::TOKEN_LEN];
+        let mut second_token = [0; Format::TOKEN_LEN];
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+        format
+            .generate_retry_token(&mut context, &orig_conn_id, &mut first_token)
+            .unwrap();
+        format
+            .generate_retry_token(&mut context, &orig_conn_id, &mut second_token)
+            .unwrap();
+
+        // The token is accepted until it expires
+        clock.adjust_by(format.retry_token_lifetime - Duration::from_millis(1));
+        assert_eq!(
+            format.validate_token(&mut context, &first_token),
+            Some(orig_conn_id)
+        );
+
+        // Validation should fail because the token expired, even though the signing key
+        // is still valid
+        clock.adjust_by(Duration::from_millis(1));
+        assert!(format.validate_token(&mut context, &second_token).is_none());
+    }
+
+    #[test]
+    fn test_retry_token_expiration_tampering() {
+        let clock = Arc::new(time::testing::MockClock::new());
+        time::testing::set_local_clock(clock.clone());
+
+        let mut format = get_test_format();
+        format.retry_token_lifetime = TEST_KEY_ROTATION_PERIOD / 2;
+        let conn_id = connection::PeerId::TEST_ID;
+        let orig_conn_id = connection::InitialId::TEST_ID;
+        let addr = SocketAddress::default();
+        let mut buf = [0; Format::TOKEN_LEN];
+        let mut random = random::testing::Generator(5);
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+        format
+            .generate_retry_token(&mut context, &orig_conn_id, &mut buf)
+            .unwrap();
+
+        // Push the expiration time of the token further into the future
+        let (token, _) = DecoderBufferMut::new(&mut buf)
+            .decode::<&mut Token>()
+            .unwrap();
+        let expires_at =
+            u64::from_be_bytes(token.expires_at) + TEST_KEY_ROTATION_PERIOD.as_micros() as u64;
+        token.expires_at = expires_at.to_be_bytes();
+
+        // Validation should fail because the HMAC no longer matches the token
+        clock.adjust_by(format.retry_token_lifetime);
+        assert!(format.validate_token(&mut context, &buf).is_none());
+    }
+
     #[test]
     fn test_retry_validation_default_format() {
         let clock = Arc::new(time::testing::MockClock::new());
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub use s2n_quic_core::packet::interceptor::{
-    loss, Disabled, Havoc, Interceptor as PacketInterceptor, Loss,
+    loss, Disabled, DropEveryN, Havoc, Interceptor as PacketInterceptor, Loss,
 };
 
 /// Provides packet_interceptor support for an endpoint
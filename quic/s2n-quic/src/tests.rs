@@ -29,14 +29,31 @@ mod setup;
 use setup::*;
 
 mod blackhole;
+mod blocked_streams;
+mod close_reason;
+mod coalescing;
+mod connection_id_len;
 mod connection_migration;
+mod datagram;
 mod handshake_cid_rotation;
+mod handshake_confirmed;
+mod idle_timeout;
 mod interceptor;
+mod keep_alive;
 mod mtu;
+mod new_token;
 mod no_tls;
+mod peer_transport_parameters;
+mod ping;
 mod pto;
+mod reorder;
 mod self_test;
 mod skip_packets;
+mod stats;
+mod stream_limits;
+mod stream_priority;
+mod transmission_budget;
+mod version;
 
 // TODO: https://github.com/aws/s2n-quic/issues/1726
 //
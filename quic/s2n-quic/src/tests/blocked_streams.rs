@@ -0,0 +1,86 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use s2n_quic_core::event::api::Frame;
+
+/// A stream which reaches the peer's MAX_STREAM_DATA limit is reported as blocked and
+/// sends STREAM_DATA_BLOCKED until the peer raises the limit
+#[test]
+fn blocked_streams_test() {
+    let model = Model::default();
+    let subscriber = recorder::FrameSent::new();
+    let events = subscriber.events();
+    let stream_id = Arc::new(Mutex::new(None));
+    let blocked_stream_id = stream_id.clone();
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(
+                provider::limits::Limits::default()
+                    .with_bidirectional_remote_data_window(1_000)
+                    .unwrap(),
+            )?
+            .start()?;
+        let server_addr = server.local_addr()?;
+
+        spawn(async move {
+            while let Some(mut connection) = server.accept().await {
+                spawn(async move {
+                    let mut stream = connection
+                        .accept_bidirectional_stream()
+                        .await
+                        .unwrap()
+                        .unwrap();
+
+                    // hold off reading so the client stays blocked for a while
+                    delay(Duration::from_millis(500)).await;
+                    while let Ok(Some(_)) = stream.receive().await {}
+                });
+            }
+        });
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), subscriber))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            *stream_id.lock().unwrap() = Some(stream.id());
+            stream.send(Bytes::from(vec![42; 3_000])).await.unwrap();
+            stream.finish().unwrap();
+
+            delay(Duration::from_millis(100)).await;
+            let blocked: Vec<_> = connection.blocked_streams().unwrap().collect();
+            assert_eq!(blocked, [stream.id()]);
+
+            // the server reading the data raises the limit until the whole stream is sent
+            let mut attempts = 0;
+            while connection.blocked_streams().unwrap().count() > 0 {
+                assert!(attempts < 100, "stream is still blocked");
+                attempts += 1;
+                delay(Duration::from_millis(100)).await;
+            }
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let stream_id = blocked_stream_id.lock().unwrap().unwrap();
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|event| matches!(
+        event.frame,
+        Frame::StreamDataBlocked { stream_id: id, stream_data_limit: 1_000, .. } if id == stream_id
+    )));
+}
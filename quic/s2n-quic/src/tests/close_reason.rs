@@ -0,0 +1,105 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{application, connection::ConnectionCloseReason};
+use s2n_quic_core::{endpoint, varint::VarInt};
+
+/// The error code used by the client to close connections
+const ERROR_CODE: u8 = 123;
+
+/// Connects to a server and returns the close reason reported by the server's connection
+///
+/// The client closes the connection with `close_error`, or drops it if `None` is provided.
+fn server_close_reason(close_error: Option<application::Error>) -> ConnectionCloseReason {
+    let model = Model::default();
+    let reason = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let server_addr = server.local_addr()?;
+
+        let server_reason = reason.clone();
+        primary::spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+
+            // wait for the client to close the connection
+            while let Ok(Some(_stream)) = connection.accept().await {}
+
+            *server_reason.lock().unwrap() = connection.close_reason();
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+            assert_eq!(connection.close_reason(), None);
+
+            if let Some(error) = close_error {
+                connection.close(error);
+                assert_eq!(
+                    connection.close_reason(),
+                    Some(ConnectionCloseReason::Application {
+                        error: Some(error),
+                        initiator: endpoint::Location::Local,
+                    })
+                );
+            }
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let reason = reason.lock().unwrap().take();
+    reason.expect("the server connection should have closed")
+}
+
+#[test]
+fn application_close_reason_test() {
+    let error = application::Error::from(VarInt::from_u8(ERROR_CODE));
+    assert_eq!(
+        server_close_reason(Some(error)),
+        ConnectionCloseReason::Application {
+            error: Some(error),
+            initiator: endpoint::Location::Remote,
+        }
+    );
+}
+
+#[test]
+fn graceful_close_reason_test() {
+    assert_eq!(
+        server_close_reason(None),
+        ConnectionCloseReason::Application {
+            error: None,
+            initiator: endpoint::Location::Remote,
+        }
+    );
+}
+
+#[test]
+fn idle_timeout_close_reason_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+            assert_eq!(connection.close_reason(), None);
+
+            // sit idle for longer than the default 30s idle timeout
+            delay(Duration::from_secs(60)).await;
+
+            assert_eq!(
+                connection.close_reason(),
+                Some(ConnectionCloseReason::IdleTimeout)
+            );
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+}
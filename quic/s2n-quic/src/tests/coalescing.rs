@@ -0,0 +1,274 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use s2n_codec::{DecoderBufferMut, EncoderBuffer};
+use s2n_quic_core::{
+    connection::{self, id::ConnectionInfo},
+    event::api::Subject,
+    inet::SocketAddress,
+    packet::{
+        interceptor::{Datagram, Interceptor},
+        ProtectedPacket,
+    },
+};
+
+/// The smallest datagram a client is allowed to send an Initial packet in
+///
+/// See https://www.rfc-editor.org/rfc/rfc9000#section-14.1
+const MIN_INITIAL_DATAGRAM_LEN: usize = 1200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PacketType {
+    Initial,
+    Handshake,
+    Other,
+}
+
+/// The length of a transmitted datagram along with the types of the packets it contains
+type DatagramInfo = (usize, Vec<PacketType>);
+
+/// Records the packet types of every datagram that is transmitted
+#[derive(Clone, Default)]
+struct DatagramRecorder {
+    datagrams: Arc<Mutex<Vec<DatagramInfo>>>,
+}
+
+impl DatagramRecorder {
+    fn datagrams(&self) -> Vec<DatagramInfo> {
+        self.datagrams.lock().unwrap().clone()
+    }
+}
+
+impl Interceptor for DatagramRecorder {
+    fn intercept_tx_datagram(
+        &mut self,
+        _subject: &Subject,
+        _datagram: &Datagram,
+        payload: &mut EncoderBuffer,
+    ) {
+        let (payload, _) = payload.split_mut();
+        let datagram_len = payload.len();
+
+        let remote_address = SocketAddress::default();
+        let connection_info = ConnectionInfo::new(&remote_address);
+
+        let mut packets = vec![];
+        let mut buffer = DecoderBufferMut::new(payload);
+        let mut consumed = 0;
+
+        while !buffer.is_empty() {
+            let before = buffer.len();
+            let (packet, remaining) = ProtectedPacket::decode(buffer, &connection_info, &20)
+                .expect("coalesced packets should decode back-to-back");
+
+            packets.push(match packet {
+                ProtectedPacket::Initial(_) => PacketType::Initial,
+                ProtectedPacket::Handshake(_) => PacketType::Handshake,
+                _ => PacketType::Other,
+            });
+
+            consumed += before - remaining.len();
+            buffer = remaining;
+        }
+
+        // the length fields of the long header packets should account for the entire datagram
+        assert_eq!(consumed, datagram_len);
+
+        self.datagrams.lock().unwrap().push((datagram_len, packets));
+    }
+}
+
+/// Sends a single unreliable datagram as soon as the application space is available
+///
+/// This gives the ApplicationData space something to send while the Initial and Handshake spaces
+/// are still active.
+#[derive(Clone, Copy, Default)]
+struct EagerDatagram {
+    sent: bool,
+}
+
+impl provider::datagram::Endpoint for EagerDatagram {
+    type Sender = Self;
+    type Receiver = Self;
+
+    fn create_connection(
+        &mut self,
+        _info: &provider::datagram::ConnectionInfo,
+    ) -> (Self::Sender, Self::Receiver) {
+        (Self::default(), Self::default())
+    }
+
+    fn max_datagram_frame_size(&self, _info: &provider::datagram::PreConnectionInfo) -> u64 {
+        u16::MAX as u64
+    }
+}
+
+impl provider::datagram::Sender for EagerDatagram {
+    fn on_transmit<P: provider::datagram::Packet>(&mut self, packet: &mut P) {
+        self.sent |= packet.write_datagram(&[1, 2, 3]).is_ok();
+    }
+
+    fn has_transmission_interest(&self) -> bool {
+        !self.sent
+    }
+
+    fn on_connection_error(&mut self, _error: connection::Error) {}
+}
+
+impl provider::datagram::Receiver for EagerDatagram {
+    fn on_datagram(&mut self, _context: &provider::datagram::ReceiveContext<'_>, _datagram: &[u8]) {
+    }
+
+    fn on_connection_error(&mut self, _error: connection::Error) {}
+}
+
+/// Runs a connection with the given limits and returns the datagrams sent by the server and client
+fn record_datagrams(limits: provider::limits::Limits) -> (Vec<DatagramInfo>, Vec<DatagramInfo>) {
+    record_datagrams_with(limits, provider::datagram::Default::default)
+}
+
+/// Runs a connection with the given limits and datagram providers and returns the datagrams sent
+/// by the server and client
+fn record_datagrams_with<D: provider::datagram::Provider>(
+    limits: provider::limits::Limits,
+    datagram: impl Fn() -> D,
+) -> (Vec<DatagramInfo>, Vec<DatagramInfo>) {
+    let model = Model::default();
+
+    let server_recorder = DatagramRecorder::default();
+    let client_recorder = DatagramRecorder::default();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(limits)?
+            .with_datagram(datagram())?
+            .with_packet_interceptor(server_recorder.clone())?
+            .start()?;
+        let server_address = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(limits)?
+            .with_datagram(datagram())?
+            .with_packet_interceptor(client_recorder.clone())?
+            .start()?;
+
+        start_client(client, server_address, Data::new(1000))
+    })
+    .unwrap();
+
+    (server_recorder.datagrams(), client_recorder.datagrams())
+}
+
+#[test]
+fn initial_and_handshake_coalescing_test() {
+    let (server_datagrams, client_datagrams) = record_datagrams(Default::default());
+
+    // the server's first flight should carry both its Initial and Handshake packets
+    let (_, first_flight) = &server_datagrams[0];
+    assert_eq!(
+        &first_flight[..2],
+        &[PacketType::Initial, PacketType::Handshake],
+        "server datagrams: {server_datagrams:?}"
+    );
+
+    let mut client_initials = 0;
+
+    for (len, packets) in &client_datagrams {
+        if packets.contains(&PacketType::Initial) {
+            client_initials += 1;
+            // client datagrams containing an Initial packet must be padded, even when coalesced
+            assert!(
+                *len >= MIN_INITIAL_DATAGRAM_LEN,
+                "client datagrams: {client_datagrams:?}"
+            );
+        }
+    }
+
+    assert!(client_initials > 0);
+}
+
+#[test]
+fn max_coalesced_packets_test() {
+    let (server_datagrams, client_datagrams) = record_datagrams(Default::default());
+    let datagrams: Vec<_> = server_datagrams.iter().chain(&client_datagrams).collect();
+
+    // by default, packets from multiple spaces are coalesced into a single datagram
+    assert!(
+        datagrams.iter().any(|(_, packets)| packets.len() > 1),
+        "datagrams: {datagrams:?}"
+    );
+
+    let limits = provider::limits::Limits::default()
+        .with_max_coalesced_packets(1)
+        .unwrap();
+    let (server_datagrams, client_datagrams) = record_datagrams(limits);
+    let datagrams: Vec<_> = server_datagrams.iter().chain(&client_datagrams).collect();
+
+    // each datagram carries a single packet even though other spaces had data to send
+    assert!(
+        datagrams.iter().all(|(_, packets)| packets.len() == 1),
+        "datagrams: {datagrams:?}"
+    );
+
+    // the handshake still completes, with the server sending its Handshake packets separately
+    assert!(server_datagrams
+        .iter()
+        .any(|(_, packets)| packets == &[PacketType::Handshake]));
+
+    // datagrams containing an Initial packet are still padded
+    for (len, packets) in &client_datagrams {
+        if packets.contains(&PacketType::Initial) {
+            assert!(
+                *len >= MIN_INITIAL_DATAGRAM_LEN,
+                "client datagrams: {client_datagrams:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn max_coalesced_packets_defers_third_space_test() {
+    let (_, client_datagrams) = record_datagrams_with(Default::default(), EagerDatagram::default);
+
+    // with the default limit, the client's datagram finishing the handshake carries a packet from
+    // every space
+    assert!(
+        client_datagrams.iter().any(|(_, packets)| packets
+            == &[
+                PacketType::Initial,
+                PacketType::Handshake,
+                PacketType::Other
+            ]),
+        "client datagrams: {client_datagrams:?}"
+    );
+
+    let limits = provider::limits::Limits::default()
+        .with_max_coalesced_packets(2)
+        .unwrap();
+    let (server_datagrams, client_datagrams) =
+        record_datagrams_with(limits, EagerDatagram::default);
+    let datagrams: Vec<_> = server_datagrams.iter().chain(&client_datagrams).collect();
+
+    assert!(
+        datagrams.iter().all(|(_, packets)| packets.len() <= 2),
+        "datagrams: {datagrams:?}"
+    );
+
+    // the ApplicationData packet is deferred to the datagram after the Initial and Handshake packets
+    let index = client_datagrams
+        .iter()
+        .position(|(_, packets)| packets == &[PacketType::Initial, PacketType::Handshake])
+        .unwrap_or_else(|| panic!("client datagrams: {client_datagrams:?}"));
+    let (len, _) = client_datagrams[index];
+    assert!(len >= MIN_INITIAL_DATAGRAM_LEN);
+    assert_eq!(client_datagrams[index + 1].1, [PacketType::Other]);
+}
@@ -0,0 +1,155 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::connection_id::{self, ConnectionInfo, Generator, LocalId, Validator};
+use s2n_codec::{DecoderBufferMut, DecoderValueMut};
+use s2n_quic_core::{
+    event::api::Subject,
+    frame::{Frame, FrameMut},
+    packet::interceptor::{Interceptor, Packet},
+};
+
+/// Records every connection ID received in a NEW_CONNECTION_ID frame
+#[derive(Clone, Default)]
+struct NewConnectionIds(Arc<Mutex<Vec<Vec<u8>>>>);
+
+impl Interceptor for NewConnectionIds {
+    fn intercept_rx_payload<'a>(
+        &mut self,
+        _subject: &Subject,
+        _packet: &Packet,
+        payload: DecoderBufferMut<'a>,
+    ) -> DecoderBufferMut<'a> {
+        // decode a copy of the payload so it's passed through unmodified
+        let mut frames = payload.peek().into_less_safe_slice().to_vec();
+        let mut buffer = DecoderBufferMut::new(&mut frames);
+
+        while !buffer.is_empty() {
+            let (frame, remaining) = FrameMut::decode_mut(buffer).unwrap();
+            if let Frame::NewConnectionId(frame) = frame {
+                self.0.lock().unwrap().push(frame.connection_id.to_vec());
+            }
+            buffer = remaining;
+        }
+
+        payload
+    }
+}
+
+/// Returns the connection IDs the server issued to the client with NEW_CONNECTION_ID frames
+fn new_connection_ids<F: connection_id::Provider>(connection_id: F) -> Vec<Vec<u8>> {
+    let model = Model::default();
+    let interceptor = NewConnectionIds::default();
+    let ids = interceptor.0.clone();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_connection_id(connection_id)?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_packet_interceptor(interceptor)?
+            .start()?;
+
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(10_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let ids = ids.lock().unwrap();
+    ids.clone()
+}
+
+#[test]
+fn new_connection_id_len_test() {
+    for len in [
+        s2n_quic_core::connection::LocalId::MIN_LEN,
+        8,
+        s2n_quic_core::connection::id::MAX_LEN,
+    ] {
+        let connection_id = connection_id::default::Format::builder()
+            .with_len(len)
+            .unwrap()
+            .build()
+            .unwrap();
+        let lens: Vec<_> = new_connection_ids(connection_id)
+            .iter()
+            .map(Vec::len)
+            .collect();
+
+        // the client allows more than one active connection ID so the server issues at least one
+        assert!(!lens.is_empty(), "no NEW_CONNECTION_ID frames received");
+        assert!(lens.iter().all(|&actual| actual == len), "{len}: {lens:?}");
+    }
+}
+
+/// A connection ID format that encodes a routing prefix followed by a sequence number, as a
+/// load balancer might require
+struct RoutingFormat {
+    next: u32,
+}
+
+impl RoutingFormat {
+    const PREFIX: &'static [u8] = b"lb";
+    const LEN: usize = Self::PREFIX.len() + 4;
+}
+
+impl Generator for RoutingFormat {
+    fn generate(&mut self, _connection_info: &ConnectionInfo) -> LocalId {
+        let mut id = Self::PREFIX.to_vec();
+        id.extend_from_slice(&self.next.to_be_bytes());
+        self.next += 1;
+        LocalId::try_from_bytes(&id).unwrap()
+    }
+}
+
+impl Validator for RoutingFormat {
+    fn validate(&self, _connection_info: &ConnectionInfo, buffer: &[u8]) -> Option<usize> {
+        if buffer.len() >= Self::LEN && buffer.starts_with(Self::PREFIX) {
+            Some(Self::LEN)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn custom_connection_id_generator_test() {
+    let ids = new_connection_ids(RoutingFormat { next: 0 });
+
+    assert!(!ids.is_empty(), "no NEW_CONNECTION_ID frames received");
+
+    let mut sequence_numbers = vec![];
+    for id in &ids {
+        assert_eq!(id.len(), RoutingFormat::LEN, "{id:?}");
+        assert!(id.starts_with(RoutingFormat::PREFIX), "{id:?}");
+        let sequence_number =
+            u32::from_be_bytes(id[RoutingFormat::PREFIX.len()..].try_into().unwrap());
+        sequence_numbers.push(sequence_number);
+    }
+
+    // the handshake connection ID is generated first so the issued IDs start after it
+    assert!(
+        sequence_numbers.iter().all(|&n| n > 0),
+        "{sequence_numbers:?}"
+    );
+    // every issued connection ID came from a distinct call to the generator
+    let mut deduped = sequence_numbers.clone();
+    deduped.sort_unstable();
+    deduped.dedup();
+    assert_eq!(
+        deduped.len(),
+        sequence_numbers.len(),
+        "{sequence_numbers:?}"
+    );
+}
@@ -39,12 +39,14 @@ where
 
     let active_paths = recorder::ActivePathUpdated::new();
     let active_path_sub = active_paths.clone();
+    let path_validations = recorder::PathValidated::new();
+    let path_validation_sub = path_validations.clone();
 
     test(model, move |handle| {
         let server = Server::builder()
             .with_io(handle.builder().build()?)?
             .with_tls(SERVER_CERTS)?
-            .with_event((tracing_events(), active_path_sub))?
+            .with_event((tracing_events(), (active_path_sub, path_validation_sub)))?
             .with_random(Random::with_seed(456))?
             .start()?;
 
@@ -95,6 +97,15 @@ where
         &*active_paths.events().lock().unwrap(),
         &*expected_paths.lock().unwrap()
     );
+
+    // the server validates each of the new client addresses, which takes at least a round trip
+    let path_validations = path_validations.events();
+    let path_validations = path_validations.lock().unwrap();
+    assert_eq!(path_validations.len(), rebind_count);
+    for duration in path_validations.iter() {
+        assert!(*duration >= rtt, "{duration:?}");
+        assert!(*duration < Duration::from_secs(1), "{duration:?}");
+    }
 }
 
 /// Ensures that a client that changes its port immediately after
@@ -218,3 +229,99 @@ fn port_rebind_test() {
 fn ip_and_port_rebind_test() {
     run_test(|addr| rebind_ip(rebind_port(addr)));
 }
+
+/// Ensures the server reports both the original and the migrated path, with the
+/// migrated path active
+#[test]
+fn paths_after_migration_test() {
+    let model = Model::default();
+    let rtt = Duration::from_millis(10);
+    model.set_delay(rtt / 2);
+
+    let client_addrs = Arc::new(Mutex::new(vec![]));
+    let server_paths = Arc::new(Mutex::new(vec![]));
+
+    let on_socket = {
+        let client_addrs = client_addrs.clone();
+        move |socket: io::Socket| {
+            let client_addrs = client_addrs.clone();
+            spawn(async move {
+                let local_addr = socket.local_addr().unwrap();
+                delay(rtt * 2).await;
+                let new_addr = rebind_port(local_addr);
+                client_addrs.lock().unwrap().push(new_addr);
+                socket.rebind(new_addr);
+            });
+        }
+    };
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let server_paths = server_paths.clone();
+        spawn(async move {
+            let mut conn = server.accept().await.unwrap();
+            let mut stream = conn.accept_bidirectional_stream().await.unwrap().unwrap();
+
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                stream.send(chunk).await.unwrap();
+            }
+
+            *server_paths.lock().unwrap() = conn.paths().unwrap().collect::<Vec<_>>();
+            stream.finish().unwrap();
+        });
+
+        let client = Client::builder()
+            .with_io(handle.builder().on_socket(on_socket).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut conn = client.connect(connect).await.unwrap();
+
+            // before migrating the client only has a single active path
+            let paths: Vec<_> = conn.paths().unwrap().collect();
+            assert_eq!(paths.len(), 1);
+            assert!(paths[0].is_active);
+
+            let mut stream = conn.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+
+            // send some more data once the socket has been rebound
+            delay(rtt * 4).await;
+            stream.send(Bytes::from_static(b"B")).await.unwrap();
+            stream.finish().unwrap();
+
+            while stream.receive().await.unwrap().is_some() {}
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let client_addr = client_addrs.lock().unwrap()[0];
+    let paths = server_paths.lock().unwrap();
+
+    assert_eq!(paths.len(), 2, "{paths:?}");
+
+    let active: Vec<_> = paths.iter().filter(|path| path.is_active).collect();
+    assert_eq!(active.len(), 1, "{paths:?}");
+    assert_eq!(SocketAddr::from(active[0].remote_address), client_addr);
+
+    // the original path was validated during the handshake
+    let original = paths.iter().find(|path| !path.is_active).unwrap();
+    assert_eq!(
+        original.validation_state,
+        crate::connection::PathValidationState::Validated
+    );
+    assert_ne!(SocketAddr::from(original.remote_address), client_addr);
+}
@@ -0,0 +1,76 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use provider::datagram::default::{DatagramError, Endpoint, Sender};
+use s2n_quic_core::path::MaxMtu;
+
+fn datagram_endpoint() -> Endpoint {
+    Endpoint::builder()
+        .with_send_capacity(10)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+// The peer accepts datagrams of up to 65535 bytes but the client's MTU can only fit around 1200
+// bytes in a packet, so the MTU determines the largest datagram the client can send.
+#[test]
+fn max_datagram_size_mtu_limit_test() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_datagram(datagram_endpoint())?
+            .start()?;
+        let server_address = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(MaxMtu::MIN.into()).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_datagram(datagram_endpoint())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_address).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            let max_datagram_size = connection.max_datagram_size().unwrap().unwrap();
+            let peer_limit = connection
+                .peer_transport_parameters()
+                .unwrap()
+                .unwrap()
+                .max_datagram_frame_size;
+            assert!(
+                (max_datagram_size as u64) < peer_limit,
+                "the MTU should be the binding constraint"
+            );
+
+            let send = |len: usize| {
+                connection
+                    .datagram_mut(|sender: &mut Sender| {
+                        sender.send_datagram(Bytes::from(vec![1; len]))
+                    })
+                    .unwrap()
+            };
+
+            // a datagram that fits in a single packet is accepted
+            assert_eq!(send(max_datagram_size), Ok(()));
+
+            // a datagram within the peer's limit that can't fit in a packet is rejected up front
+            assert!(matches!(
+                send(max_datagram_size + 1),
+                Err(DatagramError::ExceedsPeerTransportLimits { .. })
+            ));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,87 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use events::HandshakeStatus;
+
+fn confirmed_count(events: &Arc<Mutex<Vec<events::HandshakeStatusUpdated>>>) -> usize {
+    events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|event| matches!(event.status, HandshakeStatus::Confirmed { .. }))
+        .count()
+}
+
+/// The client is handed the connection once the handshake completes, but only resolves
+/// `handshake_confirmed` after the server's HANDSHAKE_DONE frame is processed
+#[test]
+fn client_handshake_confirmed_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let client_subscriber = recorder::HandshakeStatus::new();
+    let client_events = client_subscriber.events();
+    let events = client_events.clone();
+
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), client_subscriber))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // establishing the connection doesn't confirm the handshake on the client
+            assert_eq!(confirmed_count(&client_events), 0);
+
+            connection.handshake_confirmed().await.unwrap();
+            assert_eq!(confirmed_count(&client_events), 1);
+
+            // waiting again resolves immediately without another transition
+            connection.handshake_confirmed().await.unwrap();
+            assert_eq!(confirmed_count(&client_events), 1);
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    // make sure the client task actually ran to completion
+    assert_eq!(confirmed_count(&events), 1);
+}
+
+/// The server confirms the handshake once it completes, so accepted connections resolve
+/// `handshake_confirmed` immediately
+#[test]
+fn server_handshake_confirmed_test() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let server_addr = server.local_addr()?;
+
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            connection.handshake_confirmed().await.unwrap();
+            // keep the connection open until the client is done
+            while let Ok(Some(_stream)) = connection.accept().await {}
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            connection.handshake_confirmed().await.unwrap();
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+}
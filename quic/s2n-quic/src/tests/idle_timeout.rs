@@ -0,0 +1,103 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::connection::ConnectionCloseReason;
+use io::time::now;
+
+const SHORT_TIMEOUT: Duration = Duration::from_secs(5);
+const LONG_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn limits(max_idle_timeout: Duration) -> provider::limits::Limits {
+    provider::limits::Limits::default()
+        .with_max_idle_timeout(max_idle_timeout)
+        .unwrap()
+}
+
+/// Returns how long an idle client connection stays open with the given advertised timeouts
+fn client_idle_duration(client_timeout: Duration, server_timeout: Duration) -> Duration {
+    let model = Model::default();
+    let idle_duration = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(limits(server_timeout))?
+            .start()?;
+        let server_addr = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(limits(client_timeout))?
+            .start()?;
+
+        let idle_duration = idle_duration.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let start = now();
+
+            // the server never opens a stream so this only returns once the connection closes
+            while let Ok(Some(_stream)) = connection.accept().await {}
+
+            assert_eq!(
+                connection.close_reason(),
+                Some(ConnectionCloseReason::IdleTimeout)
+            );
+            *idle_duration.lock().unwrap() = Some(now() - start);
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let idle_duration = idle_duration.lock().unwrap().take();
+    idle_duration.expect("the client connection should have timed out")
+}
+
+/// Asserts the connection timed out after `expected`, allowing for the packets exchanged
+/// at the end of the handshake
+#[track_caller]
+fn assert_idle_duration(actual: Duration, expected: Duration) {
+    let tolerance = Duration::from_secs(1);
+    assert!(
+        expected <= actual && actual <= expected + tolerance,
+        "expected an idle timeout of {expected:?}, got {actual:?}"
+    );
+}
+
+//= https://www.rfc-editor.org/rfc/rfc9000#section-10.1
+//= type=test
+//# Each endpoint advertises a max_idle_timeout, but the effective value
+//# at an endpoint is computed as the minimum of the two advertised
+//# values (or the sole advertised value, if only one endpoint advertises
+//# a non-zero value).
+#[test]
+fn peer_shorter_idle_timeout_test() {
+    let duration = client_idle_duration(LONG_TIMEOUT, SHORT_TIMEOUT);
+    assert_idle_duration(duration, SHORT_TIMEOUT);
+}
+
+#[test]
+fn local_shorter_idle_timeout_test() {
+    let duration = client_idle_duration(SHORT_TIMEOUT, LONG_TIMEOUT);
+    assert_idle_duration(duration, SHORT_TIMEOUT);
+}
+
+#[test]
+fn peer_disabled_idle_timeout_test() {
+    let duration = client_idle_duration(SHORT_TIMEOUT, Duration::ZERO);
+    assert_idle_duration(duration, SHORT_TIMEOUT);
+}
+
+#[test]
+fn local_disabled_idle_timeout_test() {
+    let duration = client_idle_duration(Duration::ZERO, SHORT_TIMEOUT);
+    assert_idle_duration(duration, SHORT_TIMEOUT);
+}
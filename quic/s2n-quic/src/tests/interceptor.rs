@@ -40,3 +40,23 @@ fn interceptor_failure_test() {
             .build(),
     )
 }
+
+#[test]
+fn drop_every_n_test() {
+    use crate::provider::packet_interceptor::DropEveryN;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_packet_interceptor(DropEveryN::new(2))?
+            .start()?;
+        let server_address = start_server(server)?;
+
+        client(handle, server_address)
+    })
+    .unwrap();
+}
@@ -0,0 +1,19 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// The amount of time the client sits idle, which is longer than the default idle timeout
+const IDLE_DURATION: Duration = Duration::from_secs(60);
+
+#[test]
+fn keep_alive_sends_ping_test() {
+    // the keep-alive PINGs should prevent the connection from timing out while idle
+    let pings = idle_ping_frames(IDLE_DURATION, |connection| {
+        connection.keep_alive(true).unwrap();
+    });
+
+    // the keep-alive period is 3/4 of the 30s default idle timeout so the client
+    // should have sent a PING at least twice while idle
+    assert!(pings >= 2, "pings sent while idle: {pings}");
+}
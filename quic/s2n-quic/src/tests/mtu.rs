@@ -78,6 +78,50 @@ fn mtu_probe_jumbo_frame_unsupported_test() {
     assert_eq!(last_mtu.mtu, 1472);
 }
 
+// if the peer advertises a smaller max_udp_payload_size than the local endpoint supports,
+// datagrams are limited to the peer's value even though the network supports jumbo frames
+#[test]
+fn mtu_probe_peer_max_udp_payload_test() {
+    let model = Model::default();
+    model.set_max_udp_payload(9_001);
+
+    let mtu_subscriber = recorder::MtuUpdated::new();
+    let mtu_events = mtu_subscriber.events();
+    let datagram_subscriber = recorder::DatagramSent::new();
+    let datagram_events = datagram_subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_max_mtu(9_001).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((tracing_events(), (mtu_subscriber, datagram_subscriber)))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        // the client advertises 1500 - UDP_HEADER_LEN - IPV4_HEADER_LEN
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(1_500).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the server probes up to the client's limit and no further
+    let mtu_events = mtu_events.lock().unwrap();
+    assert_eq!(mtu_events.last().unwrap().mtu, 1472);
+
+    let datagram_events = datagram_events.lock().unwrap();
+    assert!(!datagram_events.is_empty());
+    for event in datagram_events.iter() {
+        assert!(event.len <= 1472, "datagram of {} bytes sent", event.len);
+    }
+    assert!(datagram_events.iter().any(|event| event.len == 1472));
+}
+
 // if we lose every packet during a round trip and then allow packets through,
 // this is not determined to be an MTU black hole
 #[test]
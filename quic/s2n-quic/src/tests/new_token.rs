@@ -0,0 +1,170 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::{
+    address_token::{self, Context},
+    endpoint_limits,
+};
+use core::convert::Infallible;
+use s2n_quic_core::{
+    connection,
+    event::api::{Frame, PacketHeader},
+};
+
+/// The server should deliver a single NEW_TOKEN frame once the handshake is confirmed
+#[test]
+fn server_sends_new_token_test() {
+    let model = Model::default();
+    let subscriber = recorder::FrameSent::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((tracing_events(), subscriber))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let server_addr = start_server(server)?;
+
+        client(handle, server_addr)?;
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    let new_tokens = events
+        .iter()
+        .filter(|event| matches!(event.frame, Frame::NewToken { .. }))
+        .collect::<Vec<_>>();
+
+    assert_eq!(new_tokens.len(), 1);
+
+    // NEW_TOKEN frames are only allowed in 1-RTT packets
+    assert!(matches!(
+        new_tokens[0].packet_header,
+        PacketHeader::OneRtt { .. }
+    ));
+}
+
+/// Wraps the default address token format to record the tokens it issues and accepts
+#[derive(Clone, Default)]
+struct TokenRecorder {
+    new_tokens: Arc<Mutex<Vec<Vec<u8>>>>,
+    retry_tokens: Arc<Mutex<usize>>,
+    validated_new_tokens: Arc<Mutex<usize>>,
+}
+
+struct RecordingFormat {
+    inner: address_token::default::Format,
+    recorder: TokenRecorder,
+}
+
+impl address_token::Provider for TokenRecorder {
+    type Format = RecordingFormat;
+    type Error = Infallible;
+
+    fn start(self) -> Result<Self::Format, Self::Error> {
+        Ok(RecordingFormat {
+            inner: address_token::Default::default().start()?,
+            recorder: self,
+        })
+    }
+}
+
+impl address_token::Format for RecordingFormat {
+    const TOKEN_LEN: usize = address_token::default::Format::TOKEN_LEN;
+
+    fn generate_new_token(
+        &mut self,
+        context: &mut Context<'_>,
+        source_connection_id: &connection::LocalId,
+        output_buffer: &mut [u8],
+    ) -> Option<()> {
+        self.inner
+            .generate_new_token(context, source_connection_id, output_buffer)?;
+        self.recorder
+            .new_tokens
+            .lock()
+            .unwrap()
+            .push(output_buffer.to_vec());
+        Some(())
+    }
+
+    fn generate_retry_token(
+        &mut self,
+        context: &mut Context<'_>,
+        original_destination_connection_id: &connection::InitialId,
+        output_buffer: &mut [u8],
+    ) -> Option<()> {
+        *self.recorder.retry_tokens.lock().unwrap() += 1;
+        self.inner
+            .generate_retry_token(context, original_destination_connection_id, output_buffer)
+    }
+
+    fn validate_token(
+        &mut self,
+        context: &mut Context<'_>,
+        token: &[u8],
+    ) -> Option<connection::InitialId> {
+        self.inner.validate_token(context, token)
+    }
+
+    fn validate_new_token(&mut self, context: &mut Context<'_>, token: &[u8]) -> bool {
+        let is_valid = self.inner.validate_new_token(context, token);
+        if is_valid {
+            *self.recorder.validated_new_tokens.lock().unwrap() += 1;
+        }
+        is_valid
+    }
+}
+
+/// A token from a NEW_TOKEN frame on one connection should validate the client's address on a
+/// later connection, so the server doesn't need to send a Retry
+#[test]
+fn new_token_validates_later_connection_test() {
+    let model = Model::default();
+    let recorder = TokenRecorder::default();
+    let server_recorder = recorder.clone();
+    let client_recorder = recorder.clone();
+
+    test(model, |handle| {
+        // require address validation for every connection
+        let limits = endpoint_limits::Default::builder()
+            .with_inflight_handshake_limit(0)?
+            .build()?;
+
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_endpoint_limits(limits)?
+            .with_address_token(server_recorder)?
+            .start()?;
+        let server_addr = start_server(server)?;
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+
+            let connection = client.connect(connect.clone()).await.unwrap();
+            drop(connection);
+
+            // reconnect a while later with the token issued on the first connection
+            delay(Duration::from_secs(60)).await;
+            let token = client_recorder.new_tokens.lock().unwrap().pop().unwrap();
+            let mut connection = client.connect(connect.with_token(token)).await.unwrap();
+            connection.ping().unwrap();
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    // only the first connection was validated with a Retry
+    assert_eq!(*recorder.retry_tokens.lock().unwrap(), 1);
+    assert_eq!(*recorder.validated_new_tokens.lock().unwrap(), 1);
+}
@@ -0,0 +1,40 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// The client reports the transport parameters the server advertised
+#[test]
+fn peer_transport_parameters_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(
+                provider::limits::Limits::default()
+                    .with_max_open_remote_bidirectional_streams(7)
+                    .unwrap()
+                    .with_max_idle_timeout(Duration::from_secs(15))
+                    .unwrap(),
+            )?
+            .start()?;
+        let server_addr = start_server(server)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            let parameters = connection.peer_transport_parameters().unwrap().unwrap();
+            assert_eq!(parameters.initial_max_streams_bidi, 7);
+            assert_eq!(parameters.max_idle_timeout, Some(Duration::from_secs(15)));
+            assert!(!parameters.migration_disabled);
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+}
@@ -0,0 +1,15 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+#[test]
+fn ping_sends_ping_frame_test() {
+    assert_eq!(idle_ping_frames(Duration::from_secs(1), |_| {}), 0);
+    assert_eq!(
+        idle_ping_frames(Duration::from_secs(1), |connection| {
+            connection.ping().unwrap();
+        }),
+        1
+    );
+}
@@ -58,7 +58,14 @@ macro_rules! event_recorder {
 
 event_recorder!(FrameSent, FrameSent, on_frame_sent);
 event_recorder!(PacketSent, PacketSent, on_packet_sent);
+event_recorder!(DatagramSent, DatagramSent, on_datagram_sent);
+event_recorder!(PacketReceived, PacketReceived, on_packet_received);
 event_recorder!(MtuUpdated, MtuUpdated, on_mtu_updated);
+event_recorder!(
+    TransmissionBudget,
+    TransmissionBudget,
+    on_transmission_budget
+);
 event_recorder!(
     PathUpdated,
     RecoveryMetrics,
@@ -71,6 +78,21 @@ event_recorder!(
         }
     }
 );
+event_recorder!(
+    PathValidated,
+    PathValidationUpdated,
+    on_path_validation_updated,
+    Duration,
+    |event: &events::PathValidationUpdated, storage: &mut Vec<Duration>| {
+        if matches!(event.status, events::PathChallengeStatus::Validated { .. }) {
+            storage.push(
+                event
+                    .completed_at
+                    .saturating_duration_since(event.challenge_sent_at),
+            );
+        }
+    }
+);
 event_recorder!(
     Pto,
     RecoveryMetrics,
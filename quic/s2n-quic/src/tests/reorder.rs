@@ -0,0 +1,57 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use events::PacketHeader;
+
+/// The handshake and a transfer complete when packets are heavily reordered but not lost
+#[test]
+fn reorder_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    // jitter larger than the delay causes packets in the same flight to arrive out of order
+    model.set_network_jitter(Duration::from_millis(100));
+
+    let subscriber = recorder::PacketReceived::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), subscriber))?
+            .with_random(Random::with_seed(123))?
+            .start()?;
+
+        // the data is echoed back and checked by the client, which requires the CRYPTO and
+        // STREAM data to be reassembled in order
+        start_client(client, server_addr, Data::new(100_000))?;
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+
+    let mut initial = vec![];
+    let mut handshake = vec![];
+    let mut one_rtt = vec![];
+    for event in events.iter() {
+        match event.packet_header {
+            PacketHeader::Initial { number, .. } => initial.push(number),
+            PacketHeader::Handshake { number, .. } => handshake.push(number),
+            PacketHeader::OneRtt { number, .. } => one_rtt.push(number),
+            _ => {}
+        }
+    }
+
+    assert!(!initial.is_empty());
+    assert!(!handshake.is_empty());
+    assert!(!one_rtt.is_empty());
+
+    // make sure the network actually delivered packets out of order within a space
+    let is_reordered = |numbers: &[u64]| numbers.windows(2).any(|pair| pair[0] > pair[1]);
+    assert!(is_reordered(&one_rtt));
+}
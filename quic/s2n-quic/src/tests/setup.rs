@@ -1,18 +1,20 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::recorder;
 use crate::{
     client::Connect,
     provider::{
         event,
-        io::testing::{primary, spawn, Handle, Result},
+        io::testing::{primary, spawn, test, time::delay, Handle, Model, Result},
     },
     stream::PeerStream,
-    Client, Server,
+    Client, Connection, Server,
 };
+use bytes::Bytes;
 use rand::{Rng, RngCore};
 use s2n_quic_core::{crypto::tls::testing::certificates, havoc, stream::testing::Data};
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 
 pub static SERVER_CERTS: (&str, &str) = (certificates::CERT_PEM, certificates::KEY_PEM);
 
@@ -156,6 +158,66 @@ pub fn client_server(handle: &Handle) -> Result<SocketAddr> {
     Ok(addr)
 }
 
+/// Returns the number of PING frames the client sent in 1-RTT packets while it sat idle for
+/// `idle_duration`
+///
+/// `on_idle` is called with the client's connection right before it goes idle.
+pub fn idle_ping_frames<F>(idle_duration: Duration, on_idle: F) -> usize
+where
+    F: FnOnce(&mut Connection) + Send + 'static,
+{
+    use s2n_quic_core::event::api::{Frame, PacketHeader};
+
+    let model = Model::default();
+    let subscriber = recorder::FrameSent::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = build_server(handle)?;
+        let server_addr = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), subscriber))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let events = events.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // Delay for a second to allow expiring timers and packet acks to be cleared out
+            delay(Duration::from_secs(1)).await;
+            events.lock().unwrap().clear();
+
+            on_idle(&mut connection);
+
+            // nothing else is sent by the application while idle so any PING frames come
+            // from `on_idle`
+            delay(idle_duration).await;
+
+            // the connection should still be usable after sitting idle
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"ping")).await.unwrap();
+            stream.finish().unwrap();
+            let chunk = stream.receive().await.unwrap().unwrap();
+            assert_eq!(&chunk[..], b"ping");
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    events
+        .iter()
+        .filter(|event| matches!(event.packet_header, PacketHeader::OneRtt { .. }))
+        .filter(|event| matches!(event.frame, Frame::Ping { .. }))
+        .count()
+}
+
 pub struct Random {
     inner: rand_chacha::ChaCha8Rng,
 }
@@ -88,8 +88,8 @@ fn optimistic_ack_mitigation() {
 
     // Verify that both client and server are skipping packets for Optimistic
     // Ack attack mitigation.
-    assert_eq!(server_skip_count, 5);
-    assert_eq!(client_skip_count, 5);
+    assert_eq!(server_skip_count, 4);
+    assert_eq!(client_skip_count, 4);
 }
 
 // Mimic an Optimistic Ack attack and confirm the connection is closed with
@@ -0,0 +1,47 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// The stats reported by the client reflect the data exchanged with the server
+#[test]
+fn connection_stats_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let before = connection.stats().unwrap();
+            assert!(before.packets_sent > 0);
+            assert!(before.packets_received > 0);
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            let mut data = Data::new(10_000);
+            while let Some(chunk) = data.send_one(usize::MAX) {
+                stream.send(chunk).await.unwrap();
+            }
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+
+            let after = connection.stats().unwrap();
+            assert!(after.packets_sent > before.packets_sent);
+            assert!(after.packets_received > before.packets_received);
+            // the request and the echoed response were both carried by the counted bytes
+            assert!(after.bytes_sent - before.bytes_sent >= 10_000);
+            assert!(after.bytes_received - before.bytes_received >= 10_000);
+            assert_eq!(after.packets_lost, 0);
+            assert_eq!(
+                after.smoothed_rtt,
+                connection.rtt_estimate().unwrap().smoothed
+            );
+            assert!(after.congestion_window > 0);
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+}
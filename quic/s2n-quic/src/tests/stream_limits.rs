@@ -0,0 +1,74 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use core::task::Poll;
+use s2n_quic_core::event::api::Frame;
+
+/// Opening more streams than the peer allows blocks until an open stream completes
+#[test]
+fn stream_limits_test() {
+    let model = Model::default();
+    let subscriber = recorder::FrameSent::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(
+                provider::limits::Limits::default()
+                    // only allow 1 concurrent stream from the peer
+                    .with_max_open_remote_bidirectional_streams(1)
+                    .unwrap(),
+            )?
+            .start()?;
+        let server_addr = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), subscriber))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"first")).await.unwrap();
+
+            // the second stream exceeds the limit so it can't be opened while the first is open
+            for _ in 0..3 {
+                let is_blocked = futures::future::poll_fn(|cx| {
+                    Poll::Ready(connection.poll_open_bidirectional_stream(cx).is_pending())
+                })
+                .await;
+                assert!(is_blocked);
+                delay(Duration::from_millis(100)).await;
+            }
+
+            // completing the first stream lets the server raise the limit
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+            drop(stream);
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"second")).await.unwrap();
+            stream.finish().unwrap();
+            let chunk = stream.receive().await.unwrap().unwrap();
+            assert_eq!(&chunk[..], b"second");
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    assert!(events
+        .iter()
+        .any(|event| matches!(event.frame, Frame::StreamsBlocked { .. })));
+}
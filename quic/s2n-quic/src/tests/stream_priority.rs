@@ -0,0 +1,72 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::stream::Priority;
+use s2n_quic_core::event::api::Frame;
+
+/// A stream opened with a high priority is transmitted ahead of a low priority stream which
+/// already had data queued
+#[test]
+fn stream_priority_test() {
+    let model = Model::default();
+    let subscriber = recorder::FrameSent::new();
+    let events = subscriber.events();
+    let stream_ids = Arc::new(Mutex::new(None));
+    let opened_stream_ids = stream_ids.clone();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let server_addr = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), subscriber))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // queue data on the low priority stream before the high priority stream exists
+            let mut low = connection
+                .open_bidirectional_stream_with_priority(Priority::LOWEST)
+                .await
+                .unwrap();
+            low.send(Bytes::from(vec![42; 10_000])).await.unwrap();
+
+            let mut high = connection
+                .open_send_stream_with_priority(Priority::HIGHEST)
+                .await
+                .unwrap();
+            high.send(Bytes::from_static(b"urgent")).await.unwrap();
+            *stream_ids.lock().unwrap() = Some((low.id(), high.id()));
+
+            high.finish().unwrap();
+            low.finish().unwrap();
+            while let Ok(Some(_)) = low.receive().await {}
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let (low_id, high_id) = opened_stream_ids.lock().unwrap().unwrap();
+    let events = events.lock().unwrap();
+    let first_stream_frame = events
+        .iter()
+        .find_map(|event| match event.frame {
+            // skip the empty STREAM frame which notifies the peer of the bidirectional stream
+            Frame::Stream { id, len, .. } if len > 0 && (id == low_id || id == high_id) => Some(id),
+            _ => None,
+        })
+        .expect("stream data should be sent");
+    assert_eq!(first_stream_frame, high_id);
+}
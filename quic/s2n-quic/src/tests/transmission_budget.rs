@@ -0,0 +1,78 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use s2n_quic_core::event::api::PacketHeader;
+
+#[test]
+fn transmission_budget_test() {
+    let model = Model::default();
+    let budget = recorder::TransmissionBudget::new();
+    let budget_events = budget.events();
+    let packet_sent = recorder::PacketSent::new();
+    let packet_sent_events = packet_sent.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let server_address = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), (budget, packet_sent)))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        start_client(client, server_address, Data::new(100_000))
+    })
+    .unwrap();
+
+    let budget_events = budget_events.lock().unwrap();
+    let packet_sent_events = packet_sent_events.lock().unwrap();
+
+    // a budget is recorded for every 1-RTT packet with the number of bytes actually written,
+    // except for the final CONNECTION_CLOSE packet
+    let packet_lens: Vec<_> = packet_sent_events
+        .iter()
+        .filter(|event| matches!(event.packet_header, PacketHeader::OneRtt { .. }))
+        .map(|event| event.packet_len)
+        .collect();
+    let bytes_written: Vec<_> = budget_events
+        .iter()
+        .map(|event| event.bytes_written)
+        .collect();
+    assert_eq!(bytes_written, packet_lens[..packet_lens.len() - 1]);
+
+    //= https://www.rfc-editor.org/rfc/rfc9002#section-7.2
+    //= type=test
+    //# Endpoints SHOULD use an initial congestion
+    //# window of ten times the maximum datagram size (max_datagram_size),
+    //# while limiting the window to the larger of 14,720 bytes or twice the
+    //# maximum datagram size.
+    let first = &budget_events[0];
+    assert_eq!(first.congestion_window, 10 * 1200);
+
+    // until the first ACK arrives each packet adds to the bytes in flight of the next
+    for (prev, next) in budget_events
+        .iter()
+        .zip(budget_events.iter().skip(1))
+        .take_while(|(prev, next)| prev.congestion_window == next.congestion_window)
+    {
+        assert_eq!(
+            next.bytes_in_flight,
+            prev.bytes_in_flight + prev.bytes_written as u32
+        );
+    }
+
+    for event in budget_events.iter() {
+        // packets are only written while there is room in the congestion window
+        assert!(event.bytes_in_flight < event.congestion_window, "{event:?}");
+        // Cubic doesn't set a send quantum
+        assert_eq!(event.send_quantum, None);
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// QUIC version 1
+///
+/// See https://www.rfc-editor.org/rfc/rfc9000#section-15
+const QUIC_V1: u32 = 0x00000001;
+
+#[test]
+fn negotiated_version_test() {
+    let model = Model::default();
+
+    let server_version = Arc::new(Mutex::new(None));
+    let client_version = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let server_version = server_version.clone();
+        spawn(async move {
+            let mut conn = server.accept().await.unwrap();
+            *server_version.lock().unwrap() = Some(conn.version().unwrap());
+
+            let mut stream = conn.accept_bidirectional_stream().await.unwrap().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+            stream.finish().unwrap();
+        });
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let client_version = client_version.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut conn = client.connect(connect).await.unwrap();
+            *client_version.lock().unwrap() = Some(conn.version().unwrap());
+
+            // keep the connection open until the server has accepted it
+            let mut stream = conn.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // both endpoints report the version the handshake was completed with
+    assert_eq!(*client_version.lock().unwrap(), Some(QUIC_V1));
+    assert_eq!(*server_version.lock().unwrap(), Some(QUIC_V1));
+}